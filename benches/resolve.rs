@@ -0,0 +1,62 @@
+//! Compares resolving through [`Container::resolve`], which skips boxing for plain bindings and singletons, against the type-erased path taken by a [`Resolver`](silhouette::resolver::Resolver).
+//!
+//! Run with `cargo bench --bench resolve`.
+
+use silhouette::Container;
+use std::{hint::black_box, time::Instant};
+
+const ITERATIONS: u32 = 1_000_000;
+
+#[derive(Clone)]
+struct Config {
+    workers: usize,
+    port: u16,
+}
+
+struct Service {
+    port: u16,
+}
+
+fn measure(name: &str, mut resolve: impl FnMut()) {
+    for _ in 0..ITERATIONS / 10 {
+        resolve();
+    }
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        resolve();
+    }
+    let elapsed = start.elapsed();
+
+    println!(
+        "{name:<32} {:>8.1} ns/iter",
+        elapsed.as_nanos() as f64 / f64::from(ITERATIONS)
+    );
+}
+
+fn main() {
+    let mut container = Container::new();
+    container.singleton(&|_| Config {
+        workers: 4,
+        port: 8080,
+    });
+    container.bind(|container| Service {
+        port: container.resolve::<Config>().unwrap().port,
+    });
+
+    let config = container.resolver::<Config>().unwrap();
+    let service = container.resolver::<Service>().unwrap();
+
+    measure("resolve singleton", || {
+        black_box(container.resolve::<Config>().unwrap().workers);
+    });
+    measure("resolve singleton (erased)", || {
+        black_box(config.resolve(&container).unwrap().workers);
+    });
+    measure("resolve binding", || {
+        black_box(container.resolve::<Service>().unwrap().port);
+    });
+    measure("resolve binding (erased)", || {
+        black_box(service.resolve(&container).unwrap().port);
+    });
+}
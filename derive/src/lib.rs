@@ -0,0 +1,49 @@
+#![warn(clippy::all, clippy::pedantic, clippy::nursery)]
+
+//! Derive macros for [silhouette](https://docs.rs/silhouette). Enable silhouette's `derive` feature instead of depending on this crate directly.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields};
+
+/// Implement `silhouette::construct::Construct` by resolving every field of the struct from the container.
+#[proc_macro_derive(Construct)]
+pub fn derive_construct(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let Data::Struct(data) = &input.data else {
+        return Error::new_spanned(&input.ident, "Construct can only be derived for structs")
+            .to_compile_error()
+            .into();
+    };
+
+    let body = match &data.fields {
+        Fields::Named(fields) => {
+            let fields = fields.named.iter().map(|field| &field.ident);
+
+            quote! { Self { #(#fields: container.resolve()?),* } }
+        }
+        Fields::Unnamed(fields) => {
+            let fields = fields
+                .unnamed
+                .iter()
+                .map(|_| quote! { container.resolve()? });
+
+            quote! { Self(#(#fields),*) }
+        }
+        Fields::Unit => quote! { Self },
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::silhouette::construct::Construct for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn construct(container: &::silhouette::Container) -> ::core::result::Result<Self, ::silhouette::Error> {
+                ::core::result::Result::Ok(#body)
+            }
+        }
+    }
+    .into()
+}
@@ -0,0 +1,160 @@
+use std::{
+    any::Any,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        OnceLock,
+    },
+};
+
+/// The number of slots in a chunk's first block. Every following block doubles in size.
+const INITIAL_CAPACITY: usize = 16;
+
+/// Storage for values resolved with [`Container::resolve_in`](crate::Container::resolve_in), released all at once when the arena is dropped.
+///
+/// Values are moved into blocks reserved for their type, which double in size as they fill up, so resolving many values of the same type only allocates once per block rather than once per value. Every [`Scope`](crate::scope::Scope) owns an arena, which [`Scope::resolve_ref`](crate::scope::Scope::resolve_ref) fills and the scope releases when it ends.
+pub struct Arena {
+    /// A chunk of values for every type stored in the arena.
+    lanes: Chunk<Box<dyn Any + Send + Sync>>,
+    /// The number of values stored in the arena.
+    len: AtomicUsize,
+}
+
+/// Append-only storage for values of a single type, in blocks that double in size.
+struct Chunk<T> {
+    /// The first block of slots.
+    head: Block<T>,
+    /// The number of slots handed out.
+    len: AtomicUsize,
+}
+
+/// A fixed-size block of slots, linked to the next (larger) block.
+struct Block<T> {
+    /// The block's slots.
+    slots: Box<[OnceLock<T>]>,
+    /// The next block, allocated once this one is full.
+    next: OnceLock<Box<Self>>,
+}
+
+impl<T> Block<T> {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slots: (0..capacity).map(|_| OnceLock::new()).collect(),
+            next: OnceLock::new(),
+        }
+    }
+}
+
+impl<T> Chunk<T> {
+    fn new() -> Self {
+        Self {
+            head: Block::with_capacity(INITIAL_CAPACITY),
+            len: AtomicUsize::new(0),
+        }
+    }
+
+    /// Move a value into the next free slot, returning a reference that lives as long as the chunk.
+    fn push(&self, value: T) -> &T {
+        let mut index = self.len.fetch_add(1, Ordering::Relaxed);
+
+        let mut block = &self.head;
+        while index >= block.slots.len() {
+            index -= block.slots.len();
+
+            let capacity = block.slots.len() * 2;
+            block = block
+                .next
+                .get_or_init(|| Box::new(Block::with_capacity(capacity)));
+        }
+
+        block.slots[index].get_or_init(move || value)
+    }
+
+    /// The values stored so far.
+    fn iter(&self) -> impl Iterator<Item = &T> {
+        std::iter::successors(Some(&self.head), |block| {
+            block.next.get().map(AsRef::as_ref)
+        })
+        .flat_map(|block| block.slots.iter().filter_map(OnceLock::get))
+    }
+}
+
+impl Arena {
+    /// Create a new, empty arena.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            lanes: Chunk::new(),
+            len: AtomicUsize::new(0),
+        }
+    }
+
+    /// The number of values stored in the arena.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+
+    /// Whether the arena is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Move a value into the arena, returning a reference that lives as long as the arena.
+    pub fn alloc<T: 'static + Send + Sync>(&self, value: T) -> &T {
+        self.len.fetch_add(1, Ordering::Relaxed);
+
+        loop {
+            if let Some(lane) = self
+                .lanes
+                .iter()
+                .find_map(|lane| lane.as_ref().downcast_ref::<Chunk<T>>())
+            {
+                return lane.push(value);
+            }
+
+            // Racing to add the type's first value may leave it with two chunks, which both keep their values alive.
+            self.lanes.push(Box::new(Chunk::<T>::new()));
+        }
+    }
+}
+
+impl Default for Arena {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Container, Error};
+
+    #[test]
+    fn resolved_values_live_as_long_as_the_arena() {
+        let mut container = Container::new();
+        container.bind(|_| "Hello, world!".to_string());
+
+        let arena = Arena::new();
+        let values = (0..100)
+            .map(|_| container.resolve_in::<String>(&arena))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(arena.len(), 100);
+        assert!(values.iter().all(|value| *value == "Hello, world!"));
+        assert_eq!(container.resolve_in::<u32>(&arena), Err(Error::NotFound));
+    }
+
+    #[test]
+    fn stores_values_of_each_type_together() {
+        let arena = Arena::new();
+        let numbers = (0..40_u32).map(|n| arena.alloc(n)).collect::<Vec<_>>();
+        let greeting = arena.alloc("Hello, world!");
+
+        assert_eq!(arena.len(), 41);
+        assert_eq!(arena.lanes.iter().count(), 2);
+        assert!(numbers.iter().map(|number| **number).eq(0..40));
+        assert_eq!(*greeting, "Hello, world!");
+    }
+}
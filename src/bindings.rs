@@ -0,0 +1,365 @@
+use std::{
+    any::{Any, TypeId},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+#[cfg(feature = "concurrent")]
+use crate::hash::BuildTypeIdHasher;
+#[cfg(not(feature = "concurrent"))]
+use crate::hash::TypeMap;
+use crate::{resolver::Source, Binding, Instance};
+
+/// How the values of a registered type are produced.
+#[derive(Clone)]
+pub enum Registration {
+    /// A factory that builds a new value for every resolution.
+    Transient(Binding),
+    /// A shared instance, handed out to every resolution until it's removed.
+    Singleton(Instance),
+    /// A shared instance that's discarded when the container's scope ends, with [`Container::forget_scoped_instances`](crate::Container::forget_scoped_instances).
+    Scoped(Instance),
+}
+
+impl Registration {
+    /// A shared instance, scoped or not.
+    pub fn shared(instance: Instance, scoped: bool) -> Self {
+        if scoped {
+            Self::Scoped(instance)
+        } else {
+            Self::Singleton(instance)
+        }
+    }
+
+    /// The shared instance, if this is one.
+    pub fn instance(&self) -> Option<&Instance> {
+        match self {
+            Self::Transient(_) => None,
+            Self::Singleton(instance) | Self::Scoped(instance) => Some(instance),
+        }
+    }
+
+    /// Where the registration's values come from.
+    pub fn source(&self) -> Source {
+        match self {
+            Self::Transient(binding) => Source::Binding(Arc::clone(binding)),
+            Self::Singleton(instance) | Self::Scoped(instance) => {
+                Source::Instance(Arc::clone(instance))
+            }
+        }
+    }
+
+    /// Whether the registration's values come from the given source.
+    fn is(&self, source: &Source) -> bool {
+        match (self, source) {
+            (Self::Transient(binding), Source::Binding(other)) => Arc::ptr_eq(binding, other),
+            (Self::Singleton(instance) | Self::Scoped(instance), Source::Instance(other)) => {
+                Arc::ptr_eq(instance, other)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// A registration, along with the factory producing its values without boxing them, if it has one.
+#[derive(Clone)]
+struct Entry {
+    /// How the type's values are produced.
+    registration: Registration,
+    /// The typed factory for the registration, discarded along with it when the type is registered again.
+    typed: Option<Arc<dyn Any + Sync + Send>>,
+    /// Set once a one-shot binding has been consumed, after which the registration counts as removed.
+    spent: Option<Arc<AtomicBool>>,
+}
+
+impl Entry {
+    /// Whether the registration is still in place, rather than a consumed one-shot binding.
+    fn is_live(&self) -> bool {
+        self.spent
+            .as_ref()
+            .is_none_or(|spent| !spent.load(Ordering::Acquire))
+    }
+}
+
+impl From<Registration> for Entry {
+    fn from(registration: Registration) -> Self {
+        Self {
+            registration,
+            typed: None,
+            spent: None,
+        }
+    }
+}
+
+/// The container's registrations, keyed by the type they resolve.
+///
+/// Each type has at most one registration, so bindings and shared instances can't shadow each other.
+///
+/// With the `concurrent` feature, the map is a [`DashMap`](dashmap::DashMap) shared with the container's [snapshots](BindingMap::share), so bindings can be registered without exclusive access to the container.
+#[derive(Default)]
+pub struct BindingMap {
+    #[cfg(not(feature = "concurrent"))]
+    map: TypeMap<Entry>,
+    #[cfg(feature = "concurrent")]
+    map: Arc<dashmap::DashMap<TypeId, Entry, BuildTypeIdHasher>>,
+}
+
+// Modifications take `&mut self` even when the map is shared, so only concurrent registrations go through a shared reference.
+#[cfg_attr(feature = "concurrent", allow(clippy::needless_pass_by_ref_mut))]
+impl BindingMap {
+    /// Get the registration for the given type.
+    pub fn get(&self, type_id: &TypeId) -> Option<Registration> {
+        self.map
+            .get(type_id)
+            .filter(|entry| entry.is_live())
+            .map(|entry| entry.registration.clone())
+    }
+
+    /// Call the given function with the typed factory registered alongside the given type's registration, if it has one.
+    pub fn with_typed<R>(
+        &self,
+        type_id: &TypeId,
+        call: impl FnOnce(&(dyn Any + Sync + Send)) -> R,
+    ) -> Option<R> {
+        #[cfg(not(feature = "concurrent"))]
+        return self.map.get(type_id)?.typed.as_deref().map(call);
+
+        // Cloned out of the map, so its shard isn't locked while the factory resolves its own dependencies.
+        #[cfg(feature = "concurrent")]
+        {
+            let typed = self.map.get(type_id)?.typed.clone();
+
+            typed.as_deref().map(call)
+        }
+    }
+
+    /// Attach a typed factory to the given type's registration, as long as it still produces its values from the given source.
+    pub fn set_typed(
+        &mut self,
+        type_id: &TypeId,
+        source: &Source,
+        typed: Arc<dyn Any + Sync + Send>,
+    ) {
+        #[cfg(not(feature = "concurrent"))]
+        let Some(entry) = self.map.get_mut(type_id) else {
+            return;
+        };
+        #[cfg(feature = "concurrent")]
+        let Some(mut entry) = self.map.get_mut(type_id) else {
+            return;
+        };
+
+        if entry.registration.is(source) {
+            entry.typed = Some(typed);
+        }
+    }
+
+    /// Mark the given type's registration as consumed once the given flag is set, as long as it still produces its values from the given source.
+    ///
+    /// Resolution only has shared access to the map, so a consumed registration stays in it, but is skipped until the type is registered again.
+    pub fn set_spent(&mut self, type_id: &TypeId, source: &Source, spent: Arc<AtomicBool>) {
+        #[cfg(not(feature = "concurrent"))]
+        let Some(entry) = self.map.get_mut(type_id) else {
+            return;
+        };
+        #[cfg(feature = "concurrent")]
+        let Some(mut entry) = self.map.get_mut(type_id) else {
+            return;
+        };
+
+        if entry.registration.is(source) {
+            entry.spent = Some(spent);
+        }
+    }
+
+    /// Get the shared instance registered for the given type, unless it's registered as a binding.
+    pub fn instance(&self, type_id: &TypeId) -> Option<Instance> {
+        self.get(type_id)?.instance().cloned()
+    }
+
+    /// Whether the given type is registered.
+    pub fn contains_key(&self, type_id: &TypeId) -> bool {
+        self.get(type_id).is_some()
+    }
+
+    /// Whether a binding is registered for the given type.
+    pub fn contains_binding(&self, type_id: &TypeId) -> bool {
+        matches!(self.get(type_id), Some(Registration::Transient(_)))
+    }
+
+    /// Whether a shared instance is registered for the given type.
+    pub fn contains_instance(&self, type_id: &TypeId) -> bool {
+        matches!(
+            self.get(type_id),
+            Some(Registration::Singleton(_) | Registration::Scoped(_))
+        )
+    }
+
+    /// Whether a scoped instance is registered for the given type.
+    pub fn is_scoped(&self, type_id: &TypeId) -> bool {
+        matches!(self.get(type_id), Some(Registration::Scoped(_)))
+    }
+
+    /// Register a type, replacing its previous registration.
+    pub fn insert(&mut self, type_id: TypeId, registration: Registration) {
+        self.map.insert(type_id, registration.into());
+    }
+
+    /// Register a type through a shared reference, replacing its previous registration.
+    #[cfg(feature = "concurrent")]
+    pub fn insert_shared(&self, type_id: TypeId, registration: Registration) {
+        self.map.insert(type_id, registration.into());
+    }
+
+    /// Replace the shared instance registered for the given type, keeping it scoped if it was.
+    pub fn replace_instance(&mut self, type_id: TypeId, instance: Instance) {
+        let scoped = self.is_scoped(&type_id);
+
+        self.insert(type_id, Registration::shared(instance, scoped));
+    }
+
+    /// Remove the registration for the given type, returning it.
+    pub fn remove(&mut self, type_id: &TypeId) -> Option<Registration> {
+        #[cfg(not(feature = "concurrent"))]
+        return self.map.remove(type_id).map(|entry| entry.registration);
+
+        #[cfg(feature = "concurrent")]
+        return self
+            .map
+            .remove(type_id)
+            .map(|(_, entry)| entry.registration);
+    }
+
+    /// Remove the shared instance registered for the given type, leaving a binding in place.
+    pub fn remove_instance(&mut self, type_id: &TypeId) {
+        if self.contains_instance(type_id) {
+            self.remove(type_id);
+        }
+    }
+
+    /// Remove every registration.
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// The number of bindings, singletons and scoped instances registered.
+    pub fn tally(&self) -> (usize, usize, usize) {
+        let mut tally = (0, 0, 0);
+        let mut count = |registration: &Registration| match registration {
+            Registration::Transient(_) => tally.0 += 1,
+            Registration::Singleton(_) => tally.1 += 1,
+            Registration::Scoped(_) => tally.2 += 1,
+        };
+
+        #[cfg(not(feature = "concurrent"))]
+        self.map
+            .values()
+            .filter(|entry| entry.is_live())
+            .for_each(|entry| count(&entry.registration));
+        #[cfg(feature = "concurrent")]
+        self.map
+            .iter()
+            .filter(|entry| entry.is_live())
+            .for_each(|entry| count(&entry.registration));
+
+        tally
+    }
+
+    /// The number of bytes allocated for the registrations.
+    pub fn heap_bytes(&self) -> usize {
+        self.map.capacity() * std::mem::size_of::<(TypeId, Entry)>()
+    }
+
+    /// Free the space the map holds beyond its registrations.
+    pub fn shrink_to_fit(&mut self) {
+        self.map.shrink_to_fit();
+    }
+
+    /// Make room for at least `additional` more registrations.
+    ///
+    /// Not available with the `concurrent` feature, since the shared map can't reserve space ahead of time.
+    #[cfg(not(feature = "concurrent"))]
+    pub fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional);
+    }
+
+    /// The registered types.
+    pub fn types(&self) -> Vec<TypeId> {
+        #[cfg(not(feature = "concurrent"))]
+        return self
+            .map
+            .iter()
+            .filter(|(_, entry)| entry.is_live())
+            .map(|(type_id, _)| *type_id)
+            .collect();
+
+        #[cfg(feature = "concurrent")]
+        return self
+            .map
+            .iter()
+            .filter(|entry| entry.is_live())
+            .map(|entry| *entry.key())
+            .collect();
+    }
+
+    /// The types registered as scoped instances.
+    pub fn scoped(&self) -> Vec<TypeId> {
+        #[cfg(not(feature = "concurrent"))]
+        return self
+            .map
+            .iter()
+            .filter(|(_, entry)| matches!(entry.registration, Registration::Scoped(_)))
+            .map(|(type_id, _)| *type_id)
+            .collect();
+
+        #[cfg(feature = "concurrent")]
+        return self
+            .map
+            .iter()
+            .filter(|entry| matches!(entry.registration, Registration::Scoped(_)))
+            .map(|entry| *entry.key())
+            .collect();
+    }
+
+    /// The number of bindings and shared instances registered.
+    #[cfg(test)]
+    pub fn counts(&self) -> (usize, usize) {
+        let types = self.types();
+        let bindings = types
+            .iter()
+            .filter(|type_id| self.contains_binding(type_id))
+            .count();
+
+        (bindings, types.len() - bindings)
+    }
+
+    /// Create a map that shares its bindings with this one, so bindings registered through either are seen by both.
+    ///
+    /// Without the `concurrent` feature, this is a copy.
+    pub fn share(&self) -> Self {
+        #[cfg(not(feature = "concurrent"))]
+        return self.clone();
+
+        #[cfg(feature = "concurrent")]
+        return Self {
+            map: Arc::clone(&self.map),
+        };
+    }
+}
+
+impl Clone for BindingMap {
+    /// Copy the bindings into a separate map.
+    fn clone(&self) -> Self {
+        #[cfg(not(feature = "concurrent"))]
+        return Self {
+            map: self.map.clone(),
+        };
+
+        #[cfg(feature = "concurrent")]
+        return Self {
+            map: Arc::new(dashmap::DashMap::clone(&self.map)),
+        };
+    }
+}
@@ -0,0 +1,173 @@
+use std::{any::TypeId, future::Future, sync::Arc};
+
+use crate::{call::AsyncCallable, AsyncHook, Container, Error};
+
+/// Wrap an async function into a hook that resolves its arguments from the container when it runs.
+pub fn async_hook<Args, F>(hook: F) -> AsyncHook
+where
+    F: AsyncCallable<Args> + Clone + 'static + Sync + Send,
+    F::Future: Future<Output = ()> + Send + 'static,
+{
+    Arc::new(move |container: &Container| {
+        let future = hook.clone().prepare(container)?;
+
+        Ok(Box::pin(future) as std::pin::Pin<Box<dyn Future<Output = ()> + Send>>)
+    })
+}
+
+impl Container {
+    /// Register a shared binding that's constructed when the container is [booted](Container::boot), rather than when it's registered.
+    ///
+    /// Since nothing is constructed until every registration has been collected, eager singletons can depend on types registered after them. If the container has already been booted, the singleton is constructed right away.
+    pub fn singleton_eager<T: 'static + Clone + Send + Sync>(
+        &mut self,
+        factory: impl Fn(&Self) -> T + 'static + Sync + Send,
+    ) {
+        let type_id = TypeId::of::<T>();
+        if self.refuse_sealed(type_id) {
+            return;
+        }
+
+        self.singleton_lazy(factory);
+
+        if !self.eager.contains(&type_id) {
+            self.eager.push(type_id);
+        }
+        if self.booted {
+            self.construct_eager();
+        }
+    }
+
+    /// Register a callback that runs when the container is [booted](Container::boot), once the eager singletons have been constructed.
+    ///
+    /// If the container has already been booted, the callback runs right away.
+    pub fn booting(&mut self, callback: impl Fn(&mut Self) + 'static + Sync + Send) {
+        if self.refuse_frozen() {
+            return;
+        }
+
+        if self.booted {
+            callback(self);
+            return;
+        }
+
+        self.boot_callbacks.push(Arc::new(callback));
+    }
+
+    /// Register an async function that runs when the container is booted with [`Container::boot_async`], such as running migrations or completing a handshake.
+    ///
+    /// The function's arguments are resolved from the container once the providers are booted and the eager singletons are constructed, and hooks are awaited one at a time, in the order they were registered: `container.booting_async(|db: Database| async move { db.migrate().await })`.
+    pub fn booting_async<Args, F>(&mut self, hook: F)
+    where
+        F: AsyncCallable<Args> + Clone + 'static + Sync + Send,
+        F::Future: Future<Output = ()> + Send + 'static,
+    {
+        if self.refuse_frozen() {
+            return;
+        }
+
+        self.async_boot_hooks.push(async_hook(hook));
+    }
+
+    /// Boot the container like [`Container::boot`], then await the hooks registered with [`Container::booting_async`].
+    ///
+    /// This doesn't depend on any particular async runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the providers' dependencies form a cycle, or if a hook's arguments cannot be resolved, in which case the remaining hooks don't run.
+    pub async fn boot_async(&mut self) -> Result<(), Error> {
+        self.boot()?;
+
+        for hook in std::mem::take(&mut self.async_boot_hooks) {
+            hook(self)?.await;
+        }
+
+        Ok(())
+    }
+
+    /// Construct every eager singleton that's still registered, in the order they were registered.
+    pub(crate) fn construct_eager(&mut self) {
+        self.eager
+            .retain(|type_id| self.lazy_instances.contains_key(type_id));
+
+        for type_id in &self.eager {
+            if let Some(instance) = self.bindings.instance(type_id) {
+                instance(self);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Clone)]
+    struct Database;
+
+    #[derive(Clone)]
+    struct Repository;
+
+    #[test]
+    fn constructs_eager_singletons_and_runs_callbacks_on_boot() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut container = Container::new();
+
+        let built = Arc::clone(&log);
+        container.singleton_eager(move |container| {
+            container.resolve::<Database>().unwrap();
+            built.lock().unwrap().push("repository");
+
+            Repository
+        });
+        let booted = Arc::clone(&log);
+        container.booting(move |_| booted.lock().unwrap().push("callback"));
+        let built = Arc::clone(&log);
+        container.singleton_eager(move |_| {
+            built.lock().unwrap().push("database");
+
+            Database
+        });
+        assert!(log.lock().unwrap().is_empty());
+
+        container.boot().unwrap();
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["database", "repository", "callback"]
+        );
+
+        container.resolve::<Repository>().unwrap();
+        let booted = Arc::clone(&log);
+        container.booting(move |_| booted.lock().unwrap().push("late"));
+        container.boot().unwrap();
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["database", "repository", "callback", "late"]
+        );
+    }
+
+    #[test]
+    fn awaits_async_boot_hooks_after_booting() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut container = Container::new();
+        container.bind(|_| "database");
+
+        let booted = Arc::clone(&log);
+        container.booting(move |_| booted.lock().unwrap().push("callback"));
+        let migrated = Arc::clone(&log);
+        container.booting_async(move |name: &'static str| {
+            let migrated = Arc::clone(&migrated);
+
+            async move { migrated.lock().unwrap().push(name) }
+        });
+        container.booting_async(|_: u32| async {});
+
+        assert_eq!(
+            futures::executor::block_on(container.boot_async()),
+            Err(Error::NotFound)
+        );
+        assert_eq!(*log.lock().unwrap(), vec!["callback", "database"]);
+    }
+}
@@ -0,0 +1,202 @@
+use std::{
+    sync::{Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use crate::Error;
+
+/// A circuit breaker guarding a fallible binding, registered with [`Container::bind_with_circuit_breaker`](crate::Container::bind_with_circuit_breaker).
+///
+/// After `failure_threshold` consecutive failures the breaker opens, and every resolution fails with [`Error::CircuitOpen`] without calling the factory.
+/// Once the cool-down period has passed, the breaker is half-open: the next resolution is let through as a trial, while every other resolution keeps failing until it's done. If the trial succeeds the breaker closes again, otherwise (or if it panics) it re-opens for another cool-down period.
+pub struct CircuitBreaker {
+    /// The number of consecutive failures that opens the breaker.
+    failure_threshold: u32,
+    /// How long the breaker stays open before letting a resolution through.
+    cooldown: Duration,
+    /// The breaker's current state.
+    state: Mutex<State>,
+}
+
+/// The mutable state of a [`CircuitBreaker`].
+#[derive(Default)]
+struct State {
+    /// The number of consecutive failures.
+    failures: u32,
+    /// When the breaker was last opened, if it's open.
+    opened_at: Option<Instant>,
+    /// Whether a trial resolution is running while the breaker is half-open.
+    trial: bool,
+}
+
+impl CircuitBreaker {
+    /// Create a circuit breaker that opens after `failure_threshold` consecutive failures, and stays open for `cooldown`.
+    #[must_use]
+    pub fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            cooldown,
+            failure_threshold,
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Whether the breaker is currently rejecting resolutions, either because it's cooling down or because a trial resolution is running.
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        self.lock().is_open(self.cooldown)
+    }
+
+    /// Run the factory, unless the breaker is open.
+    pub(crate) fn call<T, E: Into<Box<dyn std::error::Error + Send + Sync>>>(
+        &self,
+        factory: impl FnOnce() -> Result<T, E>,
+    ) -> Result<T, Error> {
+        let mut state = self.lock();
+        if state.is_open(self.cooldown) {
+            return Err(Error::CircuitOpen);
+        }
+
+        // Once the cool-down has passed, only this resolution is let through until it's done.
+        let trial = state.opened_at.is_some().then(|| {
+            state.trial = true;
+            Trial(self)
+        });
+        drop(state);
+
+        let result = factory().map_err(Error::from_factory);
+        let mut state = self.lock();
+
+        if result.is_ok() {
+            *state = State::default();
+        } else {
+            state.failures += 1;
+
+            if trial.is_some() || state.failures >= self.failure_threshold {
+                state.opened_at = Some(Instant::now());
+            }
+        }
+        state.trial = false;
+        drop(state);
+        std::mem::forget(trial);
+
+        result
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl State {
+    /// Whether resolutions are being rejected.
+    fn is_open(&self, cooldown: Duration) -> bool {
+        self.trial
+            || self
+                .opened_at
+                .is_some_and(|opened_at| opened_at.elapsed() < cooldown)
+    }
+}
+
+/// A trial resolution of a half-open [`CircuitBreaker`], re-opening the breaker if the factory panics.
+struct Trial<'a>(&'a CircuitBreaker);
+
+impl Drop for Trial<'_> {
+    fn drop(&mut self) {
+        let mut state = self.0.lock();
+        state.trial = false;
+        state.opened_at = Some(Instant::now());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Container;
+    use std::sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc, Arc,
+    };
+
+    #[test]
+    fn opens_after_repeated_failures_and_recovers_after_cooldown() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let healthy = Arc::new(AtomicBool::new(false));
+        let mut container = Container::new();
+
+        let (counter, status) = (Arc::clone(&calls), Arc::clone(&healthy));
+        container.bind_with_circuit_breaker(
+            CircuitBreaker::new(2, Duration::from_millis(50)),
+            move |_| {
+                counter.fetch_add(1, Ordering::SeqCst);
+
+                if status.load(Ordering::SeqCst) {
+                    Ok(42_u32)
+                } else {
+                    Err(Error::NotFound)
+                }
+            },
+        );
+
+        assert_eq!(container.resolve::<u32>(), Err(Error::NotFound));
+        assert_eq!(container.resolve::<u32>(), Err(Error::NotFound));
+        assert_eq!(container.resolve::<u32>(), Err(Error::CircuitOpen));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        healthy.store(true, Ordering::SeqCst);
+        std::thread::sleep(Duration::from_millis(60));
+
+        assert_eq!(container.resolve::<u32>(), Ok(42));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn lets_a_single_trial_through_while_half_open() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let (entered, trial_started) = mpsc::channel();
+        let (finish_trial, finished) = mpsc::channel::<()>();
+        let (entered, finished) = (Mutex::new(entered), Mutex::new(finished));
+        let mut container = Container::new();
+
+        let counter = Arc::clone(&calls);
+        container.bind_with_circuit_breaker(CircuitBreaker::new(1, Duration::ZERO), move |_| {
+            if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                return Err("connection refused");
+            }
+
+            entered.lock().unwrap().send(()).unwrap();
+            finished.lock().unwrap().recv().unwrap();
+            Ok(42_u32)
+        });
+
+        let Err(Error::Factory(error)) = container.resolve::<u32>() else {
+            panic!("expected a factory error");
+        };
+        assert_eq!(error.to_string(), "connection refused");
+
+        std::thread::scope(|threads| {
+            let trial = threads.spawn(|| container.resolve::<u32>());
+
+            trial_started.recv().unwrap();
+            assert_eq!(container.resolve::<u32>(), Err(Error::CircuitOpen));
+            finish_trial.send(()).unwrap();
+
+            assert_eq!(trial.join().unwrap(), Ok(42));
+        });
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn reopens_when_the_trial_panics() {
+        let breaker = CircuitBreaker::new(1, Duration::ZERO);
+
+        assert!(breaker.call(|| Err::<(), _>("connection refused")).is_err());
+        let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            breaker.call(|| -> Result<(), Error> { panic!("trial failed") })
+        }));
+
+        assert!(panicked.is_err());
+        assert!(!breaker.lock().trial);
+        assert_eq!(breaker.call(|| Ok::<_, Error>(42)), Ok(42));
+    }
+}
@@ -0,0 +1,61 @@
+use std::{
+    any::TypeId,
+    cell::RefCell,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use crate::resolver::{Source, WeakSource};
+
+/// Hands out container versions, which are unique across every container in the process.
+static VERSIONS: AtomicU64 = AtomicU64::new(0);
+
+/// Bumped whenever a binding is registered concurrently, which doesn't change its container's version.
+#[cfg(feature = "concurrent")]
+static EPOCH: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    /// The last source resolved on this thread, along with the container version, epoch and type it was resolved for.
+    ///
+    /// Held weakly, so a removed registration (and the shared instance it holds) isn't kept alive by the threads that resolved it.
+    static LAST_RESOLVED: RefCell<Option<(u64, u64, TypeId, WeakSource)>> = const { RefCell::new(None) };
+}
+
+/// Get a version that no container has used before.
+pub fn next_version() -> u64 {
+    VERSIONS.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Get the cached source for the given type, if it was the last one resolved from this version of a container.
+pub fn get(version: u64, type_id: TypeId) -> Option<Source> {
+    let epoch = epoch();
+
+    LAST_RESOLVED.with_borrow(|last| match last {
+        Some((v, e, t, source)) if *v == version && *e == epoch && *t == type_id => {
+            source.upgrade()
+        }
+        _ => None,
+    })
+}
+
+/// Remember the source that was just resolved.
+pub fn set(version: u64, type_id: TypeId, source: &Source) {
+    LAST_RESOLVED.set(Some((version, epoch(), type_id, source.downgrade())));
+}
+
+/// Discard the source cached by every thread, after a binding was registered concurrently.
+#[cfg(feature = "concurrent")]
+pub fn invalidate() {
+    EPOCH.fetch_add(1, Ordering::Release);
+}
+
+/// The number of bindings registered concurrently so far.
+#[cfg(feature = "concurrent")]
+fn epoch() -> u64 {
+    EPOCH.load(Ordering::Acquire)
+}
+
+/// Bindings can't be registered concurrently without the `concurrent` feature.
+#[cfg(not(feature = "concurrent"))]
+const fn epoch() -> u64 {
+    0
+}
@@ -0,0 +1,147 @@
+use std::future::Future;
+
+use crate::{Container, Error};
+
+/// A function whose arguments can be resolved from the container.
+///
+/// This is implemented for every function taking up to eight arguments, as long as each argument type can be resolved from the container.
+pub trait Callable<Args> {
+    /// The function's return type.
+    type Output;
+
+    /// Resolve the function's arguments from the container, and call it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the arguments cannot be resolved.
+    fn call(self, container: &Container) -> Result<Self::Output, Error>;
+}
+
+/// An async function whose arguments can be resolved from the container.
+///
+/// This is implemented for every function returning a [`Future`] and taking up to eight arguments, as long as each argument type can be resolved from the container.
+pub trait AsyncCallable<Args> {
+    /// The future returned by the function.
+    type Future: Future;
+
+    /// Resolve the function's arguments from the container, and call it without awaiting the returned future.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the arguments cannot be resolved.
+    fn prepare(self, container: &Container) -> Result<Self::Future, Error>;
+}
+
+/// A tuple of types that can each be resolved from the container, fetched at once with [`Container::resolve_tuple`].
+///
+/// This is implemented for every tuple of up to eight types.
+pub trait ResolvableTuple: Sized {
+    /// Resolve every type in the tuple, in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the types cannot be resolved.
+    fn resolve(container: &Container) -> Result<Self, Error>;
+}
+
+macro_rules! impl_callable {
+    ($($arg:ident),*) => {
+        impl<Func, Out, $($arg: 'static),*> Callable<($($arg,)*)> for Func
+        where
+            Func: FnOnce($($arg),*) -> Out,
+        {
+            type Output = Out;
+
+            #[allow(unused_variables)]
+            fn call(self, container: &Container) -> Result<Out, Error> {
+                Ok(self($(container.resolve::<$arg>()?),*))
+            }
+        }
+
+        impl<Func, Fut, $($arg: 'static),*> AsyncCallable<($($arg,)*)> for Func
+        where
+            Func: FnOnce($($arg),*) -> Fut,
+            Fut: Future,
+        {
+            type Future = Fut;
+
+            #[allow(unused_variables)]
+            fn prepare(self, container: &Container) -> Result<Fut, Error> {
+                Ok(self($(container.resolve::<$arg>()?),*))
+            }
+        }
+
+        impl<$($arg: 'static),*> ResolvableTuple for ($($arg,)*) {
+            #[allow(unused_variables)]
+            fn resolve(container: &Container) -> Result<Self, Error> {
+                Ok(($(container.resolve::<$arg>()?,)*))
+            }
+        }
+    };
+}
+
+impl_callable!();
+impl_callable!(A);
+impl_callable!(A, B);
+impl_callable!(A, B, C);
+impl_callable!(A, B, C, D);
+impl_callable!(A, B, C, D, E);
+impl_callable!(A, B, C, D, E, F);
+impl_callable!(A, B, C, D, E, F, G);
+impl_callable!(A, B, C, D, E, F, G, H);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn container() -> Container {
+        let mut container = Container::new();
+
+        container.bind(|_| 2_u32);
+        container.bind(|_| "answer".to_string());
+
+        container
+    }
+
+    #[test]
+    fn can_call_a_function_with_resolved_arguments() {
+        let container = container();
+
+        assert_eq!(
+            container.call(|number: u32, name: String| format!("{name}: {}", number * 21)),
+            Ok("answer: 42".to_string())
+        );
+    }
+
+    #[test]
+    fn can_call_an_async_function_with_resolved_arguments() {
+        let container = container();
+
+        let result = futures::executor::block_on(container.call_async(
+            |number: u32, name: String| async move { format!("{name}: {}", number * 21) },
+        ));
+
+        assert_eq!(result, Ok("answer: 42".to_string()));
+    }
+
+    #[test]
+    fn can_resolve_a_tuple_of_types() {
+        let container = container();
+
+        assert_eq!(
+            container.resolve_tuple::<(u32, String)>(),
+            Ok((2, "answer".to_string()))
+        );
+        assert_eq!(
+            container.resolve_tuple::<(u32, u64)>(),
+            Err(Error::NotFound)
+        );
+    }
+
+    #[test]
+    fn fails_when_an_argument_cannot_be_resolved() {
+        let container = container();
+
+        assert_eq!(container.call(|number: u64| number), Err(Error::NotFound));
+    }
+}
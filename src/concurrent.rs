@@ -0,0 +1,75 @@
+use std::{
+    any::{Any, TypeId},
+    sync::Arc,
+};
+
+use crate::{bindings::Registration, cache, Container, Error, Lifetime};
+
+impl Container {
+    /// Register a binding through a shared reference, without exclusive access to the container.
+    ///
+    /// The binding is seen right away by every copy of the container sharing its bindings, including the snapshots the [facade](crate::facade::Container) resolves from. Types registered in any other way (such as singletons or connection-scoped bindings) can't be re-bound like this, since replacing them needs exclusive access to tear them down.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Frozen`] if the container is frozen, [`Error::Sealed`] if the type's registration is sealed, or [`Error::Conflict`] if the type is registered in another way.
+    pub fn bind_concurrently<T: 'static>(
+        &self,
+        factory: impl Fn(&Self) -> T + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let type_id = TypeId::of::<T>();
+
+        self.ensure_mutable()?;
+        if self.sealed.contains(&type_id) {
+            return Err(Error::Sealed);
+        }
+        if self.bindings.contains_instance(&type_id)
+            || self.emplacers.contains_key(&type_id)
+            || self.connection_bindings.contains_key(&type_id)
+            || self.thread_scoped.contains_key(&type_id)
+            || self.pools.contains_key(&type_id)
+        {
+            return Err(Error::Conflict(vec![type_id]));
+        }
+
+        let event = self.registration(type_id, Lifetime::Transient);
+        self.bindings.insert_shared(
+            type_id,
+            Registration::Transient(Arc::new(move |container: &Self| {
+                let result = factory(container);
+
+                Ok(Box::new(result) as Box<dyn Any>)
+            })),
+        );
+        cache::invalidate();
+        self.registered(event);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers_bindings_through_a_shared_reference() {
+        let mut container = Container::new();
+        container.singleton(&|_| "shared");
+
+        std::thread::scope(|threads| {
+            threads.spawn(|| container.bind_concurrently(|_| 42_u32));
+            threads.spawn(|| container.bind_concurrently(|_| 7_u64));
+        });
+
+        assert_eq!(container.resolve::<u32>(), Ok(42));
+        assert_eq!(container.resolve::<u64>(), Ok(7));
+
+        container.bind_concurrently(|_| 43_u32).unwrap();
+        assert_eq!(container.resolve::<u32>(), Ok(43));
+        assert_eq!(
+            container.bind_concurrently(|_| "bound"),
+            Err(Error::Conflict(vec![TypeId::of::<&str>()]))
+        );
+    }
+}
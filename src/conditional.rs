@@ -0,0 +1,101 @@
+use std::{
+    any::{Any, TypeId},
+    sync::Arc,
+};
+
+use crate::{resolver::Source, Container, Error};
+
+impl Container {
+    /// Register a binding that's only used while the predicate holds, checked every time the type is resolved.
+    ///
+    /// When the predicate doesn't hold, resolution falls through to the type's previous registration (which may be conditional itself), or fails with [`Error::NotFound`] if there's none. The previous registration isn't torn down, and its value can still be swapped, refreshed or taken. This lets a feature flag or environment variable flip implementations at runtime, without rebuilding the container:
+    ///
+    /// ```rust
+    /// use silhouette::Container;
+    ///
+    /// let mut container = Container::new();
+    /// container.bind(|_| "smtp");
+    /// container.bind_when(|_| std::env::var("MAILER").is_ok_and(|mailer| mailer == "log"), |_| "log");
+    ///
+    /// assert_eq!(container.resolve::<&str>(), Ok("smtp"));
+    /// ```
+    pub fn bind_when<T: 'static>(
+        &mut self,
+        predicate: impl Fn(&Self) -> bool + 'static + Sync + Send,
+        factory: impl Fn(&Self) -> T + 'static + Sync + Send,
+    ) {
+        let type_id = TypeId::of::<T>();
+        let fallback = Source::lookup(self, type_id);
+
+        self.wrap_binding(
+            type_id,
+            Arc::new(move |container: &Self| {
+                if predicate(container) {
+                    return Ok(Box::new(factory(container)) as Box<dyn Any>);
+                }
+
+                fallback
+                    .as_ref()
+                    .map_or(Err(Error::NotFound), |source| source.produce(container))
+            }),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Mutex,
+    };
+
+    #[test]
+    fn conditional_bindings_fall_through_when_their_predicate_fails() {
+        static PDF: AtomicBool = AtomicBool::new(false);
+        static CSV: AtomicBool = AtomicBool::new(false);
+
+        let mut container = Container::new();
+        container.bind_when(|_| CSV.load(Ordering::SeqCst), |_| "csv");
+        assert_eq!(container.resolve::<&str>(), Err(Error::NotFound));
+
+        container.singleton(&|_| "html");
+        container.bind_when(|_| CSV.load(Ordering::SeqCst), |_| "csv");
+        container.bind_when(|_| PDF.load(Ordering::SeqCst), |_| "pdf");
+        assert_eq!(container.resolve::<&str>(), Ok("html"));
+
+        CSV.store(true, Ordering::SeqCst);
+        assert_eq!(container.resolve::<&str>(), Ok("csv"));
+
+        PDF.store(true, Ordering::SeqCst);
+        assert_eq!(container.resolve::<&str>(), Ok("pdf"));
+    }
+
+    #[test]
+    fn conditional_bindings_keep_the_registration_they_fall_through_to() {
+        let closed = Arc::new(Mutex::new(Vec::new()));
+        let mut container = Container::new();
+
+        let recorder = Arc::clone(&closed);
+        container.singleton_with_drop(&|_| "html", move |value: &&str| {
+            recorder.lock().unwrap().push(*value);
+        });
+        container.bind_when(|_| false, |_| "csv");
+
+        assert!(closed.lock().unwrap().is_empty());
+        assert_eq!(container.swap("markdown"), Some("html"));
+        assert_eq!(container.resolve::<&str>(), Ok("markdown"));
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        container.singleton_lazy(move |_| counter.fetch_add(1, Ordering::SeqCst));
+        container.bind_when(|_| false, |_| usize::MAX);
+
+        assert_eq!(container.resolve::<usize>(), Ok(0));
+        container.refresh::<usize>().unwrap();
+        assert_eq!(container.resolve::<usize>(), Ok(1));
+
+        container.forget::<&str>();
+        assert_eq!(*closed.lock().unwrap(), vec!["markdown"]);
+    }
+}
@@ -0,0 +1,161 @@
+use std::{
+    any::{Any, TypeId},
+    sync::Arc,
+};
+
+use crate::{
+    events::{ContainerEvent, Subscribers},
+    scope::ScopedInstances,
+    Container, Error, Instance, Lifetime,
+};
+
+/// A type-erased factory for a connection-scoped value, returning a handle that clones the constructed value.
+pub(crate) type ConnectionFactory = Arc<dyn Fn(&Container) -> Instance + Sync + Send>;
+
+/// A scope tied to a long-lived connection, such as a WebSocket session or a gRPC stream.
+///
+/// Scopes are created with [`Container::connection_scope`], and are meant to be owned by the task serving the connection. Types registered with [`Container::connection_scoped`] are constructed once per scope, and dropped along with it.
+pub struct ConnectionScope {
+    /// The connection's identifier.
+    id: u64,
+    /// The values constructed for this connection.
+    instances: ScopedInstances,
+    /// The container's subscribers when the scope was created, notified when it ends.
+    subscribers: Subscribers,
+}
+
+impl ConnectionScope {
+    /// The identifier of the connection this scope belongs to.
+    #[must_use]
+    pub const fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Resolve the given type for this connection.
+    ///
+    /// Connection-scoped types are constructed the first time they're resolved in this scope, and the same value is returned afterwards. Every other type is resolved from the container as usual.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the requested type cannot be found or if the requested type cannot be cast from the binding.
+    pub fn resolve<T: 'static + Clone + Send + Sync>(
+        &self,
+        container: &Container,
+    ) -> Result<T, Error> {
+        self.instances.resolve(container)
+    }
+}
+
+impl Drop for ConnectionScope {
+    fn drop(&mut self) {
+        for type_id in self.instances.clear() {
+            self.subscribers
+                .emit(ContainerEvent::ScopedInstanceDropped { type_id });
+        }
+
+        self.subscribers
+            .emit(ContainerEvent::ConnectionScopeEnded { id: self.id });
+    }
+}
+
+impl Container {
+    /// Register a binding that is constructed once per [`ConnectionScope`] (or [`Scope`](crate::scope::Scope)).
+    ///
+    /// Connection-scoped types can only be resolved through a scope, with [`ConnectionScope::resolve`] or [`Scope::resolve`](crate::scope::Scope::resolve).
+    pub fn connection_scoped<T: 'static + Clone + Send + Sync>(
+        &mut self,
+        factory: impl Fn(&Self) -> T + 'static + Sync + Send,
+    ) {
+        let type_id = TypeId::of::<T>();
+        if self.refuse_sealed(type_id) {
+            return;
+        }
+
+        let event = self.registration(type_id, Lifetime::Scoped);
+
+        self.bindings.remove(&type_id);
+        self.lazy_instances.remove(&type_id);
+        self.swappable.remove(&type_id);
+        self.emplacers.remove(&type_id);
+        self.thread_scoped.remove(&type_id);
+        self.version = crate::cache::next_version();
+
+        self.connection_bindings.insert(
+            type_id,
+            Arc::new(move |container: &Self| -> Instance {
+                let value = factory(container);
+
+                Arc::new(move |_: &Self| Box::new(value.clone()) as Box<dyn Any>)
+            }),
+        );
+        self.registered(event);
+    }
+
+    /// Begin a scope for the connection with the given identifier.
+    ///
+    /// The returned handle caches the connection's scoped values, and disposes of them when it's dropped.
+    #[must_use]
+    pub fn connection_scope(&self, id: u64) -> ConnectionScope {
+        self.emit(ContainerEvent::ConnectionScopeStarted { id });
+
+        ConnectionScope {
+            id,
+            instances: ScopedInstances::default(),
+            subscribers: self.subscribers.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Clone)]
+    struct Session(Arc<AtomicUsize>);
+
+    impl Drop for Session {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn caches_values_per_connection_until_the_scope_drops() {
+        let built = Arc::new(AtomicUsize::new(0));
+        let dropped = Arc::new(AtomicUsize::new(0));
+
+        let mut container = Container::new();
+        container.bind(|_| 42_u32);
+
+        let (counter, drops) = (Arc::clone(&built), Arc::clone(&dropped));
+        container.connection_scoped(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            Arc::new(Session(Arc::clone(&drops)))
+        });
+
+        let first = container.connection_scope(1);
+        let second = container.connection_scope(2);
+
+        let a = first.resolve::<Arc<Session>>(&container).unwrap();
+        let b = first.resolve::<Arc<Session>>(&container).unwrap();
+        let c = second.resolve::<Arc<Session>>(&container).unwrap();
+
+        assert!(Arc::ptr_eq(&a, &b));
+        assert!(!Arc::ptr_eq(&a, &c));
+        assert_eq!(built.load(Ordering::SeqCst), 2);
+        assert_eq!(first.resolve::<u32>(&container), Ok(42));
+        assert!(matches!(
+            container.resolve::<Arc<Session>>(),
+            Err(Error::NotFound)
+        ));
+
+        drop((a, b, c));
+        drop(first);
+        assert_eq!(dropped.load(Ordering::SeqCst), 1);
+
+        drop(second);
+        assert_eq!(dropped.load(Ordering::SeqCst), 2);
+    }
+}
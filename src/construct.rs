@@ -0,0 +1,105 @@
+use std::{
+    any::{Any, TypeId},
+    sync::Arc,
+};
+
+use crate::{Container, Error};
+
+#[cfg(feature = "derive")]
+pub use silhouette_derive::Construct;
+
+/// A type that knows how to build itself from the container.
+///
+/// With the `derive` feature, `#[derive(Construct)]` implements this for structs whose fields can all be resolved from the container. Types are opted into auto-wiring with [`Container::autowire`], so resolving them no longer needs a hand-written factory:
+///
+/// ```rust
+/// use silhouette::{construct::Construct, Container, Error};
+///
+/// #[derive(Clone)]
+/// struct DbPool;
+///
+/// struct UserRepository {
+///     pool: DbPool,
+/// }
+///
+/// // with the `derive` feature, this is `#[derive(Construct)]`
+/// impl Construct for UserRepository {
+///     fn construct(container: &Container) -> Result<Self, Error> {
+///         Ok(Self {
+///             pool: container.resolve()?,
+///         })
+///     }
+/// }
+///
+/// let mut container = Container::new();
+/// container.singleton(&|_| DbPool);
+/// container.autowire::<UserRepository>();
+///
+/// assert!(container.resolve::<UserRepository>().is_ok());
+/// ```
+pub trait Construct: Sized {
+    /// Build the type, resolving its dependencies from the container.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the type's dependencies cannot be resolved.
+    fn construct(container: &Container) -> Result<Self, Error>;
+}
+
+impl Container {
+    /// Resolve the given type with its [`Construct`] implementation, unless a binding or shared instance is already registered for it.
+    ///
+    /// Stable Rust can't tell whether a requested type implements [`Construct`], so auto-wiring is opted into per type, like [`Container::enable_default_for`]. Bindings registered afterwards replace the auto-wired one.
+    pub fn autowire<T: Construct + 'static>(&mut self) {
+        let type_id = TypeId::of::<T>();
+
+        if !self.bindings.contains_key(&type_id) {
+            self.insert_binding(
+                type_id,
+                Arc::new(|container: &Self| Ok(Box::new(T::construct(container)?) as Box<dyn Any>)),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use silhouette_derive::Construct;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Config(&'static str);
+
+    #[derive(Debug, PartialEq, Construct)]
+    struct Repository {
+        config: Config,
+        retries: u32,
+    }
+
+    #[derive(Debug, PartialEq, Construct)]
+    struct Service(Repository, Config);
+
+    #[test]
+    fn autowired_types_are_constructed_from_their_dependencies() {
+        let mut container = Container::new();
+        container.singleton(&|_| Config("postgres://localhost"));
+        container.autowire::<Repository>();
+        container.autowire::<Service>();
+
+        assert!(matches!(
+            container.resolve::<Service>(),
+            Err(Error::Resolution { .. })
+        ));
+
+        container.bind(|_| 3_u32);
+        let repository = Repository {
+            config: Config("postgres://localhost"),
+            retries: 3,
+        };
+
+        assert_eq!(
+            container.resolve::<Service>(),
+            Ok(Service(repository, Config("postgres://localhost")))
+        );
+    }
+}
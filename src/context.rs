@@ -0,0 +1,77 @@
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::HashMap,
+};
+
+use crate::Error;
+
+/// Identifies a per-resolution value: the address of the container that built it, and its type.
+pub type MemoKey = (usize, TypeId);
+
+thread_local! {
+    /// The types currently being resolved on this thread, outermost first.
+    static RESOLVING: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+
+    /// The per-resolution values built during the current top-level resolution on this thread.
+    static MEMOIZED: RefCell<HashMap<MemoKey, Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+/// A type being resolved, removed from the resolution context when dropped.
+pub struct Frame {
+    /// How many types were already being resolved when this frame was entered.
+    depth: usize,
+}
+
+/// Record that the given type is being resolved on this thread.
+pub fn enter(type_name: &'static str) -> Frame {
+    let depth = RESOLVING.with_borrow_mut(|resolving| {
+        resolving.push(type_name);
+        resolving.len() - 1
+    });
+
+    Frame { depth }
+}
+
+/// Whether a type is being resolved on this thread.
+pub fn resolving() -> bool {
+    RESOLVING.with_borrow(|resolving| !resolving.is_empty())
+}
+
+/// The value built for the given key during the current top-level resolution, if any.
+pub fn memoized<T: 'static + Clone>(key: MemoKey) -> Option<T> {
+    MEMOIZED.with_borrow(|memoized| memoized.get(&key)?.downcast_ref::<T>().cloned())
+}
+
+/// Keep a value for the rest of the current top-level resolution.
+pub fn memoize<T: 'static>(key: MemoKey, value: T) {
+    MEMOIZED.with_borrow_mut(|memoized| memoized.insert(key, Box::new(value)));
+}
+
+impl Frame {
+    /// Attach the chain of requesting types to an error raised while resolving this frame's type.
+    ///
+    /// Errors from top-level resolutions are returned unchanged, as are errors that already carry a chain (which was captured closer to the failure).
+    pub fn wrap(&self, error: Error) -> Error {
+        if self.depth == 0 || matches!(error, Error::Resolution { .. }) {
+            return error;
+        }
+
+        Error::Resolution {
+            chain: RESOLVING
+                .with_borrow(|resolving| resolving[..=self.depth].iter().rev().copied().collect()),
+            source: Box::new(error),
+        }
+    }
+}
+
+impl Drop for Frame {
+    fn drop(&mut self) {
+        RESOLVING.with_borrow_mut(Vec::pop);
+
+        if self.depth == 0 {
+            // Taken out first, so values whose `Drop` resolves from the container don't find the cache borrowed.
+            drop(MEMOIZED.with_borrow_mut(std::mem::take));
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use std::{
+    any::TypeId,
+    sync::{
+        mpsc::{self, Receiver, SyncSender},
+        Arc,
+    },
+};
+
+use crate::{Container, Lifetime};
+
+/// Something that happened in a [`Container`], reported to its subscribers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerEvent {
+    /// A type was registered for the first time.
+    Registered {
+        /// The registered type.
+        type_id: TypeId,
+        /// How long the type's resolved values live.
+        lifetime: Lifetime,
+    },
+    /// A type that was already registered was registered again, replacing its previous registration.
+    Rebound {
+        /// The registered type.
+        type_id: TypeId,
+        /// How long the type's resolved values live.
+        lifetime: Lifetime,
+    },
+    /// A type was resolved from the container.
+    Resolved {
+        /// The resolved type.
+        type_id: TypeId,
+        /// Whether the resolution succeeded.
+        success: bool,
+    },
+    /// A type's registration was removed with [`Container::forget`].
+    Forgotten {
+        /// The forgotten type.
+        type_id: TypeId,
+    },
+    /// A [`Scope`](crate::scope::Scope) was created.
+    ScopeStarted,
+    /// The scoped instances were cleared, or a [`Scope`](crate::scope::Scope) was dropped, ending the scope.
+    ScopeEnded,
+    /// A value was constructed for (or stored in) a [`Scope`](crate::scope::Scope) or [`ConnectionScope`](crate::connection::ConnectionScope).
+    ScopedInstanceCreated {
+        /// The value's type.
+        type_id: TypeId,
+    },
+    /// A value cached by a [`Scope`](crate::scope::Scope) or [`ConnectionScope`](crate::connection::ConnectionScope) was dropped along with it.
+    ScopedInstanceDropped {
+        /// The value's type.
+        type_id: TypeId,
+    },
+    /// The instances scoped to a named scope were cleared with [`Container::forget_scope`], ending it.
+    NamedScopeEnded {
+        /// The scope's name.
+        scope: &'static str,
+    },
+    /// A [`ConnectionScope`](crate::connection::ConnectionScope) was created.
+    ConnectionScopeStarted {
+        /// The connection's identifier.
+        id: u64,
+    },
+    /// A [`ConnectionScope`](crate::connection::ConnectionScope) was dropped, disposing of its values.
+    ConnectionScopeEnded {
+        /// The connection's identifier.
+        id: u64,
+    },
+    /// The container started shutting down with [`Container::shutdown`], which tears its shared instances down and then flushes it.
+    Shutdown,
+    /// The container was flushed of all bindings and resolved instances.
+    Flushed,
+}
+
+/// How many events a [`Container::subscribe`] receiver holds before newer events are dropped.
+pub const SUBSCRIPTION_CAPACITY: usize = 1024;
+
+/// A callback notified of every [`ContainerEvent`].
+type Listener = Arc<dyn Fn(&ContainerEvent) + Sync + Send>;
+
+/// Everything notified of a container's activity.
+#[derive(Clone, Default)]
+pub(crate) struct Subscribers {
+    /// The senders for every [`Container::subscribe`] receiver.
+    channels: Vec<SyncSender<ContainerEvent>>,
+    /// The callbacks registered with [`Container::on_event`].
+    listeners: Vec<Listener>,
+}
+
+impl Subscribers {
+    /// Send an event to every subscriber, ignoring the ones that have hung up or fallen too far behind.
+    pub(crate) fn emit(&self, event: ContainerEvent) {
+        for channel in &self.channels {
+            let _ = channel.try_send(event);
+        }
+        for listener in &self.listeners {
+            listener(&event);
+        }
+    }
+}
+
+impl Container {
+    /// Subscribe to the container's activity.
+    ///
+    /// The receiver gets a [`ContainerEvent`] for every registration, resolution and scope change from now on. Once the container (and every copy made of it) is dropped, the receiver is disconnected.
+    ///
+    /// The receiver holds up to [`SUBSCRIPTION_CAPACITY`] events. Events are dropped while it's full instead of blocking the container, so a receiver that's never read doesn't grow without bound.
+    pub fn subscribe(&mut self) -> Receiver<ContainerEvent> {
+        let (sender, receiver) = mpsc::sync_channel(SUBSCRIPTION_CAPACITY);
+        self.subscribers.channels.push(sender);
+
+        receiver
+    }
+
+    /// Register a callback that's notified of the container's activity as it happens.
+    ///
+    /// Unlike [`Container::subscribe`], the callback runs on the thread that caused each event, which suits logging or tracing integrations that need the current span (such as APM tooling tracking a request's scoped resources).
+    pub fn on_event(&mut self, listener: impl Fn(&ContainerEvent) + 'static + Sync + Send) {
+        self.subscribers.listeners.push(Arc::new(listener));
+    }
+
+    /// Send an event to every subscriber, ignoring the ones that have hung up.
+    pub(crate) fn emit(&self, event: ContainerEvent) {
+        self.subscribers.emit(event);
+    }
+
+    /// Describe a registration for the given type that's about to happen, which is a rebind if the type is already registered.
+    pub(crate) fn registration(&self, type_id: TypeId, lifetime: Lifetime) -> ContainerEvent {
+        if self.bindings.contains_key(&type_id)
+            || self.emplacers.contains_key(&type_id)
+            || self.connection_bindings.contains_key(&type_id)
+        {
+            ContainerEvent::Rebound { type_id, lifetime }
+        } else {
+            ContainerEvent::Registered { type_id, lifetime }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn reports_container_activity_to_subscribers() {
+        let mut container = Container::new();
+        let events = container.subscribe();
+
+        container.bind(|_| 1_u32);
+        container.scoped(&|_| 2_u32);
+        assert_eq!(container.resolve::<u32>(), Ok(2));
+        assert_eq!(container.resolve::<u64>(), Err(Error::NotFound));
+        container.forget_scoped_instances();
+        container.shutdown();
+        drop(container);
+
+        assert_eq!(
+            events.iter().collect::<Vec<_>>(),
+            vec![
+                ContainerEvent::Registered {
+                    type_id: TypeId::of::<u32>(),
+                    lifetime: Lifetime::Transient,
+                },
+                ContainerEvent::Rebound {
+                    type_id: TypeId::of::<u32>(),
+                    lifetime: Lifetime::Scoped,
+                },
+                ContainerEvent::Resolved {
+                    type_id: TypeId::of::<u32>(),
+                    success: true,
+                },
+                ContainerEvent::Resolved {
+                    type_id: TypeId::of::<u64>(),
+                    success: false,
+                },
+                ContainerEvent::ScopeEnded,
+                ContainerEvent::Shutdown,
+                ContainerEvent::Flushed,
+            ]
+        );
+    }
+
+    #[test]
+    fn drops_events_once_a_subscriber_falls_behind() {
+        let mut container = Container::new();
+        let events = container.subscribe();
+
+        for _ in 0..=SUBSCRIPTION_CAPACITY {
+            let _ = container.resolve::<u32>();
+        }
+        container.flush();
+        drop(container);
+
+        let events = events.iter().collect::<Vec<_>>();
+        assert_eq!(events.len(), SUBSCRIPTION_CAPACITY);
+        assert!(!events.contains(&ContainerEvent::Flushed));
+    }
+
+    #[test]
+    fn reports_scope_lifecycles_to_listeners() {
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut container = Container::new();
+        container.connection_scoped(|_| 1_u64);
+
+        let log = Arc::clone(&events);
+        container.on_event(move |event| log.lock().unwrap().push(*event));
+
+        let scope = container.create_scope();
+        scope.instance(2_u32);
+        assert_eq!(scope.resolve::<u64>(), Ok(1));
+        drop(scope);
+
+        let mut events = events.lock().unwrap().clone();
+        events[3..5].sort_by_key(|event| {
+            matches!(event, ContainerEvent::ScopedInstanceDropped { type_id } if *type_id == TypeId::of::<u64>())
+        });
+        assert_eq!(
+            events,
+            vec![
+                ContainerEvent::ScopeStarted,
+                ContainerEvent::ScopedInstanceCreated {
+                    type_id: TypeId::of::<u32>(),
+                },
+                ContainerEvent::ScopedInstanceCreated {
+                    type_id: TypeId::of::<u64>(),
+                },
+                ContainerEvent::ScopedInstanceDropped {
+                    type_id: TypeId::of::<u32>(),
+                },
+                ContainerEvent::ScopedInstanceDropped {
+                    type_id: TypeId::of::<u64>(),
+                },
+                ContainerEvent::ScopeEnded,
+            ]
+        );
+    }
+}
@@ -1,25 +1,470 @@
-use std::sync::RwLock;
+#[cfg(not(feature = "single_threaded"))]
+use std::sync::{Mutex, MutexGuard, TryLockError};
+use std::{
+    cell::{Cell, RefCell},
+    future::Future,
+    mem::MaybeUninit,
+    ops::{Deref, DerefMut},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, PoisonError, Weak,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::call::{AsyncCallable, Callable};
+
+/// How many times the global container has been modified, identifying its current snapshot.
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// A snapshot of the container, along with the generation it was taken at.
+type Published = Option<(u64, Arc<crate::Container>)>;
+
+/// The latest snapshot of the container, along with the generation it was taken at.
+///
+/// It's the only strong reference to the snapshot outside of ongoing resolutions, so discarding it releases the shared instances the snapshot holds on to.
+#[cfg(not(feature = "single_threaded"))]
+static PUBLISHED: Mutex<Published> = Mutex::new(None);
+
+#[cfg(feature = "single_threaded")]
+thread_local! {
+    /// The latest snapshot of this thread's container, along with the generation it was taken at.
+    static PUBLISHED: RefCell<Published> = const { RefCell::new(None) };
+}
+
+thread_local! {
+    /// Whether this thread is modifying the container through the facade.
+    static WRITING: Cell<bool> = const { Cell::new(false) };
+
+    /// The snapshot this thread resolved from last, along with the generation it was taken at. Held weakly, so only the published snapshot keeps it alive.
+    static SNAPSHOT: RefCell<Option<(u64, Weak<crate::Container>)>> = const { RefCell::new(None) };
+}
+
+/// Discard the published snapshot, as the global container is about to be modified.
+///
+/// Called with the global container locked for writing, so no thread is taking a snapshot of it.
+pub(crate) fn retire_snapshot() {
+    #[cfg(not(feature = "single_threaded"))]
+    let retired = PUBLISHED
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take();
+    #[cfg(feature = "single_threaded")]
+    let retired = PUBLISHED.take();
+
+    drop(retired);
+}
+
+/// Make every thread take a new snapshot, as the global container was modified.
+pub(crate) fn advance_generation() {
+    GENERATION.fetch_add(1, Ordering::AcqRel);
+}
+
+#[cfg(all(feature = "tokio", debug_assertions))]
+thread_local! {
+    /// Whether this thread holds the lock on the container behind [`AsyncContainer`].
+    static HOLDING_ASYNC: Cell<bool> = const { Cell::new(false) };
+}
+
+/// A read lock on the global container.
+type ReadGuard = crate::global::GlobalReadGuard<'static>;
+
+/// A write lock on the global container.
+type WriteGuard = crate::global::GlobalWriteGuard<'static>;
+
+/// Write access to the global container through the facade.
+struct Writer(WriteGuard);
+
+impl Deref for Writer {
+    type Target = crate::Container;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Writer {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Drop for Writer {
+    fn drop(&mut self) {
+        WRITING.set(false);
+    }
+}
+
+/// Keep making an attempt to take a lock until it stops reporting that the lock is held elsewhere, giving up with [`Error::Timeout`] at the deadline.
+#[cfg(not(feature = "single_threaded"))]
+fn retry_until<G>(
+    deadline: Instant,
+    mut attempt: impl FnMut() -> Option<Result<G, Error>>,
+) -> Result<G, Error> {
+    loop {
+        if let Some(result) = attempt() {
+            return result;
+        }
+        if Instant::now() >= deadline {
+            return Err(Error::Timeout);
+        }
+
+        std::thread::yield_now();
+    }
+}
 
 /// Static interface for the container.
+///
+/// Resolving doesn't lock the container: each thread resolves from an immutable snapshot, which is only taken again after the container was modified, either through the static interface or by locking [`crate::Container::get_instance`] directly. The snapshot is discarded as soon as the container is locked for writing, so it doesn't keep shared instances that are taken, forgotten or flushed alive.
+///
+/// Factories can resolve their dependencies (or even register bindings) through the static interface while they run. Resolving through the static interface from code that runs while the container is being modified on the same thread, such as a singleton's factory or a service provider, returns [`Error::Reentrant`] instead of deadlocking, as does modifying it. Use the container passed to those callbacks instead.
 pub struct Container {}
 
 impl Container {
-    fn get_instance() -> &'static RwLock<crate::Container> {
+    fn get_instance() -> &'static crate::GlobalLock {
         crate::Container::get_instance()
     }
 
+    /// Get a snapshot of the container to resolve from, re-using this thread's snapshot unless the container was modified since it was taken.
+    fn read() -> Result<Arc<crate::Container>, Error> {
+        Self::read_until(None)
+    }
+
+    /// Get a snapshot of the container to resolve from, giving up at the deadline if a new one has to be taken while another thread holds the container.
+    fn read_until(deadline: Option<Instant>) -> Result<Arc<crate::Container>, Error> {
+        if WRITING.get() {
+            return Err(Error::Reentrant);
+        }
+
+        let generation = GENERATION.load(Ordering::Acquire);
+        let cached = SNAPSHOT.with_borrow(|snapshot| {
+            snapshot
+                .as_ref()
+                .filter(|(taken, _)| *taken == generation)
+                .and_then(|(_, container)| container.upgrade())
+        });
+        if let Some(container) = cached {
+            return Ok(container);
+        }
+
+        let (generation, container) = Self::publish(deadline)?;
+        SNAPSHOT.set(Some((generation, Arc::downgrade(&container))));
+
+        Ok(container)
+    }
+
+    /// Get the latest snapshot of the container, taking a new one if it was modified since the last one was published.
+    #[cfg(not(feature = "single_threaded"))]
+    fn publish(deadline: Option<Instant>) -> Result<(u64, Arc<crate::Container>), Error> {
+        // Locked in the same order as writers, which discard the published snapshot while holding the container.
+        let container = Self::lock_read(deadline)?;
+        let mut published = Self::lock_published(deadline)?;
+        let generation = GENERATION.load(Ordering::Acquire);
+        if let Some((taken, snapshot)) = &*published {
+            if *taken == generation {
+                return Ok((generation, Arc::clone(snapshot)));
+            }
+        }
+
+        let snapshot = Arc::new(container.share());
+        *published = Some((generation, Arc::clone(&snapshot)));
+        drop(published);
+        drop(container);
+
+        Ok((generation, snapshot))
+    }
+
+    /// Get the latest snapshot of this thread's container, taking a new one if it was modified since the last one was published.
+    #[cfg(feature = "single_threaded")]
+    fn publish(deadline: Option<Instant>) -> Result<(u64, Arc<crate::Container>), Error> {
+        let container = Self::lock_read(deadline)?;
+        let generation = GENERATION.load(Ordering::Acquire);
+        let published = PUBLISHED.with_borrow(|published| {
+            published
+                .as_ref()
+                .filter(|(taken, _)| *taken == generation)
+                .map(|(_, snapshot)| Arc::clone(snapshot))
+        });
+        if let Some(snapshot) = published {
+            return Ok((generation, snapshot));
+        }
+
+        let snapshot = Arc::new(container.share());
+        PUBLISHED.set(Some((generation, Arc::clone(&snapshot))));
+
+        Ok((generation, snapshot))
+    }
+
+    /// Get write access to the container, unless this thread is already modifying it.
+    fn write() -> Result<Writer, Error> {
+        Self::write_until(None)
+    }
+
+    /// Get write access to the container to remove instances from it, discarding the snapshot first so it doesn't keep them alive.
+    fn write_removing() -> Result<Writer, Error> {
+        let writer = Self::write()?;
+        retire_snapshot();
+
+        Ok(writer)
+    }
+
+    /// Get write access to the container, giving up at the deadline if another thread still holds it.
+    fn write_until(deadline: Option<Instant>) -> Result<Writer, Error> {
+        if WRITING.get() {
+            return Err(Error::Reentrant);
+        }
+
+        let guard = Self::lock_write(deadline)?;
+        WRITING.set(true);
+
+        Ok(Writer(guard))
+    }
+
+    /// Lock the latest snapshot, waiting at most until the deadline for another thread to publish its own.
+    ///
+    /// The container must already be locked for reading.
+    #[cfg(not(feature = "single_threaded"))]
+    fn lock_published(deadline: Option<Instant>) -> Result<MutexGuard<'static, Published>, Error> {
+        let Some(deadline) = deadline else {
+            return Ok(PUBLISHED.lock().unwrap_or_else(PoisonError::into_inner));
+        };
+
+        retry_until(deadline, || match PUBLISHED.try_lock() {
+            Ok(published) => Some(Ok(published)),
+            Err(TryLockError::Poisoned(poisoned)) => Some(Ok(poisoned.into_inner())),
+            Err(TryLockError::WouldBlock) => None,
+        })
+    }
+
+    /// Lock the global container for reading, waiting at most until the deadline.
+    #[cfg(not(any(feature = "parking_lot", feature = "single_threaded")))]
+    fn lock_read(deadline: Option<Instant>) -> Result<ReadGuard, Error> {
+        let Some(deadline) = deadline else {
+            return Self::recover(Self::get_instance().read());
+        };
+
+        retry_until(deadline, || match Self::get_instance().try_read() {
+            Ok(guard) => Some(Ok(guard)),
+            Err(TryLockError::Poisoned(poisoned)) => Some(Self::recover(Err(poisoned))),
+            Err(TryLockError::WouldBlock) => None,
+        })
+    }
+
+    /// Lock the global container for reading, waiting at most until the deadline.
+    #[cfg(any(feature = "parking_lot", feature = "single_threaded"))]
+    fn lock_read(deadline: Option<Instant>) -> Result<ReadGuard, Error> {
+        deadline.map_or_else(
+            || Self::recover(Self::get_instance().read()),
+            |deadline| {
+                Self::get_instance()
+                    .try_read_until(deadline)
+                    .ok_or(Error::Timeout)
+            },
+        )
+    }
+
+    /// Lock the global container for writing, waiting at most until the deadline.
+    #[cfg(not(any(feature = "parking_lot", feature = "single_threaded")))]
+    fn lock_write(deadline: Option<Instant>) -> Result<WriteGuard, Error> {
+        let Some(deadline) = deadline else {
+            return Self::recover(Self::get_instance().write());
+        };
+
+        retry_until(deadline, || match Self::get_instance().try_write() {
+            Ok(guard) => Some(Ok(guard)),
+            Err(TryLockError::Poisoned(poisoned)) => Some(Self::recover(Err(poisoned))),
+            Err(TryLockError::WouldBlock) => None,
+        })
+    }
+
+    /// Lock the global container for writing, waiting at most until the deadline.
+    #[cfg(any(feature = "parking_lot", feature = "single_threaded"))]
+    fn lock_write(deadline: Option<Instant>) -> Result<WriteGuard, Error> {
+        deadline.map_or_else(
+            || Self::recover(Self::get_instance().write()),
+            |deadline| {
+                Self::get_instance()
+                    .try_write_until(deadline)
+                    .ok_or(Error::Timeout)
+            },
+        )
+    }
+
+    /// Take the guard of a lock that a thread panicked while holding, clearing the poison so that only this access fails.
+    fn recover<G>(result: Result<G, PoisonError<G>>) -> Result<G, Error> {
+        result.map_err(|poisoned| {
+            drop(poisoned);
+            Self::get_instance().clear_poison();
+
+            Error::Poisoned
+        })
+    }
+
     /// Register a binding with the container.
     ///
     /// # Errors
     ///
-    /// This function will return an error if it fails to get write access to the container.
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
     pub fn bind<T: 'static>(
         factory: impl Fn(&crate::Container) -> T + 'static + Sync + Send,
     ) -> Result<(), Error> {
-        let container = Self::get_instance();
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.bind(factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
 
-        let mut container_w = container.write().map_err(|_| Error::Lock)?;
+    /// Register a binding with the container, giving up if another thread holds the container for longer than the timeout.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if it times out waiting for it, if the type's registration is sealed, or if the container is frozen.
+    pub fn bind_timeout<T: 'static>(
+        factory: impl Fn(&crate::Container) -> T + 'static + Sync + Send,
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write_until(Instant::now().checked_add(timeout))?;
+        container_w.ensure_mutable()?;
         container_w.bind(factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a binding with the container, without waiting for exclusive access to it.
+    ///
+    /// The binding is added to the bindings shared by every thread's snapshot, so resolutions running at the same time aren't held up, and see it right away.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get read access to the container, if the type's registration is sealed, if the container is frozen, or if the type is registered in another way.
+    #[cfg(feature = "concurrent")]
+    pub fn bind_concurrently<T: 'static>(
+        factory: impl Fn(&crate::Container) -> T + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let container_r = Self::read()?;
+        container_r.bind_concurrently(factory)?;
+        drop(container_r);
+
+        Ok(())
+    }
+
+    /// Register a binding with the container, along with metadata describing it.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn bind_with_meta<T: 'static>(
+        metadata: crate::metadata::Metadata,
+        factory: impl Fn(&crate::Container) -> T + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.bind_with_meta(metadata, factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Attach metadata to the given type, whatever its lifetime.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, or if the container is frozen.
+    pub fn describe<T: 'static>(metadata: crate::metadata::Metadata) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.describe::<T>(metadata);
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a binding with the given lifetime, so it can be chosen at runtime.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn register<T: 'static + Clone + Send + Sync>(
+        lifetime: crate::Lifetime,
+        factory: impl Fn(&crate::Container) -> T + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.register(lifetime, factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a binding that's built at most once per top-level resolution.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn bind_per_resolution<T: 'static + Clone>(
+        factory: impl Fn(&crate::Container) -> T + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.bind_per_resolution(factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a binding whose factory runs at most once, handing its value to the first resolution.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn bind_once<T: 'static>(
+        factory: impl FnOnce(&crate::Container) -> T + 'static + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.bind_once(factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a binding whose factory can fail.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn try_bind<T: 'static, E: Into<Box<dyn std::error::Error + Send + Sync>>>(
+        factory: impl Fn(&crate::Container) -> Result<T, E> + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.try_bind(factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a callback that's notified with the new value whenever the registration for the given type is replaced.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, or if the container is frozen.
+    pub fn rebinding<T: 'static>(
+        callback: impl Fn(&crate::Container, T) + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.rebinding(callback);
         drop(container_w);
 
         Ok(())
@@ -29,14 +474,1432 @@ impl Container {
     ///
     /// # Errors
     ///
-    /// This function will return an error if it fails to get write access to the container.
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
     pub fn bind_if<T: 'static>(
         factory: impl Fn(&crate::Container) -> T + 'static + Sync + Send,
     ) -> Result<(), Error> {
-        let container = Self::get_instance();
-
-        let mut container_w = container.write().map_err(|_| Error::Lock)?;
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
         container_w.bind_if(factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a binding for the given type, keyed by the zero-sized marker type `K`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn bind_keyed<T: 'static, K: 'static>(
+        factory: impl Fn(&crate::Container) -> T + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.bind_keyed::<T, K>(factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a shared binding for the given type, keyed by the zero-sized marker type `K`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn singleton_keyed<T: 'static + Clone + Send + Sync, K: 'static>(
+        factory: &(impl Fn(&crate::Container) -> T + 'static),
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.singleton_keyed::<T, K>(factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a binding that's only used while the predicate holds, falling through to the type's previous registration otherwise.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn bind_when<T: 'static>(
+        predicate: impl Fn(&crate::Container) -> bool + 'static + Sync + Send,
+        factory: impl Fn(&crate::Container) -> T + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.bind_when(predicate, factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Resolve the given type with its [`Default`] implementation, unless a binding or shared instance is already registered for it.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn enable_default_for<T: 'static + Default>() -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.enable_default_for::<T>();
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Resolve the given type with its [`Construct`](crate::construct::Construct) implementation, unless a binding or shared instance is already registered for it.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn autowire<T: crate::construct::Construct + 'static>() -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.autowire::<T>();
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Make `Option<T>` resolvable, resolving to `None` when `T` isn't registered instead of failing.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn optional<T: 'static>() -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.optional::<T>();
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a binding whose factory runs at most `limit` times concurrently.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn bind_limited<T: 'static>(
+        limit: std::num::NonZeroUsize,
+        factory: impl Fn(&crate::Container) -> T + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.bind_limited(limit, factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a fallible binding, guarded by a circuit breaker.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn bind_with_circuit_breaker<
+        T: 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    >(
+        breaker: crate::breaker::CircuitBreaker,
+        factory: impl Fn(&crate::Container) -> Result<T, E> + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.bind_with_circuit_breaker(breaker, factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a binding that constructs its value directly into caller-provided storage.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn bind_in_place<T: 'static>(
+        factory: impl for<'a> Fn(&crate::Container, &'a mut MaybeUninit<T>) -> &'a mut T
+            + 'static
+            + Sync
+            + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.bind_in_place(factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a scoped binding in the container.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn scoped<T: 'static + Clone + Send + Sync>(
+        factory: &(impl Fn(&crate::Container) -> T + 'static),
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.scoped(factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a scoped binding if it hasn't already been registered.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn scoped_if<T: 'static + Clone + Send + Sync>(
+        factory: &(impl Fn(&crate::Container) -> T + 'static),
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.scoped_if(factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a binding scoped to the named scope, such as `"request"`, `"session"` or `"job"`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn scoped_in<T: 'static + Clone + Send + Sync>(
+        scope: &'static str,
+        factory: &(impl Fn(&crate::Container) -> T + 'static),
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.scoped_in(scope, factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a binding that is constructed once per thread, until the thread's scope ends.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn thread_scoped<T: 'static + Clone>(
+        factory: impl Fn(&crate::Container) -> T + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.thread_scoped(factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a shared binding in the container.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn singleton<T: 'static + Clone + Send + Sync>(
+        factory: &(impl Fn(&crate::Container) -> T + 'static),
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.singleton(factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a shared binding whose factory is kept, so [`Container::refresh`] can construct the value again.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn singleton_refreshable<T: 'static + Clone + Send + Sync>(
+        factory: impl Fn(&crate::Container) -> T + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.singleton_refreshable(factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a shared binding in the container, giving up if another thread holds the container for longer than the timeout.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if it times out waiting for it, if the type's registration is sealed, or if the container is frozen.
+    pub fn singleton_timeout<T: 'static + Clone + Send + Sync>(
+        factory: &(impl Fn(&crate::Container) -> T + 'static),
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write_until(Instant::now().checked_add(timeout))?;
+        container_w.ensure_mutable()?;
+        container_w.singleton(factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a shared binding, along with a cleanup callback that runs when the instance is removed.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn singleton_with_drop<T: 'static + Clone + Send + Sync>(
+        factory: &(impl Fn(&crate::Container) -> T + 'static),
+        teardown: impl Fn(&T) + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.singleton_with_drop(factory, teardown);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a shared binding whose factory can fail.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the factory fails, if the type's registration is sealed, or if the container is frozen.
+    pub fn try_singleton<
+        T: 'static + Clone + Send + Sync,
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    >(
+        factory: &(impl Fn(&crate::Container) -> Result<T, E> + 'static),
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.try_singleton(factory)?;
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register an already-constructed value as a shared instance.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn instance<T: 'static + Clone + Send + Sync>(value: T) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.instance(value);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Atomically replace the value of a shared instance, returning the previous value.
+    ///
+    /// Only needs read access to the container, so other threads can keep resolving while the value is swapped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container.
+    pub fn swap<T: 'static>(value: T) -> Result<Option<T>, Error> {
+        let container_r = Self::read()?;
+        Ok(container_r.swap(value))
+    }
+
+    /// Remove the shared instance registered for the given type from the container, handing its value back to the caller.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn take<T: 'static + Send + Sync>() -> Result<Option<T>, Error> {
+        let mut container_w = Self::write_removing()?;
+        container_w.ensure_mutable()?;
+        let value = container_w.take::<T>();
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(value)
+    }
+
+    /// Mutate the value of a shared instance in place, returning the closure's result.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container, or if no shared instance is registered for the requested type.
+    pub fn resolve_mut<T: 'static, R>(mutate: impl FnOnce(&mut T) -> R) -> Result<R, Error> {
+        let container_r = Self::read()?;
+        Ok(container_r.resolve_mut(mutate)?)
+    }
+
+    /// Register a pool of at most `max` reusable instances, created on demand by the factory.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, or if the container is frozen.
+    pub fn pooled<T: 'static + Send>(
+        max: std::num::NonZeroUsize,
+        factory: impl Fn(&crate::Container) -> T + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.pooled(max, factory);
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a shared binding that is only constructed the first time it's resolved.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn singleton_lazy<T: 'static + Clone + Send + Sync>(
+        factory: impl Fn(&crate::Container) -> T + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.singleton_lazy(factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a shared binding that's stored in an [`Arc`](std::sync::Arc), and resolved as `Arc<T>`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn singleton_arc<T: 'static + Send + Sync>(
+        factory: &(impl Fn(&crate::Container) -> T + 'static),
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.singleton_arc(factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a shared binding that's resolved as `Arc<T>`, but only kept alive by the values handed out.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn singleton_weak<T: 'static + Send + Sync>(
+        factory: impl Fn(&crate::Container) -> T + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.singleton_weak(factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a shared binding if it hasn't already been registered.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn singleton_if<T: 'static + Clone + Send + Sync>(
+        factory: &(impl Fn(&crate::Container) -> T + 'static),
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.singleton_if(factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register an additional binding for the given type, alongside any registered before it.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, or if the container is frozen.
+    pub fn bind_many<T: 'static>(
+        factory: impl Fn(&crate::Container) -> T + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.bind_many(factory);
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register `Alias` as an alias of `Target`, so resolving `Alias` resolves `Target` and converts it.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn alias<Alias: From<Target> + 'static, Target: 'static>() -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.alias::<Alias, Target>();
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a binding whose factory receives arguments supplied by the caller, along with the container.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, or if the container is frozen.
+    pub fn bind_with<T: 'static, Args: 'static>(
+        factory: impl Fn(&crate::Container, Args) -> T + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.bind_with(factory);
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a binding that's shared per key, so every key (such as a tenant identifier) gets its own cached instance.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, or if the container is frozen.
+    pub fn scoped_by_key<T, K>(
+        factory: impl Fn(&crate::Container, &K) -> T + 'static + Sync + Send,
+    ) -> Result<(), Error>
+    where
+        T: 'static + Clone + Send + Sync,
+        K: 'static + Eq + std::hash::Hash + Send + Sync,
+    {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.scoped_by_key(factory);
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Decorate every value resolved for the given type, wrapping or modifying what its binding produces.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, or if the container is frozen.
+    pub fn extend<T: 'static>(
+        extender: impl Fn(T, &crate::Container) -> T + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.extend(extender);
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a callback that receives every value of the given type as soon as it's resolved.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, or if the container is frozen.
+    pub fn resolving<T: 'static>(
+        hook: impl Fn(&mut T, &crate::Container) + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.resolving(hook);
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a callback that receives every value of the given type once the resolving callbacks have run.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, or if the container is frozen.
+    pub fn after_resolving<T: 'static>(
+        hook: impl Fn(&mut T, &crate::Container) + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.after_resolving(hook);
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a binding for the given type under a name.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, or if the container is frozen.
+    pub fn bind_named<T: 'static>(
+        name: &'static str,
+        factory: impl Fn(&crate::Container) -> T + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.bind_named(name, factory);
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Add the given type to a tag.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, or if the container is frozen.
+    pub fn tag<T: 'static>(tag: &'static str) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.tag::<T>(tag);
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Whether a binding, shared instance, pool or connection-scoped binding is registered for the given type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container.
+    pub fn has<T: 'static>() -> Result<bool, Error> {
+        let container_r = Self::read()?;
+        Ok(container_r.has::<T>())
+    }
+
+    /// Whether the given type is registered as a singleton.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container.
+    pub fn is_singleton<T: 'static>() -> Result<bool, Error> {
+        let container_r = Self::read()?;
+        Ok(container_r.is_singleton::<T>())
+    }
+
+    /// Whether the given type is registered as scoped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container.
+    pub fn is_scoped<T: 'static>() -> Result<bool, Error> {
+        let container_r = Self::read()?;
+        Ok(container_r.is_scoped::<T>())
+    }
+
+    /// The metadata attached to the given type, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container.
+    pub fn metadata<T: 'static>() -> Result<Option<crate::metadata::Metadata>, Error> {
+        let container_r = Self::read()?;
+        Ok(container_r.metadata::<T>().cloned())
+    }
+
+    /// Every described service, as its type name and metadata, sorted by type name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container.
+    pub fn services() -> Result<Vec<(&'static str, crate::metadata::Metadata)>, Error> {
+        let container_r = Self::read()?;
+        Ok(container_r
+            .services()
+            .into_iter()
+            .map(|(name, metadata)| (name, metadata.clone()))
+            .collect())
+    }
+
+    /// Resolve the given type from the container.
+    ///
+    /// Inside a [task scope](crate::task::scope), the task's own values are returned first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container, if the requested type cannot be found, or if the requested type cannot be cast from the binding.
+    pub fn resolve<T: 'static>() -> Result<T, Error> {
+        let container_r = Self::read()?;
+        if let Some(value) = crate::task::resolve_any(&container_r, std::any::TypeId::of::<T>()) {
+            return Ok(value?
+                .downcast::<T>()
+                .map(|value| *value)
+                .map_err(|_| crate::Error::CastFailed)?);
+        }
+
+        Ok(container_r.resolve()?)
+    }
+
+    /// Resolve the given type from the container, giving up if another thread holds the container for longer than the timeout.
+    ///
+    /// Resolving only waits when the container was modified since this thread last resolved from it, as a new snapshot has to be taken.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container, if it times out waiting for it, if the requested type cannot be found, or if the requested type cannot be cast from the binding.
+    pub fn resolve_timeout<T: 'static>(timeout: Duration) -> Result<T, Error> {
+        let container_r = Self::read_until(Instant::now().checked_add(timeout))?;
+        if let Some(value) = crate::task::resolve_any(&container_r, std::any::TypeId::of::<T>()) {
+            return Ok(value?
+                .downcast::<T>()
+                .map(|value| *value)
+                .map_err(|_| crate::Error::CastFailed)?);
+        }
+
+        Ok(container_r.resolve()?)
+    }
+
+    /// Resolve the given type with per-call arguments, using the factory registered with [`Container::bind_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container, or if no factory taking `Args` is registered for the requested type.
+    pub fn resolve_with<T: 'static, Args: 'static>(args: Args) -> Result<T, Error> {
+        let container_r = Self::read()?;
+        Ok(container_r.resolve_with(args)?)
+    }
+
+    /// Resolve the instance of the given type for the given key, constructing it if the key hasn't been resolved yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container, or if the type isn't shared per key of the given type.
+    pub fn resolve_for<T, K>(key: K) -> Result<T, Error>
+    where
+        T: 'static + Clone + Send + Sync,
+        K: 'static + Eq + std::hash::Hash + Send + Sync,
+    {
+        let container_r = Self::read()?;
+        Ok(container_r.resolve_for(key)?)
+    }
+
+    /// Discard the instance of the given type cached for the given key, returning whether one was cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container.
+    pub fn evict_key<T, K>(key: &K) -> Result<bool, Error>
+    where
+        T: 'static + Clone + Send + Sync,
+        K: 'static + Eq + std::hash::Hash + Send + Sync,
+    {
+        let container_r = Self::read()?;
+        Ok(container_r.evict_key::<T, K>(key))
+    }
+
+    /// Resolve the given type's binding keyed by the marker type `K`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container, or if no binding is registered for the type under that key.
+    pub fn resolve_keyed<T: 'static, K: 'static>() -> Result<T, Error> {
+        let container_r = Self::read()?;
+        Ok(container_r.resolve_keyed::<T, K>()?)
+    }
+
+    /// Resolve the binding registered for the given type under a name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container, or if no binding is registered for the type under that name.
+    pub fn resolve_named<T: 'static>(name: &str) -> Result<T, Error> {
+        let container_r = Self::read()?;
+        Ok(container_r.resolve_named(name)?)
+    }
+
+    /// Resolve every binding registered for the given type with [`Container::bind_many`], in the order they were registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container, or if any of the bindings cannot be resolved.
+    pub fn resolve_all<T: 'static>() -> Result<Vec<T>, Error> {
+        let container_r = Self::read()?;
+        Ok(container_r.resolve_all()?)
+    }
+
+    /// Resolve the given type from the container, or produce a fallback value if it isn't registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container, or if the type is registered but can't be resolved.
+    pub fn resolve_or_else<T: 'static>(fallback: impl FnOnce() -> T) -> Result<T, Error> {
+        let container_r = Self::read()?;
+        Ok(container_r.resolve_or_else(fallback)?)
+    }
+
+    /// Resolve the given type from the container, or return `None` if it isn't registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container, or if the type is registered but can't be resolved.
+    pub fn resolve_optional<T: 'static>() -> Result<Option<T>, Error> {
+        let container_r = Self::read()?;
+        Ok(container_r.resolve_optional()?)
+    }
+
+    /// Resolve the given type from the container, or use its [`Default`] implementation if it isn't registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container, or if the type is registered but can't be resolved.
+    pub fn resolve_or_default<T: 'static + Default>() -> Result<T, Error> {
+        let container_r = Self::read()?;
+        Ok(container_r.resolve_or_default()?)
+    }
+
+    /// Resolve every type under the given tag, in the order they were tagged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container, or if any of the tagged types cannot be resolved as the requested type.
+    pub fn resolve_tagged<T: 'static>(tag: &str) -> Result<Vec<T>, Error> {
+        let container_r = Self::read()?;
+        Ok(container_r.resolve_tagged(tag)?)
+    }
+
+    /// Check an instance out of the pool registered for the given type, waiting for one to be returned if the pool is exhausted.
+    ///
+    /// The returned guard doesn't hold on to the container's lock.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container, or if no pool is registered for the requested type.
+    pub fn resolve_pooled<T: 'static + Send>() -> Result<crate::pool::Pooled<T>, Error> {
+        let container_r = Self::read()?;
+        Ok(container_r.resolve_pooled()?)
+    }
+
+    /// Resolve the given type from the container into caller-provided storage.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container, if the requested type cannot be found, or if the requested type cannot be cast from the binding.
+    pub fn resolve_into<T: 'static>(slot: &mut MaybeUninit<T>) -> Result<&mut T, Error> {
+        let container_r = Self::read()?;
+        Ok(container_r.resolve_into(slot)?)
+    }
+
+    /// Resolve several types at once, as a tuple, from a single snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container, or if any of the types cannot be resolved.
+    pub fn resolve_tuple<T: crate::call::ResolvableTuple>() -> Result<T, Error> {
+        let container_r = Self::read()?;
+        Ok(container_r.resolve_tuple()?)
+    }
+
+    /// Call the given function, resolving its arguments from the container.
+    ///
+    /// The arguments are resolved from a single snapshot, and the function itself is free to use the container.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container, or if any of the function's arguments cannot be resolved.
+    pub fn call<Args, F: Callable<Args>>(function: F) -> Result<F::Output, Error> {
+        let container_r = Self::read()?;
+        Ok(container_r.call(function)?)
+    }
+
+    /// Call the given async function, resolving its arguments from the container, and await it.
+    ///
+    /// The arguments are resolved from a single snapshot before the function is awaited.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container, or if any of the function's arguments cannot be resolved.
+    pub fn call_async<Args, F: AsyncCallable<Args>>(
+        function: F,
+    ) -> impl Future<Output = Result<<F::Future as Future>::Output, Error>> {
+        let prepared = Self::read().and_then(|container_r| Ok(function.prepare(&container_r)?));
+
+        async move { Ok(prepared?.await) }
+    }
+
+    /// Apply a batch of registrations atomically, rolling all of them back if the closure returns an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get write access to the container, if the container is frozen, or the error produced by the closure.
+    pub fn register_batch<E: From<Error>>(
+        batch: impl FnOnce(&mut crate::Container) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable().map_err(Error::from)?;
+        container_w.register_batch(batch)?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Apply a batch of registrations atomically, giving up if another thread holds the container for longer than the timeout.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get write access to the container, if it times out waiting for it, if the container is frozen, or the error produced by the closure.
+    pub fn register_batch_timeout<E: From<Error>>(
+        batch: impl FnOnce(&mut crate::Container) -> Result<(), E>,
+        timeout: Duration,
+    ) -> Result<(), E> {
+        let mut container_w = Self::write_until(Instant::now().checked_add(timeout))?;
+        container_w.ensure_mutable().map_err(Error::from)?;
+        container_w.register_batch(batch)?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Apply every registrar to the container, in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get write access to the container, or if the container is frozen.
+    pub fn register_all(
+        registrars: impl IntoIterator<Item = Box<dyn crate::registrar::Registrar>>,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.register_all(registrars);
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a service provider with the container.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get write access to the container, or if the container is frozen.
+    pub fn register_provider<P: crate::provider::ServiceProvider + 'static>(
+        provider: P,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.register_provider(provider);
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a shared binding that's constructed when the container is booted, rather than when it's registered.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn singleton_eager<T: 'static + Clone + Send + Sync>(
+        factory: impl Fn(&crate::Container) -> T + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.singleton_eager(factory);
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Register a callback that runs when the container is booted, once the eager singletons have been constructed.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, or if the container is frozen.
+    pub fn booting(
+        callback: impl Fn(&mut crate::Container) + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.booting(callback);
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Boot every registered service provider that hasn't been booted yet, in dependency order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get write access to the container, or if the providers' dependencies form a cycle.
+    pub fn boot() -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.boot()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Construct a singleton's value again with its factory, replacing the current one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container, or if no singleton with a factory is registered for the requested type.
+    pub fn refresh<T: 'static>() -> Result<(), Error> {
+        let container_r = Self::read()?;
+        container_r.refresh::<T>()?;
+        drop(container_r);
+
+        Ok(())
+    }
+
+    /// Construct every lazily-registered singleton up front, returning the ones that failed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container.
+    pub fn warm_up() -> Result<Vec<crate::WarmUpFailure>, Error> {
+        let container_r = Self::read()?;
+        let failures = container_r.warm_up();
+        drop(container_r);
+
+        Ok(failures)
+    }
+
+    /// Count the container's registrations, and estimate how much memory its tables take up.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container.
+    pub fn stats() -> Result<crate::stats::Stats, Error> {
+        let container_r = Self::read()?;
+        Ok(container_r.stats())
+    }
+
+    /// Free the space the container's tables hold beyond their current registrations.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get write access to the container.
+    pub fn shrink_to_fit() -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.shrink_to_fit();
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Make room for at least `additional` more registered types, so registering them doesn't reallocate the container's tables.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get write access to the container.
+    pub fn reserve(additional: usize) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.reserve(additional);
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Construct every lazily-registered singleton up front across several threads, returning the ones that failed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container.
+    pub fn warm_up_parallel() -> Result<Vec<crate::WarmUpFailure>, Error> {
+        let container_r = Self::read()?;
+        let failures = container_r.warm_up_parallel();
+        drop(container_r);
+
+        Ok(failures)
+    }
+
+    /// Register a service to be run periodically once the scheduler is started.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get write access to the container, or if the container is frozen.
+    pub fn schedule<T: crate::schedule::Scheduled>() -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.schedule::<T>();
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Resolve every scheduled service and start running them on the given runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container, or if any of the scheduled services cannot be resolved.
+    pub fn start_scheduler(
+        runtime: impl crate::schedule::Runtime,
+    ) -> Result<crate::schedule::Scheduler, Error> {
+        let container_r = Self::read()?;
+        Ok(container_r.start_scheduler(runtime)?)
+    }
+
+    /// Subscribe to the container's activity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get write access to the container.
+    pub fn subscribe() -> Result<std::sync::mpsc::Receiver<crate::events::ContainerEvent>, Error> {
+        let mut container_w = Self::write()?;
+        Ok(container_w.subscribe())
+    }
+
+    /// Register a callback that's notified of the container's activity as it happens.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get write access to the container.
+    pub fn on_event(
+        listener: impl Fn(&crate::events::ContainerEvent) + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.on_event(listener);
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Remove the given type's registration and any instance cached for it, leaving the rest of the container untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get write access to the container, if the type's registration is sealed, or if the container is frozen.
+    pub fn forget<T: 'static>() -> Result<(), Error> {
+        let mut container_w = Self::write_removing()?;
+        container_w.ensure_mutable()?;
+        container_w.forget::<T>();
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Clear all of the scoped instances from the container.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get write access to the container.
+    pub fn forget_scoped_instances() -> Result<(), Error> {
+        let mut container_w = Self::write_removing()?;
+        container_w.forget_scoped_instances();
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Handle resolutions of types that aren't registered, instead of failing with [`crate::Error::NotFound`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get write access to the container, or if the container is frozen.
+    pub fn set_missing_handler(
+        handler: impl Fn(&crate::Container, std::any::TypeId) -> Result<Box<dyn std::any::Any>, crate::Error>
+            + 'static
+            + Sync
+            + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.set_missing_handler(handler);
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Make the container read-only, so every registration made from now on returns [`Error::Frozen`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get write access to the container.
+    pub fn freeze() -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.freeze();
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Get a read-only handle to the frozen container, which resolves types without going through the facade's lock.
+    ///
+    /// Take it once the application has booted and hand it to request handlers, so resolving never synchronizes with other threads.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container, or if the container hasn't been [frozen](Container::freeze).
+    pub fn snapshot() -> Result<Snapshot, Error> {
+        let container_r = Self::read()?;
+        if !container_r.is_frozen() {
+            return Err(Error::NotFrozen);
+        }
+
+        // Detached from the global container's bindings, which it shares with the `concurrent` feature, so flushing the container doesn't empty it.
+        Ok(Snapshot(Arc::new(container_r.snapshot())))
+    }
+
+    /// Clear the instances scoped to the named scope, ending it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get write access to the container.
+    pub fn forget_scope(scope: &'static str) -> Result<(), Error> {
+        let mut container_w = Self::write_removing()?;
+        container_w.forget_scope(scope);
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Move every registration from a local container into the global one, handling types registered by both according to `on_conflict`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if it fails to get write access to the container, if the registrations conflict and `on_conflict` is [`OnConflict::Error`](crate::merge::OnConflict::Error), if a merged type's registration is sealed, or if the container is frozen.
+    pub fn merge(
+        other: crate::Container,
+        on_conflict: crate::merge::OnConflict,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write()?;
+        container_w.ensure_mutable()?;
+        container_w.merge(other, on_conflict)?;
+        container_w.take_refused()?;
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Fork the global container into a local copy that can be changed without affecting it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get read access to the container.
+    pub fn fork(instances: crate::fork::Instances) -> Result<crate::Container, Error> {
+        let container_r = Self::read()?;
+        let fork = container_r.fork(instances);
+        drop(container_r);
+
+        Ok(fork)
+    }
+
+    /// Flush the container of all bindings and resolved instances.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get write access to the container.
+    pub fn flush() -> Result<(), Error> {
+        let mut container_w = Self::write_removing()?;
+        container_w.flush();
+        drop(container_w);
+
+        Ok(())
+    }
+
+    /// Shut the container down, tearing its shared instances down in the reverse of the order they were constructed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if it fails to get write access to the container.
+    pub fn shutdown() -> Result<(), Error> {
+        let mut container_w = Self::write_removing()?;
+        container_w.shutdown();
+        drop(container_w);
+
+        Ok(())
+    }
+}
+
+/// A read-only handle to the frozen global container, taken with [`Container::snapshot`].
+///
+/// Cloning it is cheap, and resolving from it never takes the facade's lock. It keeps resolving from the container as it was frozen, even after the container is flushed.
+#[derive(Clone)]
+pub struct Snapshot(Arc<crate::Container>);
+
+impl Snapshot {
+    /// Resolve the given type from the snapshot.
+    ///
+    /// Inside a [task scope](crate::task::scope), the task's own values are returned first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the requested type cannot be found, or if the requested type cannot be cast from the binding.
+    pub fn resolve<T: 'static>(&self) -> Result<T, crate::Error> {
+        if let Some(value) = crate::task::resolve_any(&self.0, std::any::TypeId::of::<T>()) {
+            return value?
+                .downcast::<T>()
+                .map(|value| *value)
+                .map_err(|_| crate::Error::CastFailed);
+        }
+
+        self.0.resolve()
+    }
+}
+
+/// Snapshots are frozen, so every modification made through the interface fails with [`Error::Frozen`](crate::Error::Frozen).
+impl crate::interface::ContainerInterface for Snapshot {
+    fn bind_erased(
+        &mut self,
+        _: std::any::TypeId,
+        _: &'static str,
+        _: crate::Binding,
+    ) -> Result<(), crate::Error> {
+        Err(crate::Error::Frozen)
+    }
+
+    fn singleton_erased(
+        &mut self,
+        _: std::any::TypeId,
+        _: &'static str,
+        _: &dyn Fn(&crate::Container) -> crate::Instance,
+    ) -> Result<(), crate::Error> {
+        Err(crate::Error::Frozen)
+    }
+
+    fn scoped_erased(
+        &mut self,
+        _: std::any::TypeId,
+        _: &'static str,
+        _: &dyn Fn(&crate::Container) -> crate::Instance,
+    ) -> Result<(), crate::Error> {
+        Err(crate::Error::Frozen)
+    }
+
+    fn resolve_erased(
+        &self,
+        type_id: std::any::TypeId,
+    ) -> Result<Box<dyn std::any::Any>, crate::Error> {
+        crate::task::resolve_any(&self.0, type_id).unwrap_or_else(|| self.0.resolve_any(type_id))
+    }
+
+    fn forget_scoped_instances(&mut self) -> Result<(), crate::Error> {
+        Err(crate::Error::Frozen)
+    }
+
+    fn flush(&mut self) -> Result<(), crate::Error> {
+        Err(crate::Error::Frozen)
+    }
+}
+
+impl Deref for Snapshot {
+    type Target = crate::Container;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// The global container used by [`AsyncContainer`].
+#[cfg(feature = "tokio")]
+static ASYNC_CONTAINER: std::sync::OnceLock<tokio::sync::RwLock<crate::Container>> =
+    std::sync::OnceLock::new();
+
+/// A lock on the container behind [`AsyncContainer`], which debug builds track the holding thread of.
+///
+/// Never held across an `.await`, so the thread that took it is the one releasing it.
+#[cfg(feature = "tokio")]
+struct AsyncGuard<G>(G);
+
+#[cfg(feature = "tokio")]
+impl<G: Deref<Target = crate::Container>> Deref for AsyncGuard<G> {
+    type Target = crate::Container;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<G: DerefMut<Target = crate::Container>> DerefMut for AsyncGuard<G> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<G> Drop for AsyncGuard<G> {
+    fn drop(&mut self) {
+        #[cfg(debug_assertions)]
+        HOLDING_ASYNC.set(false);
+    }
+}
+
+/// Static interface for a container guarded by an async lock, for use inside async handlers.
+///
+/// Waiting for access to the container yields to the executor instead of blocking its thread. The container is separate from the one behind [`Container`], so bindings registered through one aren't visible to the other.
+///
+/// Factories run while the lock is held, so they should resolve their dependencies from the container they're passed rather than through the static interface. Debug builds panic when a factory blocks on the static interface, instead of hanging.
+#[cfg(feature = "tokio")]
+pub struct AsyncContainer {}
+
+#[cfg(feature = "tokio")]
+impl AsyncContainer {
+    /// Get the global instance of the container.
+    pub fn get_instance() -> &'static tokio::sync::RwLock<crate::Container> {
+        ASYNC_CONTAINER.get_or_init(|| tokio::sync::RwLock::new(crate::Container::new()))
+    }
+
+    /// Get read access to the container.
+    async fn read() -> AsyncGuard<tokio::sync::RwLockReadGuard<'static, crate::Container>> {
+        Self::hold(Self::get_instance().read()).await
+    }
+
+    /// Get write access to the container.
+    async fn write() -> AsyncGuard<tokio::sync::RwLockWriteGuard<'static, crate::Container>> {
+        Self::hold(Self::get_instance().write()).await
+    }
+
+    /// Wait for the given lock, recording that this thread holds it in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if this thread already holds the lock, for example when a factory blocks on the static interface, since waiting for it would never finish.
+    async fn hold<G>(lock: impl Future<Output = G>) -> AsyncGuard<G> {
+        #[cfg(debug_assertions)]
+        assert!(
+            !HOLDING_ASYNC.get(),
+            "thread '{}' tried to lock the async container while it already holds its lock, which would never be granted. Resolve dependencies from the container passed to factories instead of through `AsyncContainer`.",
+            std::thread::current().name().unwrap_or("<unnamed>")
+        );
+
+        let guard = lock.await;
+        #[cfg(debug_assertions)]
+        HOLDING_ASYNC.set(true);
+
+        AsyncGuard(guard)
+    }
+
+    /// Register a binding with the container.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the type's registration is sealed, or if the container is frozen.
+    pub async fn bind<T: 'static>(
+        factory: impl Fn(&crate::Container) -> T + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        let mut container_w = Self::write().await;
+        container_w.ensure_mutable()?;
+        container_w.bind(factory);
+        container_w.take_refused()?;
         drop(container_w);
 
         Ok(())
@@ -46,119 +1909,193 @@ impl Container {
     ///
     /// # Errors
     ///
-    /// This function will return an error if it fails to get write access to the container.
-    pub fn scoped<T: 'static + Clone + Send + Sync>(
-        factory: &(impl Fn(&crate::Container) -> T + 'static),
+    /// This function will return an error if the type's registration is sealed, or if the container is frozen.
+    pub async fn scoped<T: 'static + Clone + Send + Sync>(
+        factory: &(impl Fn(&crate::Container) -> T + 'static + Sync),
     ) -> Result<(), Error> {
-        let container = Self::get_instance();
-
-        let mut container_w = container.write().map_err(|_| Error::Lock)?;
+        let mut container_w = Self::write().await;
+        container_w.ensure_mutable()?;
         container_w.scoped(factory);
+        container_w.take_refused()?;
         drop(container_w);
 
         Ok(())
     }
 
-    /// Register a scoped binding if it hasn't already been registered.
+    /// Register a shared binding in the container.
     ///
     /// # Errors
     ///
-    /// This function will return an error if it fails to get write access to the container.
-    pub fn scoped_if<T: 'static + Clone + Send + Sync>(
-        factory: &(impl Fn(&crate::Container) -> T + 'static),
+    /// This function will return an error if the type's registration is sealed, or if the container is frozen.
+    pub async fn singleton<T: 'static + Clone + Send + Sync>(
+        factory: &(impl Fn(&crate::Container) -> T + 'static + Sync),
     ) -> Result<(), Error> {
-        let container = Self::get_instance();
-
-        let mut container_w = container.write().map_err(|_| Error::Lock)?;
-        container_w.scoped_if(factory);
+        let mut container_w = Self::write().await;
+        container_w.ensure_mutable()?;
+        container_w.singleton(factory);
+        container_w.take_refused()?;
         drop(container_w);
 
         Ok(())
     }
 
-    /// Register a shared binding in the container.
+    /// Register an already-constructed value as a shared instance.
     ///
     /// # Errors
     ///
-    /// This function will return an error if it fails to get write access to the container.
-    pub fn singleton<T: 'static + Clone + Send + Sync>(
-        factory: &(impl Fn(&crate::Container) -> T + 'static),
-    ) -> Result<(), Error> {
-        let container = Self::get_instance();
-
-        let mut container_w = container.write().map_err(|_| Error::Lock)?;
-        container_w.singleton(factory);
+    /// This function will return an error if the type's registration is sealed, or if the container is frozen.
+    pub async fn instance<T: 'static + Clone + Send + Sync>(value: T) -> Result<(), Error> {
+        let mut container_w = Self::write().await;
+        container_w.ensure_mutable()?;
+        container_w.instance(value);
+        container_w.take_refused()?;
         drop(container_w);
 
         Ok(())
     }
 
-    /// Register a shared binding if it hasn't already been registered.
+    /// Register a service provider with the container.
     ///
     /// # Errors
     ///
-    /// This function will return an error if it fails to get write access to the container.
-    pub fn singleton_if<T: 'static + Clone + Send + Sync>(
-        factory: &(impl Fn(&crate::Container) -> T + 'static),
+    /// Returns an error if the container is frozen.
+    pub async fn register_provider<P: crate::provider::ServiceProvider + 'static>(
+        provider: P,
     ) -> Result<(), Error> {
-        let container = Self::get_instance();
+        let mut container_w = Self::write().await;
+        container_w.ensure_mutable()?;
+        container_w.register_provider(provider);
+        drop(container_w);
 
-        let mut container_w = container.write().map_err(|_| Error::Lock)?;
-        container_w.singleton_if(factory);
+        Ok(())
+    }
+
+    /// Boot the container, then await the hooks registered with [`crate::Container::booting_async`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the providers' dependencies form a cycle, or if a hook's arguments cannot be resolved.
+    pub async fn boot() -> Result<(), Error> {
+        // Held while the hooks are awaited, which may resume on another thread, so it can't be tracked like the other locks.
+        let mut container_w = Self::get_instance().write().await;
+        container_w.boot_async().await?;
         drop(container_w);
 
         Ok(())
     }
 
+    /// Whether a binding, shared instance, pool or connection-scoped binding is registered for the given type.
+    pub async fn has<T: 'static>() -> bool {
+        Self::read().await.has::<T>()
+    }
+
     /// Resolve the given type from the container.
     ///
+    /// Inside a [task scope](crate::task::scope), the task's own values are returned first.
+    ///
     /// # Errors
     ///
-    /// Returns an error if it fails to get read access to the container, if the requested type cannot be found, or if the requested type cannot be cast from the binding.
-    pub fn resolve<T: 'static>() -> Result<T, Error> {
-        let container = Self::get_instance();
+    /// Returns an error if the requested type cannot be found, or if the requested type cannot be cast from the binding.
+    pub async fn resolve<T: 'static>() -> Result<T, Error> {
+        let container_r = Self::read().await;
+        if let Some(value) = crate::task::resolve_any(&container_r, std::any::TypeId::of::<T>()) {
+            return Ok(value?
+                .downcast::<T>()
+                .map(|value| *value)
+                .map_err(|_| crate::Error::CastFailed)?);
+        }
 
-        let container_r = container.read().map_err(|_| Error::Lock)?;
         Ok(container_r.resolve()?)
     }
 
-    /// Clear all of the scoped instances from the container.
+    /// Resolve the given type from the container, returning `None` if it isn't registered.
     ///
     /// # Errors
     ///
-    /// Returns an error if it fails to get write access to the container.
-    pub fn forget_scoped_instances() -> Result<(), Error> {
-        let container = Self::get_instance();
-
-        let mut container_w = container.write().map_err(|_| Error::Lock)?;
-        container_w.forget_scoped_instances();
-        drop(container_w);
+    /// Returns an error if the type is registered but cannot be resolved.
+    pub async fn resolve_optional<T: 'static>() -> Result<Option<T>, Error> {
+        let container_r = Self::read().await;
+        Ok(container_r.resolve_optional()?)
+    }
 
-        Ok(())
+    /// Resolve every binding registered for the given type with [`crate::Container::bind_many`], in the order they were registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the bindings cannot be resolved.
+    pub async fn resolve_all<T: 'static>() -> Result<Vec<T>, Error> {
+        let container_r = Self::read().await;
+        Ok(container_r.resolve_all()?)
     }
 
-    /// Flush the container of all bindings and resolved instances.
+    /// Call the given async function, resolving its arguments from the container, and await it.
+    ///
+    /// The container is only locked while the arguments are resolved, not while the function is awaited.
     ///
     /// # Errors
     ///
-    /// Returns an error if it fails to get write access to the container.
-    pub fn flush() -> Result<(), Error> {
-        let container = Self::get_instance();
+    /// Returns an error if any of the function's arguments cannot be resolved.
+    pub async fn call_async<Args, F: AsyncCallable<Args>>(
+        function: F,
+    ) -> Result<<F::Future as Future>::Output, Error> {
+        let container_r = Self::read().await;
+        let prepared = function.prepare(&container_r);
+        drop(container_r);
 
-        let mut container_w = container.write().map_err(|_| Error::Lock)?;
-        container_w.flush();
+        Ok(prepared?.await)
+    }
+
+    /// Remove the given type's registration and any instance cached for it, leaving the rest of the container untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the type's registration is sealed, or if the container is frozen.
+    pub async fn forget<T: 'static>() -> Result<(), Error> {
+        let mut container_w = Self::write().await;
+        container_w.ensure_mutable()?;
+        container_w.forget::<T>();
+        container_w.take_refused()?;
         drop(container_w);
 
         Ok(())
     }
+
+    /// Clear all of the scoped instances from the container.
+    pub async fn forget_scoped_instances() {
+        Self::write().await.forget_scoped_instances();
+    }
+
+    /// Flush the container of all bindings and resolved instances.
+    pub async fn flush() {
+        Self::write().await.flush();
+    }
+
+    /// Shut the container down, tearing its shared instances down in the reverse of the order they were constructed.
+    pub async fn shutdown() {
+        Self::write().await.shutdown();
+    }
 }
 
 /// Possible errors that can occur when interacting with the container's static interface.
 #[derive(Debug, PartialEq, Eq, thiserror::Error)]
 pub enum Error {
-    /// Failed to get container instance.
-    #[error("Failed to get container instance")]
-    Lock,
+    /// A thread panicked while modifying the container, for example in a singleton's factory, so it may be partially modified.
+    ///
+    /// Only the first access after the panic fails: the container stays usable afterwards. Never returned with the `parking_lot` or `single_threaded` features, whose locks can't be poisoned.
+    #[error("A thread panicked while modifying the container")]
+    Poisoned,
+
+    /// The container was used from code that runs while it's being modified on the same thread, such as a singleton's factory, which would deadlock.
+    #[error("Container is already in use by this thread")]
+    Reentrant,
+
+    /// A snapshot was requested before the container was frozen.
+    #[error("The container must be frozen before taking a snapshot")]
+    NotFrozen,
+
+    /// Another thread held the container for longer than the given timeout.
+    #[error("Timed out waiting for access to the container")]
+    Timeout,
 
     /// Container error.
     #[error(transparent)]
@@ -277,6 +2214,162 @@ mod tests {
         assert_eq!(result.value, "Hello, world!");
     }
 
+    #[test]
+    #[serial]
+    #[cfg(not(feature = "single_threaded"))]
+    fn lazy_singletons_are_constructed_once_under_concurrent_first_access() {
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc, Barrier,
+        };
+
+        #[derive(Debug, Clone, PartialEq)]
+        struct LazyDependency(usize);
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        Container::singleton_lazy(move |_| {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+
+            LazyDependency(counter.fetch_add(1, Ordering::SeqCst))
+        })
+        .unwrap();
+
+        let barrier = Arc::new(Barrier::new(8));
+        let handles = (0..8)
+            .map(|_| {
+                let barrier = Arc::clone(&barrier);
+
+                std::thread::spawn(move || {
+                    barrier.wait();
+                    Container::resolve::<LazyDependency>()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), Ok(LazyDependency(0)));
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    #[serial]
+    #[cfg(not(feature = "single_threaded"))]
+    fn resolving_does_not_wait_for_modifications() {
+        use std::sync::mpsc;
+
+        #[derive(Debug, Clone, PartialEq)]
+        struct Slow;
+
+        Container::bind(|_| 42_u32).unwrap();
+        assert_eq!(Container::resolve::<u32>(), Ok(42));
+
+        let (started, wait_started) = mpsc::channel();
+        let (finish, wait_finish) = mpsc::channel::<()>();
+        let writer = std::thread::spawn(move || {
+            Container::singleton(&move |_| {
+                started.send(()).unwrap();
+                wait_finish.recv().unwrap();
+
+                Slow
+            })
+        });
+
+        wait_started.recv().unwrap();
+        assert_eq!(Container::resolve::<u32>(), Ok(42));
+        finish.send(()).unwrap();
+
+        assert_eq!(writer.join().unwrap(), Ok(()));
+        assert_eq!(Container::resolve::<Slow>(), Ok(Slow));
+    }
+
+    #[test]
+    #[serial]
+    fn taking_a_resolved_instance_returns_it_without_copying() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static COPIES: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Debug, PartialEq)]
+        struct Pool;
+
+        impl Clone for Pool {
+            fn clone(&self) -> Self {
+                COPIES.fetch_add(1, Ordering::SeqCst);
+
+                Self
+            }
+        }
+
+        Container::singleton(&|_| Pool).unwrap();
+        assert_eq!(Container::resolve::<Pool>(), Ok(Pool));
+
+        let copies = COPIES.load(Ordering::SeqCst);
+        assert_eq!(Container::take::<Pool>(), Ok(Some(Pool)));
+        assert_eq!(COPIES.load(Ordering::SeqCst), copies);
+    }
+
+    #[test]
+    #[serial]
+    fn modifications_made_through_the_global_lock_are_resolved() {
+        Container::bind(|_| 1_i16).unwrap();
+        assert_eq!(Container::resolve::<i16>(), Ok(1));
+
+        let mut container_w = Container::get_instance().write().unwrap();
+        container_w.bind(|_| 2_i16);
+        drop(container_w);
+
+        assert_eq!(Container::resolve::<i16>(), Ok(2));
+    }
+
+    #[test]
+    #[serial]
+    #[cfg(all(feature = "concurrent", not(feature = "single_threaded")))]
+    fn can_register_bindings_concurrently() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Concurrent(u32);
+
+        Container::bind(|_| 1_u8).unwrap();
+        assert_eq!(Container::resolve::<u8>(), Ok(1));
+
+        let threads = (0..4)
+            .map(|_| std::thread::spawn(|| Container::bind_concurrently(|_| Concurrent(42))))
+            .collect::<Vec<_>>();
+        for thread in threads {
+            assert_eq!(thread.join().unwrap(), Ok(()));
+        }
+
+        assert_eq!(Container::resolve::<Concurrent>(), Ok(Concurrent(42)));
+        assert_eq!(Container::resolve::<u8>(), Ok(1));
+    }
+
+    #[test]
+    #[serial]
+    fn can_retrieve_a_registered_instance() {
+        Container::instance(TestDependency {
+            value: "Hello, world!".to_string(),
+        })
+        .unwrap();
+
+        let result = Container::resolve::<TestDependency>().unwrap();
+
+        assert_eq!(result.value, "Hello, world!");
+    }
+
+    #[test]
+    #[serial]
+    fn can_retrieve_a_named_binding() {
+        Container::bind_named("greeting", |_| TestDependency {
+            value: "Hello, world!".to_string(),
+        })
+        .unwrap();
+
+        let result = Container::resolve_named::<TestDependency>("greeting").unwrap();
+
+        assert_eq!(result.value, "Hello, world!");
+    }
+
     #[test]
     #[serial]
     fn returns_singleton_over_binding() {
@@ -295,6 +2388,155 @@ mod tests {
         assert_eq!(result.value, "Goodbye, world!");
     }
 
+    #[test]
+    #[serial]
+    fn failed_batch_rolls_back_registrations() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct BatchedDependency;
+
+        let result = Container::register_batch(|tx| {
+            tx.bind(|_| BatchedDependency);
+
+            Err(Error::Container(crate::Error::NotFound))
+        });
+
+        assert_eq!(result, Err(Error::Container(crate::Error::NotFound)));
+        assert_eq!(
+            Container::resolve::<BatchedDependency>(),
+            Err(Error::Container(crate::Error::NotFound))
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn can_call_an_async_function_with_resolved_arguments() {
+        Container::bind(|_| TestDependency {
+            value: "Hello, world!".to_string(),
+        })
+        .unwrap();
+
+        let result = futures::executor::block_on(Container::call_async(
+            |dependency: TestDependency| async move { dependency.value },
+        ));
+
+        assert_eq!(result, Ok("Hello, world!".to_string()));
+    }
+
+    #[test]
+    #[serial]
+    fn snapshots_resolve_from_the_frozen_container() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct SnapshotDependency(u32);
+
+        Container::singleton(&|_| SnapshotDependency(1)).unwrap();
+        assert!(matches!(Container::snapshot(), Err(Error::NotFrozen)));
+
+        Container::freeze().unwrap();
+        let mut snapshot = Container::snapshot().unwrap();
+        Container::flush().unwrap();
+
+        assert_eq!(
+            snapshot.resolve::<SnapshotDependency>(),
+            Ok(SnapshotDependency(1))
+        );
+        assert!(snapshot.has::<SnapshotDependency>());
+
+        let interface = &mut snapshot as &mut dyn crate::interface::ContainerInterface;
+        assert_eq!(
+            interface.resolve::<SnapshotDependency>(),
+            Ok(SnapshotDependency(1))
+        );
+        assert_eq!(
+            interface.bind(|_| SnapshotDependency(2)),
+            Err(crate::Error::Frozen)
+        );
+        assert_eq!(interface.flush(), Err(crate::Error::Frozen));
+        assert_eq!(
+            Container::resolve::<SnapshotDependency>(),
+            Err(Error::Container(crate::Error::NotFound))
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn frozen_containers_refuse_registrations() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct FrozenDependency(u32);
+
+        Container::singleton(&|_| FrozenDependency(1)).unwrap();
+        Container::freeze().unwrap();
+
+        assert_eq!(
+            Container::bind(|_| FrozenDependency(2)),
+            Err(Error::Container(crate::Error::Frozen))
+        );
+        assert_eq!(
+            Container::forget::<FrozenDependency>(),
+            Err(Error::Container(crate::Error::Frozen))
+        );
+        assert_eq!(
+            Container::resolve::<FrozenDependency>(),
+            Ok(FrozenDependency(1))
+        );
+
+        Container::flush().unwrap();
+        assert_eq!(Container::bind(|_| FrozenDependency(2)), Ok(()));
+    }
+
+    #[test]
+    #[serial]
+    fn can_resolve_boxed_trait_objects() {
+        trait Greeter: Send + Sync {
+            fn greet(&self) -> &'static str;
+        }
+
+        struct English;
+        impl Greeter for English {
+            fn greet(&self) -> &'static str {
+                "Hello"
+            }
+        }
+
+        Container::bind(|_| Box::new(English) as Box<dyn Greeter>).unwrap();
+
+        assert_eq!(
+            Container::resolve::<Box<dyn Greeter>>().unwrap().greet(),
+            "Hello"
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn factories_can_resolve_through_the_facade() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Port(u16);
+        #[derive(Debug, Clone, PartialEq)]
+        struct Address(String);
+
+        Container::bind(|_| Port(8080)).unwrap();
+        Container::bind(|_| {
+            let port = Container::resolve::<Port>().unwrap();
+
+            Address(format!("localhost:{}", port.0))
+        })
+        .unwrap();
+        Container::bind(|_| Container::bind(|_| 0_u8)).unwrap();
+        Container::singleton(&|_| {
+            Container::resolve::<Port>()
+                .map(|port| port.0)
+                .map_err(|error| error == Error::Reentrant)
+        })
+        .unwrap();
+
+        assert_eq!(
+            Container::resolve::<Address>(),
+            Ok(Address("localhost:8080".to_string()))
+        );
+        assert_eq!(Container::resolve::<Result<(), Error>>(), Ok(Ok(())));
+        assert_eq!(Container::resolve::<u8>(), Ok(0));
+        assert_eq!(Container::resolve::<Result<u16, bool>>(), Ok(Err(true)));
+    }
+
     #[test]
     #[serial]
     fn returns_error_when_not_found() {
@@ -310,4 +2552,118 @@ mod tests {
     fn can_resolve_a_binding_for_a_type_that_implements_default() {
         assert_eq!(Container::resolve::<u64>(), Ok(u64::default()));
     }
+
+    #[test]
+    #[cfg(feature = "tokio")]
+    fn can_register_and_resolve_through_the_async_interface() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct AsyncDependency(u32);
+
+        futures::executor::block_on(async {
+            AsyncContainer::singleton(&|_| AsyncDependency(42))
+                .await
+                .unwrap();
+            AsyncContainer::bind(|container| {
+                container
+                    .resolve::<AsyncDependency>()
+                    .unwrap()
+                    .0
+                    .to_string()
+            })
+            .await
+            .unwrap();
+
+            assert!(AsyncContainer::has::<AsyncDependency>().await);
+            assert_eq!(
+                AsyncContainer::resolve::<String>().await,
+                Ok("42".to_string())
+            );
+            assert_eq!(
+                AsyncContainer::call_async(|dependency: AsyncDependency| async move {
+                    dependency.0 + 1
+                })
+                .await,
+                Ok(43)
+            );
+
+            AsyncContainer::forget::<AsyncDependency>().await.unwrap();
+            assert_eq!(
+                AsyncContainer::resolve_optional::<AsyncDependency>().await,
+                Ok(None)
+            );
+        });
+    }
+
+    #[test]
+    #[cfg(all(feature = "tokio", debug_assertions))]
+    #[should_panic(expected = "tried to lock the async container while it already holds its lock")]
+    fn panics_when_a_factory_blocks_on_the_async_interface() {
+        struct Nested;
+
+        futures::executor::block_on(async {
+            AsyncContainer::bind(|_| {
+                let mut nested = std::pin::pin!(AsyncContainer::has::<u32>());
+                let _ = nested.as_mut().poll(&mut std::task::Context::from_waker(
+                    futures::task::noop_waker_ref(),
+                ));
+
+                Nested
+            })
+            .await
+            .unwrap();
+
+            let _ = AsyncContainer::resolve::<Nested>().await;
+        });
+    }
+
+    #[test]
+    #[serial]
+    #[cfg(not(any(feature = "parking_lot", feature = "single_threaded")))]
+    fn recovers_from_panics_while_modifying_the_container() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Unbuildable;
+
+        Container::bind(|_| 42_u16).unwrap();
+        let panicked = std::thread::spawn(|| {
+            Container::singleton(&|_| -> Unbuildable { panic!("factory failed") })
+        })
+        .join();
+        assert!(panicked.is_err());
+
+        assert_eq!(Container::resolve::<u16>(), Err(Error::Poisoned));
+        assert_eq!(Container::resolve::<u16>(), Ok(42));
+        Container::bind(|_| 7_u16).unwrap();
+        assert_eq!(Container::resolve::<u16>(), Ok(7));
+    }
+
+    #[test]
+    #[serial]
+    #[cfg(not(feature = "single_threaded"))]
+    fn gives_up_waiting_for_the_container_after_the_timeout() {
+        Container::bind(|_| 42_u32).unwrap();
+        assert_eq!(Container::resolve::<u32>(), Ok(42));
+
+        let (locked, held) = std::sync::mpsc::channel();
+        let (release, released) = std::sync::mpsc::channel::<()>();
+        let holder = std::thread::spawn(move || {
+            let container_w = Container::get_instance().write().unwrap();
+            locked.send(()).unwrap();
+            released.recv().unwrap();
+            drop(container_w);
+        });
+        held.recv().unwrap();
+
+        let timeout = Duration::from_millis(10);
+        assert_eq!(Container::resolve_timeout::<u32>(timeout), Ok(42));
+        assert_eq!(
+            Container::bind_timeout(|_| 7_u32, timeout),
+            Err(Error::Timeout)
+        );
+
+        release.send(()).unwrap();
+        holder.join().unwrap();
+
+        Container::bind_timeout(|_| 7_u32, timeout).unwrap();
+        assert_eq!(Container::resolve_timeout::<u32>(timeout), Ok(7));
+    }
 }
@@ -0,0 +1,111 @@
+use std::{fmt, marker::PhantomData, sync::Arc};
+
+use crate::{facade, Container};
+
+/// A handle that creates a fresh `T` every time [`Factory::make`] is called.
+///
+/// Services that spin up many short-lived values can depend on a `Factory<T>` instead of carrying a container around. Like [`Lazy`](crate::lazy::Lazy), handles resolved from a container (once it's made them resolvable with [`Container::factory`]) keep a copy of its registrations and resolve from it, while handles created with [`Factory::new`] resolve from the global container (the one behind [`facade::Container`]).
+pub struct Factory<T> {
+    /// The container values are resolved from, or `None` for the global container.
+    container: Option<Arc<Container>>,
+    /// The type being created.
+    makes: PhantomData<fn() -> T>,
+}
+
+impl<T: 'static> Factory<T> {
+    /// Create a factory handle that resolves from the global container.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            container: None,
+            makes: PhantomData,
+        }
+    }
+
+    /// Create a factory handle that resolves from a copy of the given container.
+    fn from_container(container: &Container) -> Self {
+        Self {
+            container: Some(Arc::new(container.share())),
+            makes: PhantomData,
+        }
+    }
+
+    /// Create a fresh value, resolving it from the handle's container.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value cannot be resolved.
+    pub fn make(&self) -> Result<T, facade::Error> {
+        match &self.container {
+            Some(container) => Ok(container.resolve()?),
+            None => facade::Container::resolve(),
+        }
+    }
+}
+
+impl<T> Clone for Factory<T> {
+    fn clone(&self) -> Self {
+        Self {
+            container: self.container.clone(),
+            makes: PhantomData,
+        }
+    }
+}
+
+impl<T: 'static> Default for Factory<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for Factory<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Factory").finish_non_exhaustive()
+    }
+}
+
+impl Container {
+    /// Make `Factory<T>` resolvable, so services can create fresh values of `T` on demand.
+    pub fn factory<T: 'static>(&mut self) {
+        self.bind(Factory::<T>::from_container);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    #[derive(Debug, PartialEq)]
+    struct Worker(usize);
+
+    #[test]
+    fn makes_a_fresh_value_every_time() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut container = Container::new();
+
+        let counter = Arc::clone(&calls);
+        container.bind(move |_| Worker(counter.fetch_add(1, Ordering::SeqCst)));
+        container.factory::<Worker>();
+
+        let workers = container.resolve::<Factory<Worker>>().unwrap();
+        drop(container);
+
+        assert_eq!(workers.make(), Ok(Worker(0)));
+        assert_eq!(workers.make(), Ok(Worker(1)));
+    }
+
+    #[test]
+    #[serial]
+    fn handles_created_directly_make_values_from_the_global_container() {
+        facade::Container::bind(|_| Worker(2)).unwrap();
+        assert_eq!(Factory::<Worker>::new().make(), Ok(Worker(2)));
+
+        facade::Container::flush().unwrap();
+        assert!(Factory::<Worker>::new().make().is_err());
+    }
+}
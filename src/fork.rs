@@ -0,0 +1,123 @@
+use std::{
+    any::TypeId,
+    sync::{Arc, Mutex},
+};
+
+use crate::{cache, shared::Shared, Container, SingletonFactory};
+
+/// What a fork does with the shared instances of the container it was forked from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instances {
+    /// Keep sharing them: swapping or refreshing a singleton in either container affects both.
+    Share,
+    /// Give the fork its own copies: eagerly-constructed singletons are cloned, and lazy singletons are constructed again by the fork on first use.
+    Copy,
+}
+
+impl Container {
+    /// Fork the container into a copy that can be changed without affecting it.
+    ///
+    /// Factories are shared between both containers, so forking is cheap, and registrations made on either one afterwards stay on that one. This lets tests (or sub-systems) start from a prepared baseline and diverge from it. Shared instances are either kept shared or copied, as chosen by `instances`.
+    ///
+    /// The fork doesn't inherit the [cleanup callbacks](Container::singleton_with_drop) or [async shutdown hooks](Container::terminating_async), which stay with the container that owns the resources.
+    #[must_use]
+    pub fn fork(&self, instances: Instances) -> Self {
+        let mut fork = self.snapshot();
+        fork.teardowns.clear();
+        fork.async_shutdown_hooks.clear();
+        fork.constructed = Arc::new(Mutex::new(self.constructed_order()));
+        fork.version = cache::next_version();
+
+        if instances == Instances::Copy {
+            for (type_id, copier) in &self.copiers {
+                if fork.swappable.contains_key(type_id) || fork.lazy_instances.contains_key(type_id)
+                {
+                    copier(&mut fork);
+                }
+            }
+        }
+
+        fork
+    }
+
+    /// Replace the fork's eagerly-constructed singleton with a cell holding a clone of its current value.
+    pub(crate) fn copy_shared<T: 'static + Clone + Send + Sync>(&mut self) {
+        let type_id = TypeId::of::<T>();
+        let Some((value, factory)) = self
+            .swappable
+            .get(&type_id)
+            .and_then(|cell| cell.downcast_ref::<Shared<T>>())
+            .map(|cell| ((*cell.get()).clone(), cell.factory().cloned()))
+        else {
+            return;
+        };
+
+        let cell = Arc::new(Shared::new(value).with_factory(factory));
+        let (instance, arc) = Self::shared_resolvers(&cell);
+
+        self.bindings
+            .replace_instance(type_id, Arc::clone(&instance));
+        self.insert_shared_typed(instance, &cell);
+        self.arcs.insert(TypeId::of::<Arc<T>>(), (type_id, arc));
+        self.swappable.insert(type_id, cell);
+    }
+
+    /// Replace the fork's lazy singleton with one that hasn't been constructed yet.
+    pub(crate) fn copy_lazy<T: 'static + Clone + Send + Sync>(
+        &mut self,
+        factory: &SingletonFactory<T>,
+    ) {
+        let type_id = TypeId::of::<T>();
+        let (instance, arc, reset) = Self::lazy_resolvers(factory);
+
+        self.bindings.replace_instance(type_id, instance);
+        self.arcs.insert(TypeId::of::<Arc<T>>(), (type_id, arc));
+        self.lazy_instances
+            .insert(type_id, (std::any::type_name::<T>(), reset));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn forks_diverge_from_the_original() {
+        let mut container = Container::new();
+        container.bind(|_| 1_u8);
+
+        let mut fork = container.fork(Instances::Share);
+        fork.bind(|_| 2_u8);
+        fork.bind(|_| 3_u16);
+
+        assert_eq!(container.resolve::<u8>(), Ok(1));
+        assert!(container.resolve::<u16>().is_err());
+        assert_eq!(fork.resolve::<u8>(), Ok(2));
+        assert_eq!(fork.resolve::<u16>(), Ok(3));
+    }
+
+    #[test]
+    fn forks_share_or_copy_instances() {
+        let builds = Arc::new(AtomicUsize::new(0));
+        let mut container = Container::new();
+        container.singleton(&|_| 1_u32);
+
+        let counter = Arc::clone(&builds);
+        container.singleton_lazy(move |_| counter.fetch_add(1, Ordering::SeqCst));
+        assert_eq!(container.resolve::<usize>(), Ok(0));
+
+        let shared = container.fork(Instances::Share);
+        shared.swap(2_u32);
+        assert_eq!(container.resolve::<u32>(), Ok(2));
+        assert_eq!(shared.resolve::<usize>(), Ok(0));
+
+        let copied = container.fork(Instances::Copy);
+        copied.swap(3_u32);
+        assert_eq!(container.resolve::<u32>(), Ok(2));
+        assert_eq!(copied.resolve::<u32>(), Ok(3));
+        assert_eq!(copied.resolve::<Arc<u32>>().map(|value| *value), Ok(3));
+        assert_eq!(copied.resolve::<usize>(), Ok(1));
+        assert_eq!(container.resolve::<usize>(), Ok(0));
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{Container, Error};
+
+impl Container {
+    /// Make the container read-only, so it can only be used to resolve types from now on.
+    ///
+    /// Call this once the application has booted, to catch accidental rebinds at runtime. Every registration made through the facade after freezing returns [`Error::Frozen`], while registrations made directly on a frozen container (bindings and instances, as well as named bindings, tags, hooks and callbacks) are ignored, as if every type was [sealed](Container::seal), and reported by [`Container::take_refused`]. Since registrations can no longer take the facade's write lock, resolutions never wait on one, and [`facade::Container::snapshot`](crate::facade::Container::snapshot) hands out a handle that resolves without taking it at all. Flushing the container thaws it along with everything else.
+    pub const fn freeze(&mut self) {
+        self.frozen = true;
+    }
+
+    /// Whether the container has been made read-only with [`Container::freeze`].
+    #[must_use]
+    pub const fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Check that the container can still accept registrations.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Frozen`] if the container has been frozen.
+    pub(crate) const fn ensure_mutable(&self) -> Result<(), Error> {
+        if self.frozen {
+            return Err(Error::Frozen);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frozen_containers_ignore_registrations() {
+        let mut container = Container::new();
+        container.bind(|_| 42_u32);
+        container.freeze();
+
+        container.bind(|_| 7_u32);
+        container.singleton(&|_| 7_u64);
+        container.forget::<u32>();
+
+        assert!(container.is_frozen());
+        assert_eq!(container.take_refused(), Err(Error::Frozen));
+        assert_eq!(container.ensure_mutable(), Err(Error::Frozen));
+        assert_eq!(container.resolve::<u32>(), Ok(42));
+        assert_eq!(container.resolve::<u64>(), Err(Error::NotFound));
+
+        container.flush();
+        assert!(!container.is_frozen());
+    }
+
+    #[test]
+    fn frozen_containers_ignore_every_kind_of_registration() {
+        let mut container = Container::new();
+        container.bind(|_| 42_u32);
+        container.freeze();
+
+        let registrations: [fn(&mut Container); 12] = [
+            |container| container.bind_named("named", |_| 7_u32),
+            |container| container.bind_many(|_| 7_u32),
+            |container| container.tag::<u32>("numbers"),
+            |container| container.tag_trait::<dyn std::fmt::Debug, u32>("debug", |value| value),
+            |container| container.bind_with(|_, value: u32| value),
+            |container| container.scoped_by_key(|_, key: &u32| *key),
+            |container| container.extend(|value: u32, _| value + 1),
+            |container| container.rebinding(|_, _: u32| unreachable!()),
+            |container| container.resolving(|value: &mut u32, _| *value += 1),
+            |container| container.after_resolving_any(|_, _| unreachable!()),
+            |container| container.set_missing_handler(|_, _| Ok(Box::new(7_u64))),
+            |container| container.booting(|_| unreachable!()),
+        ];
+        for register in registrations {
+            register(&mut container);
+
+            assert_eq!(container.take_refused(), Err(Error::Frozen));
+        }
+
+        assert_eq!(container.resolve::<u32>(), Ok(42));
+        assert_eq!(
+            container.resolve_named::<u32>("named"),
+            Err(Error::NotFound)
+        );
+        assert_eq!(container.resolve_all::<u32>(), Ok(vec![]));
+        assert_eq!(container.resolve_tagged::<u32>("numbers"), Ok(vec![]));
+        assert_eq!(
+            container
+                .resolve_tagged::<std::sync::Arc<dyn std::fmt::Debug>>("debug")
+                .map(|values| values.len()),
+            Ok(0)
+        );
+        assert_eq!(
+            container.resolve_with::<u32, _>(7_u32),
+            Err(Error::NotFound)
+        );
+        assert_eq!(container.resolve_for::<u32, _>(7_u32), Err(Error::NotFound));
+        assert_eq!(container.resolve::<u64>(), Err(Error::NotFound));
+        assert_eq!(container.boot(), Ok(()));
+    }
+}
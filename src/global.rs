@@ -0,0 +1,176 @@
+#[cfg(not(any(feature = "parking_lot", feature = "single_threaded")))]
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+#[cfg(any(feature = "parking_lot", feature = "single_threaded"))]
+use std::time::Instant;
+use std::{
+    ops::{Deref, DerefMut},
+    sync::{LockResult, TryLockError, TryLockResult},
+};
+
+#[cfg(all(feature = "parking_lot", not(feature = "single_threaded")))]
+use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+#[cfg(feature = "single_threaded")]
+use std::cell::{Ref as RwLockReadGuard, RefMut as RwLockWriteGuard};
+
+#[cfg(feature = "single_threaded")]
+use crate::local::LocalLock as RwLock;
+use crate::{facade, Container};
+
+/// The lock guarding the [global container](Container::get_instance).
+///
+/// Releasing a write lock discards the snapshot the [facade](facade::Container) resolves from, so the facade takes a new one, so modifications made through it are seen right away.
+///
+/// Guarded by `parking_lot`'s `RwLock` with the `parking_lot` feature, which can't be poisoned, and by a `RefCell` holding each thread's own container with the `single_threaded` feature. Its methods have the same signatures either way, mirroring [`std::sync::RwLock`]'s, so enabling a feature doesn't break code that locks the container.
+pub struct GlobalLock(RwLock<Container>);
+
+/// Read access to the [global container](Container::get_instance), taken with [`GlobalLock::read`].
+pub struct GlobalReadGuard<'a>(RwLockReadGuard<'a, Container>);
+
+impl Deref for GlobalReadGuard<'_> {
+    type Target = Container;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Write access to the [global container](Container::get_instance), taken with [`GlobalLock::write`].
+pub struct GlobalWriteGuard<'a>(RwLockWriteGuard<'a, Container>);
+
+impl<'a> GlobalWriteGuard<'a> {
+    /// Take over the lock's guard.
+    const fn new(guard: RwLockWriteGuard<'a, Container>) -> Self {
+        Self(guard)
+    }
+}
+
+impl Deref for GlobalWriteGuard<'_> {
+    type Target = Container;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for GlobalWriteGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Drop for GlobalWriteGuard<'_> {
+    fn drop(&mut self) {
+        // The lock is still held, so readers that lock the container to take a snapshot see the new generation along with the changes.
+        facade::retire_snapshot();
+        facade::advance_generation();
+    }
+}
+
+impl GlobalLock {
+    /// Guard the given container.
+    pub(crate) const fn new(container: Container) -> Self {
+        Self(RwLock::new(container))
+    }
+
+    /// Lock the container for reading.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a thread panicked while holding the lock for writing. Never fails with the `parking_lot` or `single_threaded` features, whose locks can't be poisoned.
+    pub fn read(&self) -> LockResult<GlobalReadGuard<'_>> {
+        #[cfg(not(any(feature = "parking_lot", feature = "single_threaded")))]
+        return self
+            .0
+            .read()
+            .map(GlobalReadGuard)
+            .map_err(|poisoned| PoisonError::new(GlobalReadGuard(poisoned.into_inner())));
+
+        #[cfg(any(feature = "parking_lot", feature = "single_threaded"))]
+        Ok(GlobalReadGuard(self.0.read()))
+    }
+
+    /// Lock the container for reading, unless it's locked for writing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lock is held for writing, or if a thread panicked while holding it for writing.
+    pub fn try_read(&self) -> TryLockResult<GlobalReadGuard<'_>> {
+        #[cfg(not(any(feature = "parking_lot", feature = "single_threaded")))]
+        return match self.0.try_read() {
+            Ok(guard) => Ok(GlobalReadGuard(guard)),
+            Err(TryLockError::Poisoned(poisoned)) => Err(TryLockError::Poisoned(PoisonError::new(
+                GlobalReadGuard(poisoned.into_inner()),
+            ))),
+            Err(TryLockError::WouldBlock) => Err(TryLockError::WouldBlock),
+        };
+
+        #[cfg(any(feature = "parking_lot", feature = "single_threaded"))]
+        self.0
+            .try_read()
+            .map(GlobalReadGuard)
+            .ok_or(TryLockError::WouldBlock)
+    }
+
+    /// Lock the container for writing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a thread panicked while holding the lock for writing. Never fails with the `parking_lot` or `single_threaded` features, whose locks can't be poisoned.
+    pub fn write(&self) -> LockResult<GlobalWriteGuard<'_>> {
+        #[cfg(not(any(feature = "parking_lot", feature = "single_threaded")))]
+        return self
+            .0
+            .write()
+            .map(GlobalWriteGuard::new)
+            .map_err(|poisoned| PoisonError::new(GlobalWriteGuard::new(poisoned.into_inner())));
+
+        #[cfg(any(feature = "parking_lot", feature = "single_threaded"))]
+        Ok(GlobalWriteGuard::new(self.0.write()))
+    }
+
+    /// Lock the container for writing, unless it's already locked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lock is held, or if a thread panicked while holding it for writing.
+    pub fn try_write(&self) -> TryLockResult<GlobalWriteGuard<'_>> {
+        #[cfg(not(any(feature = "parking_lot", feature = "single_threaded")))]
+        return match self.0.try_write() {
+            Ok(guard) => Ok(GlobalWriteGuard::new(guard)),
+            Err(TryLockError::Poisoned(poisoned)) => Err(TryLockError::Poisoned(PoisonError::new(
+                GlobalWriteGuard::new(poisoned.into_inner()),
+            ))),
+            Err(TryLockError::WouldBlock) => Err(TryLockError::WouldBlock),
+        };
+
+        #[cfg(any(feature = "parking_lot", feature = "single_threaded"))]
+        self.0
+            .try_write()
+            .map(GlobalWriteGuard::new)
+            .ok_or(TryLockError::WouldBlock)
+    }
+
+    /// Clear the poison left by a thread that panicked while holding the lock for writing.
+    #[cfg(not(any(feature = "parking_lot", feature = "single_threaded")))]
+    pub fn clear_poison(&self) {
+        self.0.clear_poison();
+    }
+
+    /// Clear the poison left by a thread that panicked while holding the lock for writing.
+    ///
+    /// Does nothing, since the lock can't be poisoned with the `parking_lot` or `single_threaded` features.
+    #[cfg(any(feature = "parking_lot", feature = "single_threaded"))]
+    pub const fn clear_poison(&self) {}
+
+    /// Lock the container for reading, waiting at most until the deadline.
+    #[cfg(any(feature = "parking_lot", feature = "single_threaded"))]
+    pub(crate) fn try_read_until(&self, deadline: Instant) -> Option<GlobalReadGuard<'_>> {
+        self.0.try_read_until(deadline).map(GlobalReadGuard)
+    }
+
+    /// Lock the container for writing, waiting at most until the deadline.
+    #[cfg(any(feature = "parking_lot", feature = "single_threaded"))]
+    pub(crate) fn try_write_until(&self, deadline: Instant) -> Option<GlobalWriteGuard<'_>> {
+        self.0.try_write_until(deadline).map(GlobalWriteGuard::new)
+    }
+}
@@ -0,0 +1,100 @@
+use std::{
+    any::{type_name, Any, TypeId},
+    ops::Deref,
+    sync::{Arc, OnceLock},
+};
+
+use crate::{Container, Error};
+
+impl Container {
+    /// Register an existing global (a `lazy_static!`, `once_cell::sync::Lazy` or [`std::sync::LazyLock`] static) as a binding.
+    ///
+    /// Every resolution returns a clone of the global's value, so code can move from the global to the container one call site at a time.
+    pub fn bind_global<T, G>(&mut self, global: &'static G)
+    where
+        T: 'static + Clone,
+        G: Deref<Target = T> + Sync,
+    {
+        self.bind(move |_| T::clone(global));
+    }
+
+    /// Register an existing [`OnceLock`] static as a binding.
+    ///
+    /// Resolution fails with [`Error::NotFound`] until the cell has been initialized.
+    pub fn bind_once_lock<T: 'static + Clone + Send + Sync>(&mut self, cell: &'static OnceLock<T>) {
+        self.insert_binding(
+            TypeId::of::<T>(),
+            Arc::new(move |_: &Self| {
+                let value = cell.get().ok_or(Error::NotFound)?;
+
+                Ok(Box::new(value.clone()) as Box<dyn Any>)
+            }),
+        );
+    }
+}
+
+/// Resolve a value from the global container, panicking if it cannot be resolved.
+///
+/// This is meant to initialize globals from container bindings during a migration, e.g. `static CONFIG: LazyLock<Config> = LazyLock::new(from_container);`.
+///
+/// # Panics
+///
+/// Panics if the global container cannot be accessed, or if the requested type cannot be resolved.
+#[must_use]
+pub fn from_container<T: 'static>() -> T {
+    crate::facade::Container::resolve().unwrap_or_else(|error| {
+        panic!(
+            "Failed to initialize global `{}` from the container: {error}",
+            type_name::<T>()
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::sync::LazyLock;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Config {
+        name: &'static str,
+    }
+
+    #[test]
+    fn can_bind_an_existing_lazy_global() {
+        static CONFIG: LazyLock<Config> = LazyLock::new(|| Config { name: "global" });
+
+        let mut container = Container::new();
+        container.bind_global(&CONFIG);
+
+        assert_eq!(container.resolve::<Config>(), Ok(Config { name: "global" }));
+    }
+
+    #[test]
+    fn once_lock_globals_resolve_once_initialized() {
+        static CONFIG: OnceLock<Config> = OnceLock::new();
+
+        let mut container = Container::new();
+        container.bind_once_lock(&CONFIG);
+
+        assert_eq!(container.resolve::<Config>(), Err(Error::NotFound));
+
+        CONFIG.set(Config { name: "late" }).unwrap();
+
+        assert_eq!(container.resolve::<Config>(), Ok(Config { name: "late" }));
+    }
+
+    #[test]
+    #[serial]
+    fn can_initialize_a_global_from_the_container() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Settings(u32);
+
+        static SETTINGS: LazyLock<Settings> = LazyLock::new(from_container);
+
+        crate::facade::Container::bind(|_| Settings(42)).unwrap();
+
+        assert_eq!(*SETTINGS, Settings(42));
+    }
+}
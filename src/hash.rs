@@ -0,0 +1,55 @@
+use std::{
+    any::TypeId,
+    collections::{HashMap, HashSet},
+    hash::{BuildHasherDefault, Hasher},
+};
+
+/// Builds the hashers for collections keyed by [`TypeId`].
+pub type BuildTypeIdHasher = BuildHasherDefault<TypeIdHasher>;
+
+/// A map keyed by [`TypeId`], which skips hashing its keys.
+pub type TypeMap<V> = HashMap<TypeId, V, BuildTypeIdHasher>;
+
+/// A set of [`TypeId`]s, which skips hashing them.
+pub type TypeSet = HashSet<TypeId, BuildTypeIdHasher>;
+
+/// A hasher for [`TypeId`]s, which are already hashes of their type, so they're used as-is.
+///
+/// Keys aren't chosen by users, so there's no need for the `DoS` resistance of the default hasher.
+#[derive(Default)]
+pub struct TypeIdHasher(u64);
+
+impl Hasher for TypeIdHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        // `TypeId` hashes itself with `write_u64`, so this only runs if that ever changes.
+        for &byte in bytes {
+            self.0 = (self.0.rotate_left(5) ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.0 = self.0.rotate_left(5) ^ value;
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distinguishes_types() {
+        let mut map = TypeMap::default();
+        map.insert(TypeId::of::<u32>(), "u32");
+        map.insert(TypeId::of::<u64>(), "u64");
+        map.insert(TypeId::of::<String>(), "String");
+
+        assert_eq!(map.get(&TypeId::of::<u32>()), Some(&"u32"));
+        assert_eq!(map.get(&TypeId::of::<u64>()), Some(&"u64"));
+        assert_eq!(map.get(&TypeId::of::<String>()), Some(&"String"));
+        assert_eq!(map.get(&TypeId::of::<i32>()), None);
+    }
+}
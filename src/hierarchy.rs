@@ -0,0 +1,158 @@
+use std::{
+    any::{Any, TypeId},
+    sync::Arc,
+};
+
+use crate::{resolver::Source, Container, Error};
+
+/// Which container builds the bindings a child inherits from its parent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Inherited {
+    /// The parent, so their dependencies come from the parent.
+    BuiltByParent,
+    /// The child, so their dependencies see its overrides.
+    BuiltByChild,
+}
+
+impl Container {
+    /// Create a child container, which resolves the types it doesn't register itself from the given parent.
+    ///
+    /// This lets a module or tenant override a handful of services while sharing the rest. Values resolved from the parent are built by the parent, so their own dependencies come from the parent too, never from the child's overrides. Use [`Container::overlay`] for the child's overrides to reach them.
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use silhouette::Container;
+    ///
+    /// let mut parent = Container::new();
+    /// parent.bind(|_| "postgres://shared");
+    /// parent.bind(|_| 42_u32);
+    ///
+    /// let mut tenant = Container::with_parent(Arc::new(parent));
+    /// tenant.bind(|_| "postgres://tenant");
+    ///
+    /// assert_eq!(tenant.resolve::<&str>(), Ok("postgres://tenant"));
+    /// assert_eq!(tenant.resolve::<u32>(), Ok(42));
+    /// ```
+    #[must_use]
+    pub fn with_parent(parent: Arc<Self>) -> Self {
+        Self {
+            parent: Some(parent),
+            ..Self::new()
+        }
+    }
+
+    /// Create a child container whose overrides are seen by the bindings it inherits from the given parent.
+    ///
+    /// Unlike [`Container::with_parent`], the parent's bindings are built by the overlay, so their dependencies are resolved from it (and its overrides) first. The parent's shared instances are still built by the parent and shared with it. This lets a request scope substitute a service for everything resolved inside it, while callers of the parent keep getting the parent's instance.
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use silhouette::Container;
+    ///
+    /// #[derive(Clone)]
+    /// struct DbConn(&'static str);
+    /// struct Repository(DbConn);
+    ///
+    /// let mut parent = Container::new();
+    /// parent.singleton(&|_| DbConn("pool"));
+    /// parent.bind(|container| Repository(container.resolve().unwrap()));
+    /// let parent = Arc::new(parent);
+    ///
+    /// let mut request = Container::overlay(Arc::clone(&parent));
+    /// request.instance(DbConn("transaction"));
+    ///
+    /// assert_eq!(request.resolve::<Repository>().unwrap().0 .0, "transaction");
+    /// assert_eq!(parent.resolve::<Repository>().unwrap().0 .0, "pool");
+    /// ```
+    #[must_use]
+    pub fn overlay(parent: Arc<Self>) -> Self {
+        Self {
+            inherited: Inherited::BuiltByChild,
+            ..Self::with_parent(parent)
+        }
+    }
+
+    /// The container this one falls back to for the types it doesn't register, if any.
+    #[must_use]
+    pub const fn parent(&self) -> Option<&Arc<Self>> {
+        self.parent.as_ref()
+    }
+
+    /// Resolve a type that has no registration in this container, from the parent and then the missing handler.
+    pub(crate) fn resolve_inherited(&self, type_id: TypeId) -> Result<Box<dyn Any>, Error> {
+        let inherited = self.parent.as_ref().map(|parent| {
+            if self.inherited == Inherited::BuiltByChild {
+                parent.resolve_for_overlay(type_id, self)
+            } else {
+                parent.resolve_any(type_id)
+            }
+        });
+
+        match inherited {
+            None | Some(Err(Error::NotFound)) => self.resolve_missing(type_id),
+            Some(result) => result,
+        }
+    }
+
+    /// Resolve a type for an overlay, building this container's (or its ancestors') bindings with the overlay.
+    fn resolve_for_overlay(&self, type_id: TypeId, overlay: &Self) -> Result<Box<dyn Any>, Error> {
+        let value = match Source::lookup(self, type_id) {
+            Some(Source::Binding(binding)) => binding(overlay),
+            Some(source) => source.produce(self),
+            None => match self
+                .parent
+                .as_ref()
+                .map(|parent| parent.resolve_for_overlay(type_id, overlay))
+            {
+                None | Some(Err(Error::NotFound)) => self.resolve_missing(type_id),
+                Some(result) => result,
+            },
+        }?;
+
+        self.extend_any(type_id, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn children_fall_back_to_their_parent() {
+        let mut parent = Container::new();
+        parent.singleton(&|_| 1_u32);
+        parent.bind(|container| container.resolve::<u32>().map(u64::from).unwrap());
+
+        let mut child = Container::with_parent(Arc::new(parent));
+        child.bind(|_| 2_u32);
+
+        assert_eq!(child.resolve::<u32>(), Ok(2));
+        assert_eq!(child.resolve::<u64>(), Ok(1));
+        assert_eq!(child.resolve::<u8>(), Err(Error::NotFound));
+        assert!(child.parent().is_some());
+        assert!(Container::new().parent().is_none());
+    }
+
+    #[test]
+    fn overlays_override_the_dependencies_of_inherited_bindings() {
+        let mut parent = Container::new();
+        parent.singleton(&|_| 1_u32);
+        parent.bind(|container| container.resolve::<u32>().map(u64::from).unwrap());
+        parent.singleton_lazy(|container| {
+            container
+                .resolve::<u32>()
+                .map(u16::try_from)
+                .unwrap()
+                .unwrap()
+        });
+        let parent = Arc::new(parent);
+
+        let mut overlay = Container::overlay(Arc::clone(&parent));
+        overlay.instance(2_u32);
+
+        assert_eq!(overlay.resolve::<u64>(), Ok(2));
+        assert_eq!(overlay.resolve::<u16>(), Ok(1));
+        assert_eq!(parent.resolve::<u64>(), Ok(1));
+        assert_eq!(Container::overlay(parent).resolve::<u64>(), Ok(1));
+    }
+}
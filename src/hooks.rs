@@ -0,0 +1,135 @@
+use std::{
+    any::{Any, TypeId},
+    sync::Arc,
+};
+
+use crate::{hash::TypeMap, Container};
+
+/// A callback that receives a freshly resolved value.
+type Hook = Arc<dyn Fn(&mut dyn Any, &Container) + Sync + Send>;
+
+/// The callbacks for one stage of resolution, for every type and for specific types.
+#[derive(Clone, Default)]
+pub struct Hooks {
+    /// The callbacks that run for every type.
+    global: Vec<Hook>,
+    /// The callbacks that run for a specific type.
+    typed: TypeMap<Vec<Hook>>,
+}
+
+impl Hooks {
+    /// Run the global callbacks, followed by the ones registered for the given type, in the order they were registered.
+    pub fn run(&self, type_id: TypeId, value: &mut dyn Any, container: &Container) {
+        let typed = self.typed.get(&type_id).into_iter().flatten();
+
+        for hook in self.global.iter().chain(typed) {
+            hook(value, container);
+        }
+    }
+
+    /// Whether any callbacks run for the given type.
+    pub fn applies_to(&self, type_id: TypeId) -> bool {
+        !self.global.is_empty() || self.typed.contains_key(&type_id)
+    }
+
+    /// Add the other callbacks after these ones.
+    pub fn append(&mut self, other: Self) {
+        self.global.extend(other.global);
+        for (type_id, hooks) in other.typed {
+            self.typed.entry(type_id).or_default().extend(hooks);
+        }
+    }
+
+    fn push<T: 'static>(&mut self, hook: impl Fn(&mut T, &Container) + 'static + Sync + Send) {
+        self.typed
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push(Arc::new(
+                move |value: &mut dyn Any, container: &Container| {
+                    if let Some(value) = value.downcast_mut::<T>() {
+                        hook(value, container);
+                    }
+                },
+            ));
+    }
+}
+
+impl Container {
+    /// Register a callback that receives every value of the given type as soon as it's resolved, before the [`Container::after_resolving`] callbacks.
+    pub fn resolving<T: 'static>(&mut self, hook: impl Fn(&mut T, &Self) + 'static + Sync + Send) {
+        if self.refuse_frozen() {
+            return;
+        }
+
+        self.resolving_hooks.push(hook);
+    }
+
+    /// Register a callback that receives every resolved value, regardless of its type, before the [`Container::after_resolving`] callbacks.
+    pub fn resolving_any(&mut self, hook: impl Fn(&mut dyn Any, &Self) + 'static + Sync + Send) {
+        if self.refuse_frozen() {
+            return;
+        }
+
+        self.resolving_hooks.global.push(Arc::new(hook));
+    }
+
+    /// Register a callback that receives every value of the given type once the [`Container::resolving`] callbacks have run.
+    pub fn after_resolving<T: 'static>(
+        &mut self,
+        hook: impl Fn(&mut T, &Self) + 'static + Sync + Send,
+    ) {
+        if self.refuse_frozen() {
+            return;
+        }
+
+        self.after_resolving_hooks.push(hook);
+    }
+
+    /// Register a callback that receives every resolved value, regardless of its type, once the [`Container::resolving`] callbacks have run.
+    pub fn after_resolving_any(
+        &mut self,
+        hook: impl Fn(&mut dyn Any, &Self) + 'static + Sync + Send,
+    ) {
+        if self.refuse_frozen() {
+            return;
+        }
+
+        self.after_resolving_hooks.global.push(Arc::new(hook));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Request {
+        trace: Vec<&'static str>,
+    }
+
+    #[test]
+    fn runs_hooks_in_order_after_construction() {
+        let mut container = Container::new();
+        container.bind(|_| Request { trace: Vec::new() });
+
+        container.after_resolving(|request: &mut Request, _| request.trace.push("after"));
+        container.after_resolving_any(|value, _| {
+            if let Some(request) = value.downcast_mut::<Request>() {
+                request.trace.push("after any");
+            }
+        });
+        container.resolving(|request: &mut Request, _| request.trace.push("resolving"));
+        container.resolving_any(|value, _| {
+            if let Some(request) = value.downcast_mut::<Request>() {
+                request.trace.push("resolving any");
+            }
+        });
+
+        assert_eq!(
+            container.resolve::<Request>(),
+            Ok(Request {
+                trace: vec!["resolving any", "resolving", "after any", "after"]
+            })
+        );
+    }
+}
@@ -0,0 +1,307 @@
+use std::any::{type_name, Any, TypeId};
+
+use crate::{interface::ContainerInterface, Binding, Container, Error, Instance};
+
+/// An operation performed on an [`InstrumentedContainer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    /// A binding was registered.
+    Bind,
+    /// A binding was registered, unless one already existed.
+    BindIf,
+    /// A scoped binding was registered.
+    Scoped,
+    /// A scoped binding was registered, unless one already existed.
+    ScopedIf,
+    /// A shared binding was registered.
+    Singleton,
+    /// A shared binding was registered, unless one already existed.
+    SingletonIf,
+    /// A type was resolved from the container.
+    Resolve,
+    /// A batch of registrations was applied.
+    RegisterBatch,
+    /// The scoped instances were cleared.
+    ForgetScopedInstances,
+    /// The container was flushed.
+    Flush,
+}
+
+/// Details about an operation performed on an [`InstrumentedContainer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Event {
+    /// The operation that was performed.
+    pub operation: Operation,
+    /// The name of the type the operation targeted, if any.
+    pub type_name: Option<&'static str>,
+    /// Whether the operation succeeded.
+    pub success: bool,
+}
+
+type Listener = Box<dyn Fn(&Event) + Send + Sync>;
+
+/// A wrapper around [`Container`] that notifies listeners about every operation performed on it.
+pub struct InstrumentedContainer {
+    /// The wrapped container.
+    container: Container,
+    /// The callbacks to notify.
+    listeners: Vec<Listener>,
+}
+
+impl InstrumentedContainer {
+    /// Wrap the given container.
+    #[must_use]
+    pub fn new(container: Container) -> Self {
+        Self {
+            container,
+            listeners: Vec::new(),
+        }
+    }
+
+    /// Register a callback that will be notified about every operation.
+    pub fn listen(&mut self, listener: impl Fn(&Event) + Send + Sync + 'static) {
+        self.listeners.push(Box::new(listener));
+    }
+
+    /// Get a reference to the wrapped container.
+    #[must_use]
+    pub const fn inner(&self) -> &Container {
+        &self.container
+    }
+
+    /// Unwrap the instrumented container, returning the wrapped container.
+    #[must_use]
+    pub fn into_inner(self) -> Container {
+        self.container
+    }
+
+    fn emit(&self, operation: Operation, type_name: Option<&'static str>, success: bool) {
+        let event = Event {
+            operation,
+            type_name,
+            success,
+        };
+
+        for listener in &self.listeners {
+            listener(&event);
+        }
+    }
+
+    /// Register a binding with the container.
+    pub fn bind<T: 'static>(&mut self, factory: impl Fn(&Container) -> T + 'static + Sync + Send) {
+        self.container.bind(factory);
+        self.emit(Operation::Bind, Some(type_name::<T>()), true);
+    }
+
+    /// Register a binding if it hasn't already been registered.
+    pub fn bind_if<T: 'static>(
+        &mut self,
+        factory: impl Fn(&Container) -> T + 'static + Sync + Send,
+    ) {
+        self.container.bind_if(factory);
+        self.emit(Operation::BindIf, Some(type_name::<T>()), true);
+    }
+
+    /// Register a scoped binding in the container.
+    pub fn scoped<T: 'static + Clone + Send + Sync>(
+        &mut self,
+        factory: &(impl Fn(&Container) -> T + 'static),
+    ) {
+        self.container.scoped(factory);
+        self.emit(Operation::Scoped, Some(type_name::<T>()), true);
+    }
+
+    /// Register a scoped binding if it hasn't already been registered.
+    pub fn scoped_if<T: 'static + Clone + Send + Sync>(
+        &mut self,
+        factory: &(impl Fn(&Container) -> T + 'static),
+    ) {
+        self.container.scoped_if(factory);
+        self.emit(Operation::ScopedIf, Some(type_name::<T>()), true);
+    }
+
+    /// Register a shared binding in the container.
+    pub fn singleton<T: 'static + Clone + Send + Sync>(
+        &mut self,
+        factory: &(impl Fn(&Container) -> T + 'static),
+    ) {
+        self.container.singleton(factory);
+        self.emit(Operation::Singleton, Some(type_name::<T>()), true);
+    }
+
+    /// Register a shared binding if it hasn't already been registered.
+    pub fn singleton_if<T: 'static + Clone + Send + Sync>(
+        &mut self,
+        factory: &(impl Fn(&Container) -> T + 'static),
+    ) {
+        self.container.singleton_if(factory);
+        self.emit(Operation::SingletonIf, Some(type_name::<T>()), true);
+    }
+
+    /// Resolve the given type from the container.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the requested type cannot be found or if the requested type cannot be cast from the binding.
+    pub fn resolve<T: 'static>(&self) -> Result<T, Error> {
+        let result = self.container.resolve::<T>();
+        self.emit(Operation::Resolve, Some(type_name::<T>()), result.is_ok());
+
+        result
+    }
+
+    /// Apply a batch of registrations atomically.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error produced by the closure, after rolling back its registrations.
+    pub fn register_batch<E>(
+        &mut self,
+        batch: impl FnOnce(&mut Container) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let result = self.container.register_batch(batch);
+        self.emit(Operation::RegisterBatch, None, result.is_ok());
+
+        result
+    }
+
+    /// Clear all of the scoped instances from the container.
+    pub fn forget_scoped_instances(&mut self) {
+        self.container.forget_scoped_instances();
+        self.emit(Operation::ForgetScopedInstances, None, true);
+    }
+
+    /// Flush the container of all bindings and resolved instances.
+    pub fn flush(&mut self) {
+        self.container.flush();
+        self.emit(Operation::Flush, None, true);
+    }
+}
+
+impl ContainerInterface for InstrumentedContainer {
+    fn bind_erased(
+        &mut self,
+        type_id: TypeId,
+        type_name: &'static str,
+        binding: Binding,
+    ) -> Result<(), Error> {
+        let result = self.container.bind_erased(type_id, type_name, binding);
+        self.emit(Operation::Bind, Some(type_name), result.is_ok());
+
+        result
+    }
+
+    fn singleton_erased(
+        &mut self,
+        type_id: TypeId,
+        type_name: &'static str,
+        build: &dyn Fn(&Container) -> Instance,
+    ) -> Result<(), Error> {
+        let result = self.container.singleton_erased(type_id, type_name, build);
+        self.emit(Operation::Singleton, Some(type_name), result.is_ok());
+
+        result
+    }
+
+    fn scoped_erased(
+        &mut self,
+        type_id: TypeId,
+        type_name: &'static str,
+        build: &dyn Fn(&Container) -> Instance,
+    ) -> Result<(), Error> {
+        let result = self.container.scoped_erased(type_id, type_name, build);
+        self.emit(Operation::Scoped, Some(type_name), result.is_ok());
+
+        result
+    }
+
+    fn resolve_erased(&self, type_id: TypeId) -> Result<Box<dyn Any>, Error> {
+        let result = self.container.resolve_erased(type_id);
+        self.emit(Operation::Resolve, None, result.is_ok());
+
+        result
+    }
+
+    fn resolve_erased_with_name(
+        &self,
+        type_id: TypeId,
+        type_name: &'static str,
+    ) -> Result<Box<dyn Any>, Error> {
+        let result = self.container.resolve_erased(type_id);
+        self.emit(Operation::Resolve, Some(type_name), result.is_ok());
+
+        result
+    }
+
+    fn forget_scoped_instances(&mut self) -> Result<(), Error> {
+        Self::forget_scoped_instances(self);
+
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        Self::flush(self);
+
+        Ok(())
+    }
+}
+
+impl From<Container> for InstrumentedContainer {
+    fn from(container: Container) -> Self {
+        Self::new(container)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn notifies_listeners_about_operations() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let mut container = InstrumentedContainer::new(Container::new());
+
+        let recorded = Arc::clone(&events);
+        container.listen(move |event| recorded.lock().unwrap().push(*event));
+
+        container.bind(|_| 42_u32);
+        assert_eq!(container.resolve::<u32>(), Ok(42));
+        assert_eq!(container.resolve::<u64>(), Err(Error::NotFound));
+
+        let interface = &mut container as &mut dyn ContainerInterface;
+        interface.bind(|_| 7_u8).unwrap();
+        assert_eq!(interface.resolve::<u8>(), Ok(7));
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                Event {
+                    operation: Operation::Bind,
+                    type_name: Some("u32"),
+                    success: true,
+                },
+                Event {
+                    operation: Operation::Resolve,
+                    type_name: Some("u32"),
+                    success: true,
+                },
+                Event {
+                    operation: Operation::Resolve,
+                    type_name: Some("u64"),
+                    success: false,
+                },
+                Event {
+                    operation: Operation::Bind,
+                    type_name: Some("u8"),
+                    success: true,
+                },
+                Event {
+                    operation: Operation::Resolve,
+                    type_name: Some("u8"),
+                    success: true,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,295 @@
+use std::{
+    any::{type_name, Any, TypeId},
+    sync::Arc,
+};
+
+use crate::{Binding, Container, Error, Instance};
+
+/// The public API of a service container, in a form that can be used as a trait object.
+///
+/// Code that accepts a `&dyn ContainerInterface` can be handed a [`Container`], a [`Scope`](crate::scope::Scope), a frozen [`Snapshot`](crate::facade::Snapshot), an [`InstrumentedContainer`](crate::instrumented::InstrumentedContainer), or a hand-rolled mock in tests.
+/// The required methods work with type-erased values, along with the name of their type for diagnostics; the typed helpers (`bind`, `singleton`, `resolve`, ...) are available on `dyn ContainerInterface` itself.
+pub trait ContainerInterface {
+    /// Register a type-erased binding.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registration was refused, such as when the container is frozen or the type is sealed.
+    fn bind_erased(
+        &mut self,
+        type_id: TypeId,
+        type_name: &'static str,
+        binding: Binding,
+    ) -> Result<(), Error>;
+
+    /// Register a type-erased shared binding. The closure receives the underlying container and returns the shared instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registration was refused, such as when the container is frozen or the type is sealed.
+    fn singleton_erased(
+        &mut self,
+        type_id: TypeId,
+        type_name: &'static str,
+        build: &dyn Fn(&Container) -> Instance,
+    ) -> Result<(), Error>;
+
+    /// Register a type-erased scoped binding. The closure receives the underlying container and returns the scoped instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registration was refused, such as when the container is frozen or the type is sealed.
+    fn scoped_erased(
+        &mut self,
+        type_id: TypeId,
+        type_name: &'static str,
+        build: &dyn Fn(&Container) -> Instance,
+    ) -> Result<(), Error>;
+
+    /// Resolve a type-erased value from the container.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the requested type cannot be found.
+    fn resolve_erased(&self, type_id: TypeId) -> Result<Box<dyn Any>, Error>;
+
+    /// Resolve a type-erased value from the container, knowing the name of its type.
+    ///
+    /// The typed [`resolve`](#method.resolve) helper goes through this method, so implementations reporting on resolutions can name the resolved type. It defaults to [`ContainerInterface::resolve_erased`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the requested type cannot be found.
+    fn resolve_erased_with_name(
+        &self,
+        type_id: TypeId,
+        type_name: &'static str,
+    ) -> Result<Box<dyn Any>, Error> {
+        let _ = type_name;
+
+        self.resolve_erased(type_id)
+    }
+
+    /// Clear all of the scoped instances from the container.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the container can't be modified, such as a frozen snapshot.
+    fn forget_scoped_instances(&mut self) -> Result<(), Error>;
+
+    /// Flush the container of all bindings and resolved instances.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the container can't be modified, such as a frozen snapshot.
+    fn flush(&mut self) -> Result<(), Error>;
+}
+
+impl dyn ContainerInterface + '_ {
+    /// Register a binding with the container.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registration was refused, such as when the container is frozen or the type is sealed.
+    pub fn bind<T: 'static>(
+        &mut self,
+        factory: impl Fn(&Container) -> T + 'static + Sync + Send,
+    ) -> Result<(), Error> {
+        self.bind_erased(
+            TypeId::of::<T>(),
+            type_name::<T>(),
+            Arc::new(move |container: &Container| Ok(Box::new(factory(container)) as Box<dyn Any>)),
+        )
+    }
+
+    /// Register a shared binding in the container.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registration was refused, such as when the container is frozen or the type is sealed.
+    pub fn singleton<T: 'static + Clone + Send + Sync>(
+        &mut self,
+        factory: &(impl Fn(&Container) -> T + 'static),
+    ) -> Result<(), Error> {
+        self.singleton_erased(TypeId::of::<T>(), type_name::<T>(), &|container| {
+            shared(factory(container))
+        })
+    }
+
+    /// Register a scoped binding in the container.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registration was refused, such as when the container is frozen or the type is sealed.
+    pub fn scoped<T: 'static + Clone + Send + Sync>(
+        &mut self,
+        factory: &(impl Fn(&Container) -> T + 'static),
+    ) -> Result<(), Error> {
+        self.scoped_erased(TypeId::of::<T>(), type_name::<T>(), &|container| {
+            shared(factory(container))
+        })
+    }
+
+    /// Resolve the given type from the container.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the requested type cannot be found or if the requested type cannot be cast from the binding.
+    pub fn resolve<T: 'static>(&self) -> Result<T, Error> {
+        self.resolve_erased_with_name(TypeId::of::<T>(), type_name::<T>())?
+            .downcast::<T>()
+            .map(|value| *value)
+            .map_err(|_| Error::CastFailed)
+    }
+}
+
+fn shared<T: 'static + Clone + Send + Sync>(value: T) -> Instance {
+    Arc::new(move |_: &Container| Box::new(value.clone()) as Box<dyn Any>)
+}
+
+impl ContainerInterface for Container {
+    fn bind_erased(
+        &mut self,
+        type_id: TypeId,
+        _: &'static str,
+        binding: Binding,
+    ) -> Result<(), Error> {
+        self.insert_binding(type_id, binding);
+
+        self.take_refused()
+    }
+
+    fn singleton_erased(
+        &mut self,
+        type_id: TypeId,
+        _: &'static str,
+        build: &dyn Fn(&Container) -> Instance,
+    ) -> Result<(), Error> {
+        let instance = build(self);
+        self.insert_instance(type_id, instance, false);
+
+        self.take_refused()
+    }
+
+    fn scoped_erased(
+        &mut self,
+        type_id: TypeId,
+        _: &'static str,
+        build: &dyn Fn(&Container) -> Instance,
+    ) -> Result<(), Error> {
+        let instance = build(self);
+        self.insert_instance(type_id, instance, true);
+
+        self.take_refused()
+    }
+
+    fn resolve_erased(&self, type_id: TypeId) -> Result<Box<dyn Any>, Error> {
+        self.resolve_any(type_id)
+    }
+
+    fn forget_scoped_instances(&mut self) -> Result<(), Error> {
+        Self::forget_scoped_instances(self);
+
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        Self::flush(self);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct TestDependency {
+        value: String,
+    }
+
+    fn greeting(container: &dyn ContainerInterface) -> String {
+        container.resolve::<TestDependency>().unwrap().value
+    }
+
+    #[test]
+    fn can_use_a_container_as_a_trait_object() {
+        let mut container = Container::new();
+
+        (&mut container as &mut dyn ContainerInterface)
+            .singleton(&|_| TestDependency {
+                value: "Hello, world!".to_string(),
+            })
+            .unwrap();
+
+        assert_eq!(greeting(&container), "Hello, world!");
+    }
+
+    #[test]
+    fn scopes_keep_registrations_made_through_the_interface() {
+        let mut container = Container::new();
+        container.bind(|_| 1_u32);
+
+        let mut scope = container.create_scope();
+        let interface = &mut scope as &mut dyn ContainerInterface;
+        interface.bind(|_| 2_u32).unwrap();
+        interface
+            .singleton(&|_| TestDependency {
+                value: "Scoped!".to_string(),
+            })
+            .unwrap();
+
+        assert_eq!(interface.resolve::<u32>(), Ok(2));
+        assert_eq!(greeting(interface), "Scoped!");
+        assert!(!container.has::<TestDependency>());
+
+        interface.flush().unwrap();
+        assert_eq!(interface.resolve::<u32>(), Ok(1));
+    }
+
+    #[test]
+    fn can_substitute_a_mock_container() {
+        struct MockContainer;
+
+        impl ContainerInterface for MockContainer {
+            fn bind_erased(&mut self, _: TypeId, _: &'static str, _: Binding) -> Result<(), Error> {
+                Ok(())
+            }
+
+            fn singleton_erased(
+                &mut self,
+                _: TypeId,
+                _: &'static str,
+                _: &dyn Fn(&Container) -> Instance,
+            ) -> Result<(), Error> {
+                Ok(())
+            }
+
+            fn scoped_erased(
+                &mut self,
+                _: TypeId,
+                _: &'static str,
+                _: &dyn Fn(&Container) -> Instance,
+            ) -> Result<(), Error> {
+                Ok(())
+            }
+
+            fn forget_scoped_instances(&mut self) -> Result<(), Error> {
+                Ok(())
+            }
+
+            fn flush(&mut self) -> Result<(), Error> {
+                Ok(())
+            }
+
+            fn resolve_erased(&self, _: TypeId) -> Result<Box<dyn Any>, Error> {
+                Ok(Box::new(TestDependency {
+                    value: "Mocked!".to_string(),
+                }))
+            }
+        }
+
+        assert_eq!(greeting(&MockContainer), "Mocked!");
+    }
+}
@@ -0,0 +1,144 @@
+use std::{
+    fmt,
+    ops::Deref,
+    sync::{Arc, OnceLock},
+};
+
+use crate::{facade, Container};
+
+/// A handle that resolves `T` the first time it's used.
+///
+/// Nothing is resolved until [`Lazy::get`] is called or the handle is dereferenced. This defers expensive construction until it's needed, and lets two services depend on each other without a construction-time cycle.
+///
+/// Handles resolved from a container (once it's made them resolvable with [`Container::lazy`]) keep a copy of its registrations, sharing its instances, so they resolve from that container even after it's gone. Handles created with [`Lazy::new`] resolve from the global container (the one behind [`facade::Container`]).
+pub struct Lazy<T> {
+    /// The resolved value, once it's been used.
+    value: OnceLock<T>,
+    /// The container the value is resolved from, or `None` for the global container.
+    container: Option<Arc<Container>>,
+}
+
+impl<T: 'static> Lazy<T> {
+    /// Create a handle that resolves its value from the global container.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            value: OnceLock::new(),
+            container: None,
+        }
+    }
+
+    /// Create a handle that resolves its value from a copy of the given container.
+    fn from_container(container: &Container) -> Self {
+        Self {
+            value: OnceLock::new(),
+            container: Some(Arc::new(container.share())),
+        }
+    }
+
+    /// Get the value, resolving it if this is the first use.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value cannot be resolved, in which case the next call tries again.
+    pub fn get(&self) -> Result<&T, facade::Error> {
+        if let Some(value) = self.value.get() {
+            return Ok(value);
+        }
+
+        let value = match &self.container {
+            Some(container) => container.resolve::<T>()?,
+            None => facade::Container::resolve::<T>()?,
+        };
+
+        Ok(self.value.get_or_init(|| value))
+    }
+}
+
+impl<T: 'static> Deref for Lazy<T> {
+    type Target = T;
+
+    /// Get the value, resolving it if this is the first use.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be resolved. Use [`Lazy::get`] to handle the error instead.
+    fn deref(&self) -> &T {
+        match self.get() {
+            Ok(value) => value,
+            Err(error) => panic!("Failed to resolve lazy dependency: {error}"),
+        }
+    }
+}
+
+impl<T: 'static> Default for Lazy<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Lazy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Lazy").field(&self.value.get()).finish()
+    }
+}
+
+impl Container {
+    /// Make `Lazy<T>` resolvable, so dependencies on `T` can be deferred until they're used.
+    pub fn lazy<T: 'static>(&mut self) {
+        self.bind(Lazy::<T>::from_container);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Mailer(usize);
+
+    #[test]
+    fn resolves_on_first_use() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut container = Container::new();
+
+        let counter = Arc::clone(&calls);
+        container.bind(move |_| Mailer(counter.fetch_add(1, Ordering::SeqCst)));
+        container.lazy::<Mailer>();
+
+        let mailer = container.resolve::<Lazy<Mailer>>().unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+        assert_eq!(*mailer, Mailer(0));
+        assert_eq!(mailer.get(), Ok(&Mailer(0)));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn resolves_from_the_container_it_came_from() {
+        let mut parent = Container::new();
+        parent.singleton(&|_| Mailer(1));
+        let mut child = Container::with_parent(Arc::new(parent));
+        child.lazy::<Mailer>();
+
+        let mailer = child.resolve::<Lazy<Mailer>>().unwrap();
+        drop(child);
+
+        assert_eq!(mailer.get(), Ok(&Mailer(1)));
+    }
+
+    #[test]
+    #[serial]
+    fn handles_created_directly_resolve_from_the_global_container() {
+        facade::Container::bind(|_| Mailer(2)).unwrap();
+        assert_eq!(Lazy::<Mailer>::new().get(), Ok(&Mailer(2)));
+
+        facade::Container::flush().unwrap();
+        assert!(Lazy::<Mailer>::new().get().is_err());
+    }
+}
@@ -42,32 +42,299 @@
 //! # }
 //! ```
 //!
+//! ## Trait objects
+//!
+//! Bindings are looked up by the exact type their factory returns, so trait objects are registered and resolved behind the same pointer type.
+//! Cast the factory's result to the trait object (otherwise it registers `Box<SmtpMailer>`, and resolving `Box<dyn Mailer>` fails with [`Error::NotFound`]):
+//!
+//! ```rust
+//! use silhouette::facade::Container;
+//! use std::sync::Arc;
+//!
+//! trait Mailer: Send + Sync {}
+//! struct SmtpMailer;
+//! impl Mailer for SmtpMailer {}
+//!
+//! # fn main() -> Result<(), silhouette::facade::Error> {
+//! // a new mailer for every resolution
+//! Container::bind(|_| Box::new(SmtpMailer) as Box<dyn Mailer>)?;
+//! let mailer: Box<dyn Mailer> = Container::resolve()?;
+//!
+//! // a single mailer shared by every resolution
+//! Container::singleton(&|_| Arc::new(SmtpMailer) as Arc<dyn Mailer>)?;
+//! let shared: Arc<dyn Mailer> = Container::resolve()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Container::bind_trait`] and [`Container::bind_boxed`] bind a trait object to an implementation that's registered separately.
+//!
 //! ## Features
 //!
-//! - `nightly` - Automatically resolves types that implement [`Default`]. Requires the nightly compiler.
-
+//! - `nightly` - Automatically resolves types that implement [`Default`]. Requires the nightly compiler. On stable, opt individual types in with [`Container::enable_default_for`] or [`register_defaults!`].
+//! - `plugins` - Load shared libraries at runtime and let them install their own bindings. Only available on Unix.
+//! - `derive` - Derive [`construct::Construct`] for structs whose fields can all be resolved from the container.
+//! - `manifest` - Load declarative manifests choosing which constructor (and lifetime) each service uses, without recompiling.
+//! - `parking_lot` - Guard the global container with `parking_lot`'s `RwLock`, which can't be poisoned and performs better under contention.
+//! - `single_threaded` - Keep the global container in a `RefCell` instead of a lock, for targets without threads like `wasm32-unknown-unknown`. Each thread gets its own global container, and the facade's API stays the same, so factories and instances still have to be `Send + Sync`. Takes precedence over `parking_lot`.
+//! - `tokio` - Add `facade::AsyncContainer`, a static interface whose methods are `async` and wait for access to its container without blocking the executor's thread.
+//! - `concurrent` - Store bindings in a concurrent map, so `Container::bind_concurrently` can register them through a shared reference, and the facade can register them without exclusive access to the global container.
+
+// Lets the derive macros' `::silhouette` paths resolve in the crate's own tests.
+#[cfg(test)]
+extern crate self as silhouette;
+
+use arena::Arena;
+use bindings::{BindingMap, Registration};
+use breaker::CircuitBreaker;
+use call::{AsyncCallable, Callable, ResolvableTuple};
+use connection::ConnectionFactory;
+use events::{ContainerEvent, Subscribers};
+use hash::{TypeMap, TypeSet};
+use hierarchy::Inherited;
+use hooks::Hooks;
+use metadata::Metadata;
+use missing::MissingHandler;
+use pool::{Pool, Pooled};
+use provider::{RegisteredProvider, ServiceProvider};
+use registrar::Registrar;
+use resolver::Source;
+use schedule::{Runtime, Scheduled, ScheduledResolver, Scheduler};
+use semaphore::Semaphore;
+use shared::Shared;
 use std::{
-    any::{Any, TypeId},
+    any::{type_name, Any, TypeId},
     collections::HashMap,
-    sync::{OnceLock, RwLock},
+    fmt,
+    future::Future,
+    mem::MaybeUninit,
+    num::NonZeroUsize,
+    ops::Deref,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex, OnceLock, PoisonError, RwLock, Weak,
+    },
+    thread,
 };
+use teardown::Teardown;
 #[cfg(feature = "nightly")]
 use try_default::TryDefault;
 
-pub(crate) static SERVICE_CONTAINER: OnceLock<RwLock<Container>> = OnceLock::new();
+#[cfg(not(feature = "single_threaded"))]
+pub(crate) static SERVICE_CONTAINER: OnceLock<GlobalLock> = OnceLock::new();
+
+#[cfg(feature = "single_threaded")]
+thread_local! {
+    /// This thread's global container, leaked so it can be handed out for the rest of the program.
+    static SERVICE_CONTAINER: &'static GlobalLock =
+        Box::leak(Box::new(GlobalLock::new(Container::new())));
+}
 
+pub use global::GlobalLock;
+
+/// Arenas that keep resolved values alive until they're released all at once.
+pub mod arena;
+mod bindings;
+mod boot;
+/// Circuit breakers for bindings whose factories can fail.
+pub mod breaker;
+mod cache;
+/// Calling functions with arguments resolved from the container.
+pub mod call;
+#[cfg(feature = "concurrent")]
+mod concurrent;
+mod conditional;
+/// Scopes tied to long-lived connections, disposing of their values when the connection ends.
+pub mod connection;
+/// Auto-wiring for types that can build themselves from the container.
+pub mod construct;
+mod context;
+/// Channels reporting the container's activity to external tooling.
+pub mod events;
 /// A static interface for the service container.
 pub mod facade;
+/// Handles that create fresh values on demand.
+pub mod factory;
+/// Forking a container into a copy that can diverge from it.
+pub mod fork;
+mod frozen;
+/// The lock guarding the global container.
+pub mod global;
+/// Adapters between existing global statics and container bindings.
+pub mod globals;
+mod hash;
+mod hierarchy;
+mod hooks;
+/// A container wrapper that reports every operation to user-supplied callbacks.
+pub mod instrumented;
+/// A trait describing the container's API, for accepting (or mocking) any container.
+pub mod interface;
+/// Handles that defer resolving a dependency until it's used.
+pub mod lazy;
+/// A lock-free cell for the global container on single-threaded targets.
+#[cfg(feature = "single_threaded")]
+pub mod local;
+mod macros;
+/// Declarative manifests for choosing service constructors at runtime.
+#[cfg(feature = "manifest")]
+pub mod manifest;
+/// Merging the registrations of independently assembled containers.
+pub mod merge;
+/// Human-readable descriptions of registered services.
+pub mod metadata;
+mod missing;
+mod multi;
+mod named;
+mod named_scopes;
+mod parameterized;
+mod per_key;
+mod per_resolution;
+/// Loading bindings from shared libraries at runtime.
+#[cfg(all(feature = "plugins", unix))]
+pub mod plugins;
+/// Bounded pools of reusable instances.
+pub mod pool;
+/// Service providers, bundling bindings with a dependency-ordered boot phase.
+pub mod provider;
+/// Zero-sized qualifiers for registering multiple bindings of the same type.
+pub mod qualified;
+/// Bundles of registrations that can be applied to a container in one pass.
+pub mod registrar;
+/// Typed handles for resolving a type repeatedly.
+pub mod resolver;
+/// Services that run periodically, driven by the container.
+pub mod schedule;
+/// Child scopes that cache their own values for a single request or job.
+pub mod scope;
+mod sealed;
+mod semaphore;
+mod shared;
+/// Memory statistics for auditing a container's growth.
+pub mod stats;
+mod tags;
+/// Scopes that follow an async task across `.await` points, consulted by the facade.
+pub mod task;
+mod teardown;
+mod thread_scoped;
+mod typed;
+
+/// A type-erased factory, producing a new value each time it's called.
+pub type Binding = Arc<dyn Fn(&Container) -> Result<Box<dyn Any>, Error> + Sync + Send>;
+/// A type-erased shared instance, producing a copy of the stored value each time it's called.
+pub type Instance = Arc<dyn Fn(&Container) -> Box<dyn Any> + Sync + Send>;
+/// A callback that discards the cached value of a lazy singleton.
+type Reset = Arc<dyn Fn() + Sync + Send>;
+/// A callback notified when a type's registration is replaced.
+type Rebinding = Arc<dyn Fn(&Container) + Sync + Send>;
+/// The factory of a singleton, kept so it can be refreshed, and so forks of lazy singletons can construct their own value.
+type SingletonFactory<T> = Arc<dyn Fn(&Container) -> T + Sync + Send>;
+/// Gives a fork its own copy of a shared instance.
+type Copier = Arc<dyn Fn(&mut Container) + Sync + Send>;
+/// A callback run when the container is booted.
+type BootCallback = Arc<dyn Fn(&mut Container) + Sync + Send>;
+/// An async lifecycle hook, resolving its arguments from the container and returning the future to await.
+type AsyncHook = Arc<
+    dyn Fn(&Container) -> Result<std::pin::Pin<Box<dyn Future<Output = ()> + Send>>, Error>
+        + Sync
+        + Send,
+>;
+/// A decorator applied to every resolved value of a type.
+type Extender = Arc<dyn Fn(Box<dyn Any>, &Container) -> Result<Box<dyn Any>, Error> + Sync + Send>;
+/// A factory that constructs a value directly into caller-provided storage.
+type Emplacer<T> = dyn for<'a> Fn(&Container, &'a mut MaybeUninit<T>) -> &'a mut T + Sync + Send;
+
+/// How long a resolved value lives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lifetime {
+    /// A new value is constructed every time it's resolved.
+    Transient,
+    /// A single value is shared for the lifetime of the container.
+    Singleton,
+    /// A single value is shared until the scoped instances are cleared.
+    Scoped,
+}
 
 /// The service container.
 pub struct Container {
-    #[allow(clippy::type_complexity)]
-    /// The container's bindings.
-    bindings: HashMap<TypeId, Box<(dyn Fn(&Self) -> Box<dyn Any> + Sync + Send)>>,
-    /// The container's shared instances.
-    instances: HashMap<TypeId, Box<(dyn Fn() -> Box<dyn Any> + Sync + Send)>>,
-    /// The container's scoped instances.
-    scoped_instances: Vec<TypeId>,
+    /// The container's bindings and shared instances, along with the factories producing their values without boxing them.
+    bindings: BindingMap,
+    /// The types scoped to each named scope.
+    named_scopes: HashMap<&'static str, Vec<TypeId>>,
+    /// The container's lazily-constructed shared instances, along with their type's name and a callback that discards their cached value.
+    lazy_instances: TypeMap<(&'static str, Reset)>,
+    /// The container's eagerly-constructed shared instances, each holding the `Shared<T>` its value is stored in.
+    swappable: TypeMap<Arc<dyn Any + Sync + Send>>,
+    /// Give a fork its own copy of each shared instance.
+    copiers: TypeMap<Copier>,
+    /// The cleanup callbacks run when each shared instance is removed.
+    teardowns: TypeMap<Teardown>,
+    /// The cleanup callbacks of the instances removed during a [batch](Container::register_batch), run once it succeeds.
+    pending_teardowns: Option<Vec<Teardown>>,
+    /// The async hooks run by [`Container::shutdown_async`], in the order they were registered.
+    async_shutdown_hooks: Vec<AsyncHook>,
+    /// The shared instances, in the order their values were constructed, shared with the container's snapshots.
+    constructed: Arc<Mutex<Vec<TypeId>>>,
+    /// Hands out each singleton `T` as an `Arc<T>`, keyed by the `Arc<T>` type and paired with the `T` type.
+    arcs: TypeMap<(TypeId, Instance)>,
+    /// The container's in-place bindings, each holding a `Box<Emplacer<T>>`.
+    emplacers: TypeMap<Arc<dyn Any + Sync + Send>>,
+    /// The container's service providers.
+    providers: Vec<RegisteredProvider>,
+    /// Whether the container has been booted.
+    booted: bool,
+    /// The lazy singletons constructed when the container is booted, in the order they were registered.
+    eager: Vec<TypeId>,
+    /// The callbacks waiting for the container to be booted, in the order they were registered.
+    boot_callbacks: Vec<BootCallback>,
+    /// The async hooks waiting for the container to be booted with [`Container::boot_async`], in the order they were registered.
+    async_boot_hooks: Vec<AsyncHook>,
+    /// The container's scheduled services.
+    scheduled: Vec<ScheduledResolver>,
+    /// Set when the container is shut down or flushed, stopping every scheduler started from it until then.
+    scheduler_halt: Arc<AtomicBool>,
+    /// The container's pools, each holding an `Arc<Pool<T>>`.
+    pools: TypeMap<Arc<dyn Any + Sync + Send>>,
+    /// The container's thread-scoped bindings, along with the registration their values are cached under.
+    thread_scoped: TypeMap<u64>,
+    /// The container's connection-scoped bindings.
+    connection_bindings: TypeMap<ConnectionFactory>,
+    /// The types whose registrations can't be replaced.
+    sealed: TypeSet,
+    /// Whether a registration was refused because its type was sealed, since the last call to [`Container::take_refused`].
+    refused: bool,
+    /// Whether the container has been made read-only.
+    frozen: bool,
+    /// The container's additive bindings, ordered by descending priority.
+    multi_bindings: TypeMap<Vec<(i32, Binding)>>,
+    /// The container's named bindings, keyed by their type and name.
+    named_bindings: HashMap<(TypeId, &'static str), Binding>,
+    /// The container's parameterized bindings, keyed by their type and argument type, each holding a `Box<ParameterizedFactory<T, Args>>`.
+    parameterized: HashMap<(TypeId, TypeId), Arc<dyn Any + Sync + Send>>,
+    /// The container's bindings shared per key, each holding a `PerKey<T, K>`.
+    per_key: TypeMap<Arc<dyn Any + Sync + Send>>,
+    /// The decorators applied to each type's resolved values, in the order they were registered.
+    extenders: TypeMap<Vec<Extender>>,
+    /// The callbacks notified when each type's registration is replaced.
+    rebinding_callbacks: TypeMap<Vec<Rebinding>>,
+    /// The callbacks that receive freshly resolved values.
+    resolving_hooks: Hooks,
+    /// The callbacks that receive resolved values once the resolving callbacks have run.
+    after_resolving_hooks: Hooks,
+    /// The bindings under each tag, ordered by descending priority.
+    tags: HashMap<&'static str, Vec<(i32, Binding)>>,
+    /// The metadata describing each type, along with the type's name.
+    metadata: TypeMap<(&'static str, Metadata)>,
+    /// Resolves the types that have no registration.
+    missing_handler: Option<MissingHandler>,
+    /// The container to fall back to for the types this one doesn't register.
+    parent: Option<Arc<Self>>,
+    /// Which container builds the bindings inherited from the parent.
+    inherited: Inherited,
+    /// Identifies the container's current registrations for the per-thread last-resolved cache, and changes whenever they do.
+    version: u64,
+    /// The receivers and callbacks notified of the container's activity.
+    subscribers: Subscribers,
 }
 
 impl Container {
@@ -75,280 +342,2392 @@ impl Container {
     #[must_use]
     pub fn new() -> Self {
         Self {
-            bindings: HashMap::new(),
-            instances: HashMap::new(),
-            scoped_instances: Vec::new(),
+            bindings: BindingMap::default(),
+            named_scopes: HashMap::new(),
+            lazy_instances: TypeMap::default(),
+            swappable: TypeMap::default(),
+            copiers: TypeMap::default(),
+            teardowns: TypeMap::default(),
+            pending_teardowns: None,
+            async_shutdown_hooks: Vec::new(),
+            constructed: Arc::default(),
+            arcs: TypeMap::default(),
+            emplacers: TypeMap::default(),
+            providers: Vec::new(),
+            booted: false,
+            eager: Vec::new(),
+            boot_callbacks: Vec::new(),
+            async_boot_hooks: Vec::new(),
+            scheduled: Vec::new(),
+            scheduler_halt: Arc::default(),
+            pools: TypeMap::default(),
+            thread_scoped: TypeMap::default(),
+            connection_bindings: TypeMap::default(),
+            sealed: TypeSet::default(),
+            refused: false,
+            frozen: false,
+            multi_bindings: TypeMap::default(),
+            named_bindings: HashMap::new(),
+            parameterized: HashMap::new(),
+            per_key: TypeMap::default(),
+            extenders: TypeMap::default(),
+            rebinding_callbacks: TypeMap::default(),
+            resolving_hooks: Hooks::default(),
+            after_resolving_hooks: Hooks::default(),
+            tags: HashMap::new(),
+            metadata: TypeMap::default(),
+            missing_handler: None,
+            parent: None,
+            inherited: Inherited::BuiltByParent,
+            version: cache::next_version(),
+            subscribers: Subscribers::default(),
+        }
+    }
+
+    /// Get the global instance of the container.
+    ///
+    /// With the `single_threaded` feature, every thread has its own global container.
+    #[must_use]
+    pub fn get_instance() -> &'static GlobalLock {
+        #[cfg(not(feature = "single_threaded"))]
+        return SERVICE_CONTAINER.get_or_init(|| GlobalLock::new(Self::new()));
+
+        #[cfg(feature = "single_threaded")]
+        return SERVICE_CONTAINER.with(|container| *container);
+    }
+
+    /// Register a binding with the container.
+    pub fn bind<T: 'static>(&mut self, factory: impl Fn(&Self) -> T + 'static + Sync + Send) {
+        let factory = Arc::new(factory);
+
+        let build = Arc::clone(&factory);
+        let binding: Binding = Arc::new(move |container: &Self| {
+            let result = build(container);
+
+            Ok(Box::new(result) as Box<dyn Any>)
+        });
+
+        self.insert_binding(TypeId::of::<T>(), Arc::clone(&binding));
+        self.insert_typed(&Source::Binding(binding), move |container: &Self| {
+            Ok(factory(container))
+        });
+    }
+
+    /// Register a binding whose factory runs at most once, handing its value to the first resolution.
+    ///
+    /// The registration is removed by the first resolution, so [`Container::has`] stops reporting the type and every later resolution fails with [`Error::NotFound`]. This makes it useful for consuming startup-only resources or transferring ownership of a value through the container.
+    pub fn bind_once<T: 'static>(&mut self, factory: impl FnOnce(&Self) -> T + 'static + Send) {
+        let factory = Mutex::new(Some(factory));
+        let spent = Arc::new(AtomicBool::new(false));
+
+        let consumed = Arc::clone(&spent);
+        let binding: Binding = Arc::new(move |container: &Self| {
+            let factory = factory
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .take()
+                .ok_or(Error::NotFound)?;
+            consumed.store(true, Ordering::Release);
+
+            Ok(Box::new(factory(container)) as Box<dyn Any>)
+        });
+
+        self.insert_binding(TypeId::of::<T>(), Arc::clone(&binding));
+        self.bindings
+            .set_spent(&TypeId::of::<T>(), &Source::Binding(binding), spent);
+    }
+
+    /// Register a binding whose factory can fail.
+    ///
+    /// Errors returned by the factory are propagated from [`Container::resolve`] as [`Error::Factory`], except for the container's own errors, which are propagated as they are.
+    pub fn try_bind<T: 'static, E: Into<Box<dyn std::error::Error + Send + Sync>>>(
+        &mut self,
+        factory: impl Fn(&Self) -> Result<T, E> + 'static + Sync + Send,
+    ) {
+        self.insert_binding(
+            TypeId::of::<T>(),
+            Arc::new(move |container: &Self| {
+                let result = factory(container).map_err(Error::from_factory)?;
+
+                Ok(Box::new(result) as Box<dyn Any>)
+            }),
+        );
+    }
+
+    /// Bind the trait object `I` to the implementation `C`, so `Arc<I>` resolves to a `C` resolved from the container.
+    ///
+    /// Rust can't convert an `Arc<C>` into an `Arc<I>` generically, so the conversion is passed in. It's always the identity closure, which the [`bind_trait!`] macro writes for you: `container.bind_trait::<dyn Mailer, SmtpMailer>(|mailer| mailer)`.
+    pub fn bind_trait<I: ?Sized + 'static, C: 'static>(&mut self, upcast: fn(Arc<C>) -> Arc<I>) {
+        self.insert_binding(
+            TypeId::of::<Arc<I>>(),
+            Arc::new(move |container: &Self| {
+                let implementation = container.resolve::<C>()?;
+
+                Ok(Box::new(upcast(Arc::new(implementation))) as Box<dyn Any>)
+            }),
+        );
+    }
+
+    /// Bind the boxed trait object `I` to the implementation `C`, so `Box<I>` resolves to a `C` resolved from the container.
+    ///
+    /// This is the owned counterpart to [`Container::bind_trait`], for trait objects that aren't shared: `container.bind_boxed::<dyn Mailer, SmtpMailer>(|mailer| mailer)`.
+    pub fn bind_boxed<I: ?Sized + 'static, C: 'static>(&mut self, upcast: fn(Box<C>) -> Box<I>) {
+        self.insert_binding(
+            TypeId::of::<Box<I>>(),
+            Arc::new(move |container: &Self| {
+                let implementation = container.resolve::<C>()?;
+
+                Ok(Box::new(upcast(Box::new(implementation))) as Box<dyn Any>)
+            }),
+        );
+    }
+
+    /// Register `Alias` as an alias of `Target`, so resolving `Alias` resolves `Target` and converts it.
+    ///
+    /// `Target` is looked up on every resolution, so the alias follows it if it's re-bound. This exposes a single registration under newtype wrappers without duplicating its factory.
+    pub fn alias<Alias: From<Target> + 'static, Target: 'static>(&mut self) {
+        self.insert_binding(
+            TypeId::of::<Alias>(),
+            Arc::new(|container: &Self| {
+                let target = container.resolve::<Target>()?;
+
+                Ok(Box::new(Alias::from(target)) as Box<dyn Any>)
+            }),
+        );
+    }
+
+    /// Decorate every value resolved for the given type, wrapping or modifying what its binding produces.
+    ///
+    /// Extensions stack in the order they're registered, and apply to bindings and shared instances alike, on every resolution. They're kept when the type is re-bound.
+    pub fn extend<T: 'static>(&mut self, extender: impl Fn(T, &Self) -> T + 'static + Sync + Send) {
+        if self.refuse_sealed(TypeId::of::<T>()) {
+            return;
+        }
+
+        self.extenders
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push(Arc::new(move |value: Box<dyn Any>, container: &Self| {
+                let value = value.downcast::<T>().map_err(|_| Error::CastFailed)?;
+
+                Ok(Box::new(extender(*value, container)) as Box<dyn Any>)
+            }));
+    }
+
+    /// Register a fallible binding, guarded by a circuit breaker.
+    ///
+    /// Once the factory has failed often enough to trip the breaker, resolutions fail immediately with [`Error::CircuitOpen`] until the breaker's cool-down period has passed. Like with [`Container::try_bind`], the factory's errors are propagated as [`Error::Factory`], unless they're the container's own.
+    pub fn bind_with_circuit_breaker<
+        T: 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    >(
+        &mut self,
+        breaker: CircuitBreaker,
+        factory: impl Fn(&Self) -> Result<T, E> + 'static + Sync + Send,
+    ) {
+        self.insert_binding(
+            TypeId::of::<T>(),
+            Arc::new(move |container: &Self| {
+                let result = breaker.call(|| factory(container))?;
+
+                Ok(Box::new(result) as Box<dyn Any>)
+            }),
+        );
+    }
+
+    /// Register a type-erased binding, replacing any other registration for the type.
+    pub(crate) fn insert_binding(&mut self, type_id: TypeId, binding: Binding) {
+        if self.refuse_sealed(type_id) {
+            return;
+        }
+
+        let event = self.registration(type_id, Lifetime::Transient);
+
+        self.tear_down(type_id);
+        self.lazy_instances.remove(&type_id);
+        self.swappable.remove(&type_id);
+        self.emplacers.remove(&type_id);
+        self.connection_bindings.remove(&type_id);
+        self.thread_scoped.remove(&type_id);
+
+        self.bindings
+            .insert(type_id, Registration::Transient(binding));
+        self.version = cache::next_version();
+        self.registered(event);
+    }
+
+    /// Register a type-erased binding that wraps the type's current registration, replacing it without tearing it down.
+    ///
+    /// The wrapped registration keeps serving values through the new binding, so its teardown hook and the cells that swap, refresh or take its value are kept.
+    pub(crate) fn wrap_binding(&mut self, type_id: TypeId, binding: Binding) {
+        if self.refuse_sealed(type_id) {
+            return;
+        }
+
+        let event = self.registration(type_id, Lifetime::Transient);
+
+        self.emplacers.remove(&type_id);
+        self.connection_bindings.remove(&type_id);
+
+        self.bindings
+            .insert(type_id, Registration::Transient(binding));
+        self.version = cache::next_version();
+        self.registered(event);
+    }
+
+    /// Register a type-erased shared instance, replacing any other registration for the type.
+    ///
+    /// Scoped instances are discarded by [`Container::forget_scoped_instances`].
+    pub(crate) fn insert_instance(&mut self, type_id: TypeId, instance: Instance, scoped: bool) {
+        if self.refuse_sealed(type_id) {
+            return;
+        }
+
+        let lifetime = if scoped || self.in_named_scope(type_id) {
+            Lifetime::Scoped
+        } else {
+            Lifetime::Singleton
+        };
+        let event = self.registration(type_id, lifetime);
+
+        self.tear_down(type_id);
+        self.lazy_instances.remove(&type_id);
+        self.swappable.remove(&type_id);
+        self.connection_bindings.remove(&type_id);
+        self.thread_scoped.remove(&type_id);
+
+        self.emplacers.remove(&type_id);
+        self.bindings
+            .insert(type_id, Registration::shared(instance, scoped));
+        self.version = cache::next_version();
+        self.registered(event);
+    }
+
+    /// Register a callback that's notified with the new value whenever the registration for the given type is replaced, or its singleton is [refreshed](Container::refresh).
+    ///
+    /// This lets long-lived services that cached a value at startup pick up its replacement. The new registration is resolved right after it replaces the old one, and the callback isn't called if that fails.
+    pub fn rebinding<T: 'static>(&mut self, callback: impl Fn(&Self, T) + 'static + Sync + Send) {
+        if self.refuse_frozen() {
+            return;
+        }
+
+        self.rebinding_callbacks
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push(Arc::new(move |container: &Self| {
+                if let Ok(value) = container.resolve::<T>() {
+                    callback(container, value);
+                }
+            }));
+    }
+
+    /// Report a registration to subscribers, notifying the type's rebinding callbacks if it replaced an earlier registration.
+    fn registered(&self, event: ContainerEvent) {
+        self.emit(event);
+
+        if let ContainerEvent::Rebound { type_id, .. } = event {
+            self.rebound(type_id);
+        }
+    }
+
+    /// Notify the given type's rebinding callbacks of its new value.
+    fn rebound(&self, type_id: TypeId) {
+        for callback in self.rebinding_callbacks.get(&type_id).into_iter().flatten() {
+            callback(self);
+        }
+    }
+
+    /// Register a binding with the given lifetime, so it can be chosen at runtime (for example, from configuration).
+    ///
+    /// This is equivalent to calling [`Container::bind`], [`Container::singleton`] or [`Container::scoped`], and lets generic registration helpers stay lifetime-agnostic.
+    pub fn register<T: 'static + Clone + Send + Sync>(
+        &mut self,
+        lifetime: Lifetime,
+        factory: impl Fn(&Self) -> T + 'static + Sync + Send,
+    ) {
+        match lifetime {
+            Lifetime::Transient => self.bind(factory),
+            Lifetime::Singleton => self.singleton_refreshable(factory),
+            Lifetime::Scoped => self.scoped(&factory),
+        }
+    }
+
+    /// Register a binding if it hasn't already been registered.
+    pub fn bind_if<T: 'static>(&mut self, factory: impl Fn(&Self) -> T + 'static + Sync + Send) {
+        if !self.bindings.contains_binding(&TypeId::of::<T>()) {
+            self.bind(factory);
+        }
+    }
+
+    /// Resolve the given type with its [`Default`] implementation, unless a binding or shared instance is already registered for it.
+    ///
+    /// This is an explicit, stable replacement for the `nightly` feature's automatic fallback. Bindings registered afterwards replace the default.
+    pub fn enable_default_for<T: 'static + Default>(&mut self) {
+        let type_id = TypeId::of::<T>();
+
+        if !self.bindings.contains_key(&type_id) {
+            self.bind(|_| T::default());
+        }
+    }
+
+    /// Make `Option<T>` resolvable, resolving to `None` when `T` isn't registered instead of failing.
+    ///
+    /// Stable Rust can't tell that a requested type is an `Option`, so optional dependencies are opted into per type, like [`Container::enable_default_for`]. Factories can then express them in the type: `container.resolve::<Option<Metrics>>()`.
+    pub fn optional<T: 'static>(&mut self) {
+        self.insert_binding(
+            TypeId::of::<Option<T>>(),
+            Arc::new(|container: &Self| {
+                let value = container.resolve_optional::<T>()?;
+
+                Ok(Box::new(value) as Box<dyn Any>)
+            }),
+        );
+    }
+
+    /// Register a binding whose factory runs at most `limit` times concurrently.
+    ///
+    /// Resolutions beyond the limit wait until one of the running factories finishes, which protects expensive or exclusive constructors under bursty load.
+    pub fn bind_limited<T: 'static>(
+        &mut self,
+        limit: NonZeroUsize,
+        factory: impl Fn(&Self) -> T + 'static + Sync + Send,
+    ) {
+        let semaphore = Semaphore::new(limit);
+
+        self.bind(move |container: &Self| {
+            let _permit = semaphore.acquire();
+
+            factory(container)
+        });
+    }
+
+    /// Register a binding that constructs its value directly into caller-provided storage.
+    ///
+    /// This avoids moving large values through the container, but the binding can only be resolved with [`Container::resolve_into`].
+    pub fn bind_in_place<T: 'static>(
+        &mut self,
+        factory: impl for<'a> Fn(&Self, &'a mut MaybeUninit<T>) -> &'a mut T + 'static + Sync + Send,
+    ) {
+        let type_id = TypeId::of::<T>();
+        if self.refuse_sealed(type_id) {
+            return;
+        }
+
+        let event = self.registration(type_id, Lifetime::Transient);
+
+        self.tear_down(type_id);
+        self.bindings.remove(&type_id);
+        self.lazy_instances.remove(&type_id);
+        self.swappable.remove(&type_id);
+        self.connection_bindings.remove(&type_id);
+        self.thread_scoped.remove(&type_id);
+
+        self.emplacers
+            .insert(type_id, Arc::new(Box::new(factory) as Box<Emplacer<T>>));
+        self.version = cache::next_version();
+        self.registered(event);
+    }
+
+    /// Register a scoped binding in the container.
+    pub fn scoped<T: 'static + Clone + Send + Sync>(
+        &mut self,
+        factory: &(impl Fn(&Self) -> T + 'static),
+    ) {
+        let result = factory(self);
+
+        self.insert_shared(result, true, None);
+    }
+
+    /// Register a scoped binding if it hasn't already been registered.
+    pub fn scoped_if<T: 'static + Clone + Send + Sync>(
+        &mut self,
+        factory: &(impl Fn(&Self) -> T + 'static),
+    ) {
+        if !self.bindings.is_scoped(&TypeId::of::<T>()) {
+            self.scoped(factory);
+        }
+    }
+
+    /// Register a shared binding in the container.
+    ///
+    /// The factory runs immediately and isn't kept, so the value can't be rebuilt later. Register the singleton with [`Container::singleton_refreshable`] or [`Container::singleton_lazy`] to rebuild it with [`Container::refresh`].
+    ///
+    /// Besides `T`, the singleton can be resolved as an `Arc<T>` pointing to the stored value, unless `Arc<T>` is registered separately.
+    pub fn singleton<T: 'static + Clone + Send + Sync>(
+        &mut self,
+        factory: &(impl Fn(&Self) -> T + 'static),
+    ) {
+        let result = factory(self);
+
+        self.instance(result);
+    }
+
+    /// Register a shared binding whose factory is kept, so [`Container::refresh`] can construct the value again.
+    ///
+    /// Like [`Container::singleton`], the factory runs immediately.
+    pub fn singleton_refreshable<T: 'static + Clone + Send + Sync>(
+        &mut self,
+        factory: impl Fn(&Self) -> T + 'static + Sync + Send,
+    ) {
+        let result = factory(self);
+
+        self.insert_shared(result, false, Some(Arc::new(factory)));
+    }
+
+    /// Register a shared binding that's stored in an [`Arc`], and resolved as `Arc<T>`.
+    ///
+    /// Unlike [`Container::singleton`], `T` doesn't need to implement [`Clone`], and resolving only bumps the reference count.
+    pub fn singleton_arc<T: 'static + Send + Sync>(
+        &mut self,
+        factory: &(impl Fn(&Self) -> T + 'static),
+    ) {
+        let result = Arc::new(factory(self));
+
+        self.instance(result);
+    }
+
+    /// Register an already-constructed value as a shared instance.
+    pub fn instance<T: 'static + Clone + Send + Sync>(&mut self, value: T) {
+        self.insert_shared(value, false, None);
+    }
+
+    /// Store a value in a cell shared by every resolution, as a singleton or a scoped instance, along with the factory that can construct it again.
+    fn insert_shared<T: 'static + Clone + Send + Sync>(
+        &mut self,
+        value: T,
+        scoped: bool,
+        factory: Option<SingletonFactory<T>>,
+    ) {
+        let type_id = TypeId::of::<T>();
+        if self.refuse_sealed(type_id) {
+            return;
+        }
+
+        let cell = Arc::new(Shared::new(value).with_factory(factory));
+        let (instance, arc) = Self::shared_resolvers(&cell);
+
+        self.insert_instance(type_id, Arc::clone(&instance), scoped);
+        self.insert_shared_typed(instance, &cell);
+        self.arcs.insert(TypeId::of::<Arc<T>>(), (type_id, arc));
+        self.swappable.insert(type_id, cell);
+        self.copiers
+            .insert(type_id, Arc::new(Self::copy_shared::<T>));
+        self.constructed(type_id);
+    }
+
+    /// Build the resolvers handing out the value stored in an eagerly-constructed singleton's cell, as `T` and as `Arc<T>`.
+    fn shared_resolvers<T: 'static + Clone + Send + Sync>(
+        cell: &Arc<Shared<T>>,
+    ) -> (Instance, Instance) {
+        let shared = Arc::clone(cell);
+        let instance: Instance =
+            Arc::new(move |_: &Self| Box::new((*shared.get()).clone()) as Box<dyn Any>);
+
+        let shared = Arc::clone(cell);
+        let arc: Instance = Arc::new(move |_: &Self| Box::new(shared.get()) as Box<dyn Any>);
+
+        (instance, arc)
+    }
+
+    /// Remember a factory that clones the value stored in an eagerly-constructed singleton's cell, without boxing it.
+    fn insert_shared_typed<T: 'static + Clone + Send + Sync>(
+        &mut self,
+        instance: Instance,
+        cell: &Arc<Shared<T>>,
+    ) {
+        let shared = Arc::clone(cell);
+
+        self.insert_typed(&Source::Instance(instance), move |_: &Self| {
+            Ok((*shared.get()).clone())
+        });
+    }
+
+    /// Atomically replace the value of a shared instance, returning the previous value.
+    ///
+    /// Only instances registered with [`Container::singleton`], [`Container::scoped`] or [`Container::instance`] can be swapped. Threads resolving the instance concurrently receive either the old or the new value.
+    ///
+    /// Returns `None` (dropping `value`) if no such instance is registered for the type.
+    pub fn swap<T: 'static>(&self, value: T) -> Option<T> {
+        let cell = self
+            .swappable
+            .get(&TypeId::of::<T>())?
+            .downcast_ref::<Shared<T>>()?;
+
+        Some(cell.replace(value))
+    }
+
+    /// Remove the shared instance registered for the given type from the container, handing its value back to the caller.
+    ///
+    /// Only instances registered with [`Container::singleton`], [`Container::scoped`] or [`Container::instance`] can be taken. This is meant for shutdown, to reclaim resources like connection pools and close them explicitly. If the value is still shared (for example, by an `Arc` resolved earlier), a copy is returned instead.
+    ///
+    /// Returns `None` if no such instance is registered for the type, or if its registration is sealed.
+    pub fn take<T: 'static + Send + Sync>(&mut self) -> Option<T> {
+        let type_id = TypeId::of::<T>();
+        let cell = Arc::clone(self.swappable.get(&type_id)?)
+            .downcast::<Shared<T>>()
+            .ok()?;
+
+        let teardown = self.teardowns.remove(&type_id);
+        self.forget::<T>();
+        if self.swappable.contains_key(&type_id) {
+            if let Some(teardown) = teardown {
+                self.teardowns.insert(type_id, teardown);
+            }
+
+            return None;
+        }
+
+        self.arcs.remove(&TypeId::of::<Arc<T>>());
+
+        Some(Shared::into_value(cell))
+    }
+
+    /// Register a shared binding whose factory can fail.
+    ///
+    /// The factory runs immediately, like [`Container::singleton`]. If it fails, nothing is registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns the factory's error as [`Error::Factory`].
+    pub fn try_singleton<
+        T: 'static + Clone + Send + Sync,
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    >(
+        &mut self,
+        factory: &(impl Fn(&Self) -> Result<T, E> + 'static),
+    ) -> Result<(), Error> {
+        let result = factory(self).map_err(Error::from_factory)?;
+
+        self.instance(result);
+
+        Ok(())
+    }
+
+    /// Register a pool of at most `max` reusable instances, created on demand by the factory.
+    ///
+    /// Instances are checked out with [`Container::resolve_pooled`] (or by resolving [`Pooled<T>`], so they can be injected like any other dependency), and returned to the pool when the guard is dropped.
+    pub fn pooled<T: 'static + Send>(
+        &mut self,
+        max: NonZeroUsize,
+        factory: impl Fn(&Self) -> T + 'static + Sync + Send,
+    ) {
+        let pool = Arc::new(Pool::new(max, factory));
+
+        let shared = Arc::clone(&pool);
+        self.insert_binding(
+            TypeId::of::<Pooled<T>>(),
+            Arc::new(move |container: &Self| {
+                Ok(Box::new(shared.checkout(container)) as Box<dyn Any>)
+            }),
+        );
+
+        self.pools.insert(TypeId::of::<T>(), Arc::new(pool));
+    }
+
+    /// Register a shared binding that is only constructed the first time it's resolved.
+    ///
+    /// If several threads resolve the singleton for the first time at once, the factory still runs only once, and every thread receives the same value.
+    pub fn singleton_lazy<T: 'static + Clone + Send + Sync>(
+        &mut self,
+        factory: impl Fn(&Self) -> T + 'static + Sync + Send,
+    ) {
+        let type_id = TypeId::of::<T>();
+        if self.refuse_sealed(type_id) {
+            return;
         }
+
+        let factory: SingletonFactory<T> = Arc::new(factory);
+        let (instance, arc, reset) = Self::lazy_resolvers(&factory);
+
+        self.insert_instance(type_id, instance, false);
+        self.arcs.insert(TypeId::of::<Arc<T>>(), (type_id, arc));
+        self.lazy_instances
+            .insert(type_id, (type_name::<T>(), reset));
+        self.copiers.insert(
+            type_id,
+            Arc::new(move |container: &mut Self| container.copy_lazy(&factory)),
+        );
     }
 
-    /// Get the global instance of the container.
-    pub fn get_instance() -> &'static RwLock<Self> {
-        SERVICE_CONTAINER.get_or_init(|| RwLock::new(Self::new()))
-    }
+    /// Build the resolvers for a lazy singleton, constructing its value on first use as `T` or as `Arc<T>`, along with the callback discarding it.
+    fn lazy_resolvers<T: 'static + Clone + Send + Sync>(
+        factory: &SingletonFactory<T>,
+    ) -> (Instance, Instance, Reset) {
+        let cell: Arc<RwLock<Arc<OnceLock<Arc<T>>>>> = Arc::default();
+
+        let (shared, build) = (Arc::clone(&cell), Arc::clone(factory));
+        let instance: Instance = Arc::new(move |container: &Self| {
+            let value = Arc::clone(&shared.read().unwrap_or_else(PoisonError::into_inner));
+
+            Box::new(
+                (**value.get_or_init(|| {
+                    let value = Arc::new(build(container));
+                    container.constructed(TypeId::of::<T>());
+
+                    value
+                }))
+                .clone(),
+            ) as Box<dyn Any>
+        });
+
+        let (shared, build) = (Arc::clone(&cell), Arc::clone(factory));
+        let arc: Instance = Arc::new(move |container: &Self| {
+            let value = Arc::clone(&shared.read().unwrap_or_else(PoisonError::into_inner));
+
+            Box::new(Arc::clone(value.get_or_init(|| {
+                let value = Arc::new(build(container));
+                container.constructed(TypeId::of::<T>());
+
+                value
+            }))) as Box<dyn Any>
+        });
+
+        let reset: Reset = Arc::new(move || {
+            *cell.write().unwrap_or_else(PoisonError::into_inner) = Arc::new(OnceLock::new());
+        });
+
+        (instance, arc, reset)
+    }
+
+    /// Register a shared binding that's resolved as `Arc<T>`, but only kept alive by the values handed out.
+    ///
+    /// The container holds a weak reference, so once every resolved `Arc<T>` is dropped the value is reclaimed, and the factory builds a new one on the next resolve.
+    pub fn singleton_weak<T: 'static + Send + Sync>(
+        &mut self,
+        factory: impl Fn(&Self) -> T + 'static + Sync + Send,
+    ) {
+        let cell = Mutex::new(Weak::new());
+
+        self.insert_instance(
+            TypeId::of::<Arc<T>>(),
+            Arc::new(move |container: &Self| {
+                let mut weak = cell.lock().unwrap_or_else(PoisonError::into_inner);
+
+                let value = weak.upgrade().unwrap_or_else(|| {
+                    let value = Arc::new(factory(container));
+                    *weak = Arc::downgrade(&value);
+
+                    value
+                });
+                drop(weak);
+
+                Box::new(value) as Box<dyn Any>
+            }),
+            false,
+        );
+    }
+
+    /// Construct a singleton's value again with its factory, replacing the current one.
+    ///
+    /// Lazy singletons registered with [`Container::singleton_lazy`] discard their value, so their factory runs again on the next resolve, while singletons registered with [`Container::singleton_refreshable`] run it right away. Singletons registered with [`Container::singleton`], and instances registered with [`Container::instance`] or [`Container::scoped`], have no factory, and can't be refreshed. If [rebinding callbacks](Container::rebinding) are registered for the type, they're notified of the new value, which constructs it right away.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no singleton with a factory is registered for the requested type.
+    pub fn refresh<T: 'static>(&self) -> Result<(), Error> {
+        let type_id = TypeId::of::<T>();
+        if let Some((_, reset)) = self.lazy_instances.get(&type_id) {
+            reset();
+        } else {
+            let cell = self
+                .swappable
+                .get(&type_id)
+                .and_then(|cell| cell.downcast_ref::<Shared<T>>())
+                .ok_or(Error::NotFound)?;
+            if !cell.rebuild(self) {
+                return Err(Error::NotFound);
+            }
+
+            self.constructed(type_id);
+        }
+
+        self.rebound(type_id);
+
+        Ok(())
+    }
+
+    /// Register a shared binding if it hasn't already been registered.
+    pub fn singleton_if<T: 'static + Clone + Send + Sync>(
+        &mut self,
+        factory: &(impl Fn(&Self) -> T + 'static),
+    ) {
+        if !self.bindings.contains_instance(&TypeId::of::<T>()) {
+            self.singleton(factory);
+        }
+    }
+
+    /// Whether a binding, shared instance, pool or connection-scoped binding is registered for the given type.
+    #[must_use]
+    pub fn has<T: 'static>(&self) -> bool {
+        let type_id = TypeId::of::<T>();
+
+        self.bindings.contains_key(&type_id)
+            || self.emplacers.contains_key(&type_id)
+            || self.connection_bindings.contains_key(&type_id)
+            || self.pools.contains_key(&type_id)
+    }
+
+    /// Whether the given type is registered as a singleton, shared for the lifetime of the container.
+    #[must_use]
+    pub fn is_singleton<T: 'static>(&self) -> bool {
+        let type_id = TypeId::of::<T>();
+
+        matches!(
+            self.bindings.get(&type_id),
+            Some(Registration::Singleton(_))
+        ) && !self.in_named_scope(type_id)
+    }
+
+    /// Whether the given type is registered as scoped, either to the container's current scope, to a named scope, to each thread, or to each connection scope.
+    #[must_use]
+    pub fn is_scoped<T: 'static>(&self) -> bool {
+        let type_id = TypeId::of::<T>();
+
+        self.bindings.is_scoped(&type_id)
+            || (self.bindings.contains_instance(&type_id) && self.in_named_scope(type_id))
+            || self.thread_scoped.contains_key(&type_id)
+            || self.connection_bindings.contains_key(&type_id)
+    }
+
+    /// Resolve the given type from the container.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the requested type cannot be found or if the requested type cannot be cast from the binding.
+    ///
+    /// If the failure happened while resolving a dependency of another type, the error is wrapped in [`Error::Resolution`], which lists the chain of types that requested it.
+    pub fn resolve<T: 'static>(&self) -> Result<T, Error> {
+        let frame = context::enter(type_name::<T>());
+
+        if let Some(result) = self.resolve_typed() {
+            return result.map_err(|error| frame.wrap(error));
+        }
+
+        match self.resolve_any(TypeId::of::<T>()) {
+            Ok(value) => value
+                .downcast::<T>()
+                .map(|v| *v)
+                .map_err(|_| Error::CastFailed),
+            Err(Error::NotFound) => try_default_if_enabled().ok_or(Error::NotFound),
+            Err(e) => Err(e),
+        }
+        .map_err(|error| frame.wrap(error))
+    }
+
+    /// Borrow the value of a shared instance, without cloning it.
+    ///
+    /// Only instances registered with [`Container::singleton`], [`Container::scoped`] or [`Container::instance`] can be borrowed. The returned pointer keeps the value it was resolved with if the instance is [swapped](Container::swap) afterwards, and extensions and resolution hooks aren't applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no such instance is registered for the requested type.
+    pub fn resolve_ref<T: 'static>(&self) -> Result<impl Deref<Target = T> + '_, Error> {
+        let cell = self
+            .swappable
+            .get(&TypeId::of::<T>())
+            .ok_or(Error::NotFound)?
+            .downcast_ref::<Shared<T>>()
+            .ok_or(Error::CastFailed)?;
+
+        Ok(cell.get())
+    }
+
+    /// Mutate the value of a shared instance in place, returning the closure's result.
+    ///
+    /// Only instances registered with [`Container::singleton`], [`Container::scoped`] or [`Container::instance`] can be mutated. Other threads resolving the instance wait until the closure returns, while `Arc`s to the instance resolved earlier keep the previous value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no such instance is registered for the requested type.
+    pub fn resolve_mut<T: 'static, R>(&self, mutate: impl FnOnce(&mut T) -> R) -> Result<R, Error> {
+        let cell = self
+            .swappable
+            .get(&TypeId::of::<T>())
+            .ok_or(Error::NotFound)?
+            .downcast_ref::<Shared<T>>()
+            .ok_or(Error::CastFailed)?;
+
+        Ok(cell.update(mutate))
+    }
+
+    /// Resolve the given type from the container, or produce a fallback value if it isn't registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the type is registered but can't be resolved, for example because one of its dependencies is missing.
+    pub fn resolve_or_else<T: 'static>(&self, fallback: impl FnOnce() -> T) -> Result<T, Error> {
+        if self.source(TypeId::of::<T>()).is_err() {
+            return Ok(fallback());
+        }
+
+        self.resolve()
+    }
+
+    /// Resolve the given type from the container, or return `None` if it isn't registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the type is registered but can't be resolved, for example because one of its dependencies is missing.
+    pub fn resolve_optional<T: 'static>(&self) -> Result<Option<T>, Error> {
+        if self.source(TypeId::of::<T>()).is_err() {
+            return Ok(None);
+        }
+
+        self.resolve().map(Some)
+    }
+
+    /// Resolve the given type from the container, or use its [`Default`] implementation if it isn't registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the type is registered but can't be resolved, for example because one of its dependencies is missing.
+    pub fn resolve_or_default<T: 'static + Default>(&self) -> Result<T, Error> {
+        self.resolve_or_else(T::default)
+    }
+
+    /// Resolve the given type from the container into caller-provided storage.
+    ///
+    /// Bindings registered with [`Container::bind_in_place`] are constructed directly into `slot`. Other bindings are resolved as usual and moved into it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the requested type cannot be found or if the requested type cannot be cast from the binding.
+    pub fn resolve_into<'a, T: 'static>(
+        &self,
+        slot: &'a mut MaybeUninit<T>,
+    ) -> Result<&'a mut T, Error> {
+        let type_id = TypeId::of::<T>();
+
+        if !self.bindings.contains_instance(&type_id) {
+            if let Some(emplacer) = self.emplacers.get(&type_id) {
+                let emplacer = emplacer
+                    .downcast_ref::<Box<Emplacer<T>>>()
+                    .ok_or(Error::CastFailed)?;
+
+                return Ok(emplacer(self, slot));
+            }
+        }
+
+        Ok(slot.write(self.resolve()?))
+    }
+
+    /// Check an instance out of the pool registered for the given type, waiting for one to be returned if the pool is exhausted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no pool is registered for the requested type.
+    pub fn resolve_pooled<T: 'static + Send>(&self) -> Result<Pooled<T>, Error> {
+        let pool = self
+            .pools
+            .get(&TypeId::of::<T>())
+            .ok_or(Error::NotFound)?
+            .downcast_ref::<Arc<Pool<T>>>()
+            .ok_or(Error::CastFailed)?;
+
+        Ok(pool.checkout(self))
+    }
+
+    /// Resolve the given type from the container into an arena, returning a reference that lives as long as the arena.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the requested type cannot be found or if the requested type cannot be cast from the binding.
+    pub fn resolve_in<'a, T: 'static + Send + Sync>(
+        &self,
+        arena: &'a Arena,
+    ) -> Result<&'a T, Error> {
+        Ok(arena.alloc(self.resolve::<T>()?))
+    }
+
+    /// Resolve several types at once, as a tuple: `container.resolve_tuple::<(DbPool, Mailer, Config)>()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the types cannot be resolved.
+    pub fn resolve_tuple<T: ResolvableTuple>(&self) -> Result<T, Error> {
+        T::resolve(self)
+    }
+
+    /// Call the given function, resolving its arguments from the container.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the function's arguments cannot be resolved.
+    pub fn call<Args, F: Callable<Args>>(&self, function: F) -> Result<F::Output, Error> {
+        function.call(self)
+    }
+
+    /// Call the given async function, resolving its arguments from the container, and await it.
+    ///
+    /// The arguments are resolved immediately, so the returned future doesn't borrow the container.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the function's arguments cannot be resolved.
+    pub fn call_async<Args, F: AsyncCallable<Args>>(
+        &self,
+        function: F,
+    ) -> impl Future<Output = Result<<F::Future as Future>::Output, Error>> {
+        let prepared = function.prepare(self);
+
+        async move { Ok(prepared?.await) }
+    }
+
+    /// Resolve the value registered for the given [`TypeId`], without casting it.
+    pub(crate) fn resolve_any(&self, type_id: TypeId) -> Result<Box<dyn Any>, Error> {
+        let result = match self.source(type_id) {
+            Ok(source) => source.produce(self),
+            Err(Error::NotFound) => self.resolve_inherited(type_id),
+            Err(error) => Err(error),
+        }
+        .and_then(|value| self.extend_any(type_id, value));
+
+        self.emit(ContainerEvent::Resolved {
+            type_id,
+            success: result.is_ok(),
+        });
+
+        result
+    }
+
+    /// Apply the decorators registered for the given [`TypeId`] to a resolved value, then run its resolution hooks.
+    pub(crate) fn extend_any(
+        &self,
+        type_id: TypeId,
+        value: Box<dyn Any>,
+    ) -> Result<Box<dyn Any>, Error> {
+        let mut value = self
+            .extenders
+            .get(&type_id)
+            .into_iter()
+            .flatten()
+            .try_fold(value, |value, extender| extender(value, self))?;
+
+        self.resolving_hooks.run(type_id, value.as_mut(), self);
+        self.after_resolving_hooks
+            .run(type_id, value.as_mut(), self);
+
+        Ok(value)
+    }
+
+    /// Find where values of the given [`TypeId`] come from.
+    ///
+    /// The last type resolved on each thread is cached, so resolving the same type repeatedly skips the lookup.
+    fn source(&self, type_id: TypeId) -> Result<Source, Error> {
+        if let Some(source) = cache::get(self.version, type_id) {
+            return Ok(source);
+        }
+
+        let source = Source::lookup(self, type_id).ok_or(Error::NotFound)?;
+        cache::set(self.version, type_id, &source);
+
+        Ok(source)
+    }
+
+    /// Hand out the singleton `T` as an `Arc<T>`, given the `Arc<T>` type, as long as `T` is still registered as a singleton.
+    pub(crate) fn shared_arc(&self, type_id: TypeId) -> Option<Instance> {
+        let (owner, instance) = self.arcs.get(&type_id)?;
+
+        (self.swappable.contains_key(owner) || self.lazy_instances.contains_key(owner))
+            .then(|| Arc::clone(instance))
+    }
+
+    /// Apply a batch of registrations atomically.
+    ///
+    /// The closure receives the container and can register bindings as usual. If it returns an error, every registration made inside the batch is rolled back and the container is left exactly as it was. The cleanup callbacks of instances the batch replaces or removes only run once it succeeds, so rolling it back never restores an instance that was already torn down.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error produced by the closure, after rolling back its registrations.
+    pub fn register_batch<E>(
+        &mut self,
+        batch: impl FnOnce(&mut Self) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let snapshot = self.snapshot();
+        let constructed = self.constructed_order();
+        let outer = self.pending_teardowns.replace(Vec::new());
+
+        if let Err(error) = batch(self) {
+            *self = snapshot;
+            self.pending_teardowns = outer;
+            // The order is shared with the snapshot, so the instances constructed inside the batch have to be dropped from it too.
+            *self
+                .constructed
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner) = constructed;
+
+            return Err(error);
+        }
+
+        let teardowns = std::mem::replace(&mut self.pending_teardowns, outer).unwrap_or_default();
+        match &mut self.pending_teardowns {
+            // Nested in another batch, which can still be rolled back.
+            Some(pending) => pending.extend(teardowns),
+            None => teardowns.iter().for_each(|teardown| teardown()),
+        }
+
+        Ok(())
+    }
+
+    /// Apply every registrar to the container, in order.
+    pub fn register_all(&mut self, registrars: impl IntoIterator<Item = Box<dyn Registrar>>) {
+        for registrar in registrars {
+            registrar.register(self);
+        }
+    }
+
+    /// Register a service provider with the container.
+    ///
+    /// The provider's bindings are registered immediately. If the container has already been booted, the provider is booted right away as well.
+    pub fn register_provider<P: ServiceProvider + 'static>(&mut self, provider: P) {
+        if self.refuse_frozen() {
+            return;
+        }
+
+        let mut provider = RegisteredProvider::new(provider);
+        provider.provider.register(self);
+
+        if self.booted {
+            provider.provider.boot(self);
+            provider.booted = true;
+        }
+
+        self.providers.push(provider);
+    }
+
+    /// Boot every registered service provider that hasn't been booted yet, in dependency order.
+    ///
+    /// Once the providers are booted, the [eager singletons](Container::singleton_eager) are constructed and the [boot callbacks](Container::booting) run, each in the order they were registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the providers' dependencies form a cycle, in which case no provider is booted.
+    pub fn boot(&mut self) -> Result<(), Error> {
+        for index in provider::boot_order(&self.providers)? {
+            let provider = Arc::clone(&self.providers[index].provider);
+
+            provider.boot(self);
+            self.providers[index].booted = true;
+        }
+
+        self.booted = true;
+        self.construct_eager();
+        for callback in std::mem::take(&mut self.boot_callbacks) {
+            callback(self);
+        }
+
+        Ok(())
+    }
+
+    /// Construct every lazily-registered singleton up front.
+    ///
+    /// This moves construction cost to startup instead of the first resolution. Factories that panic don't abort the warm-up: every singleton is attempted, and the ones that failed are reported (and left to be constructed again on their next resolve).
+    #[must_use]
+    pub fn warm_up(&self) -> Vec<WarmUpFailure> {
+        let mut failures = Vec::new();
+
+        for (type_id, (type_name, _)) in &self.lazy_instances {
+            let Some(instance) = self.bindings.instance(type_id) else {
+                continue;
+            };
+
+            if let Err(panic) = panic::catch_unwind(AssertUnwindSafe(|| instance(self))) {
+                failures.push(WarmUpFailure::new(*type_id, type_name, panic.as_ref()));
+            }
+        }
+
+        failures
+    }
+
+    /// Construct every lazily-registered singleton up front, spreading them across as many threads as the machine can run in parallel.
+    ///
+    /// A singleton whose factory resolves another one that's being constructed on a different thread waits for it to finish, so dependencies are still only built once. Failures are reported like [`Container::warm_up`]'s, in no particular order.
+    #[must_use]
+    pub fn warm_up_parallel(&self) -> Vec<WarmUpFailure> {
+        let instances: Vec<(TypeId, &'static str, Instance)> = self
+            .lazy_instances
+            .iter()
+            .filter_map(|(type_id, (type_name, _))| {
+                Some((*type_id, *type_name, self.bindings.instance(type_id)?))
+            })
+            .collect();
+        let threads = thread::available_parallelism()
+            .map_or(1, NonZeroUsize::get)
+            .min(instances.len());
+
+        let next = AtomicUsize::new(0);
+        thread::scope(|scope| {
+            let workers: Vec<_> = (0..threads)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut failures = Vec::new();
+
+                        while let Some((type_id, type_name, instance)) =
+                            instances.get(next.fetch_add(1, Ordering::Relaxed))
+                        {
+                            if let Err(panic) =
+                                panic::catch_unwind(AssertUnwindSafe(|| instance(self)))
+                            {
+                                failures.push(WarmUpFailure::new(
+                                    *type_id,
+                                    type_name,
+                                    panic.as_ref(),
+                                ));
+                            }
+                        }
+
+                        failures
+                    })
+                })
+                .collect();
+
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().unwrap_or_default())
+                .collect()
+        })
+    }
+
+    /// Register a service to be run periodically once the scheduler is started.
+    ///
+    /// The service itself is resolved from the container when the scheduler starts.
+    pub fn schedule<T: Scheduled>(&mut self) {
+        if self.refuse_frozen() {
+            return;
+        }
+
+        self.scheduled.push(Arc::new(|container: &Self| {
+            Ok(Arc::new(container.resolve::<T>()?) as Arc<dyn Scheduled>)
+        }));
+    }
+
+    /// Resolve every scheduled service and start running them on the given runtime.
+    ///
+    /// The services keep running until the returned [`Scheduler`] is shut down or dropped, or until the container is [shut down](Container::shutdown) or [flushed](Container::flush).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the scheduled services cannot be resolved, in which case none of them are started.
+    pub fn start_scheduler(&self, runtime: impl Runtime) -> Result<Scheduler, Error> {
+        let services = self
+            .scheduled
+            .iter()
+            .map(|resolve| resolve(self))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Scheduler::start(runtime, services, &self.scheduler_halt))
+    }
+
+    /// Remove the given type's registration and any instance cached for it, leaving the rest of the container untouched.
+    ///
+    /// Named, tagged and additive bindings for the type are kept, since they're registered separately.
+    pub fn forget<T: 'static>(&mut self) {
+        let type_id = TypeId::of::<T>();
+        if self.refuse_sealed(type_id) {
+            return;
+        }
+
+        self.tear_down(type_id);
+        self.bindings.remove(&type_id);
+        self.lazy_instances.remove(&type_id);
+        self.swappable.remove(&type_id);
+        self.emplacers.remove(&type_id);
+        self.connection_bindings.remove(&type_id);
+        self.thread_scoped.remove(&type_id);
+        self.pools.remove(&type_id);
+        for types in self.named_scopes.values_mut() {
+            types.retain(|scoped| *scoped != type_id);
+        }
+        self.metadata.remove(&type_id);
+
+        self.version = cache::next_version();
+        self.emit(ContainerEvent::Forgotten { type_id });
+    }
+
+    /// Clear all of the scoped instances from the container.
+    ///
+    /// This also ends the calling thread's scope, dropping the values of [thread-scoped](Container::thread_scoped) bindings it constructed.
+    pub fn forget_scoped_instances(&mut self) {
+        for instance in self.bindings.scoped() {
+            self.tear_down(instance);
+            self.bindings.remove(&instance);
+            self.swappable.remove(&instance);
+        }
+        self.forget_thread_scoped_instances();
+
+        self.version = cache::next_version();
+        self.emit(ContainerEvent::ScopeEnded);
+    }
+
+    /// Flush the container of all bindings and resolved instances.
+    ///
+    /// The cleanup callbacks registered with [`Container::singleton_with_drop`] run before their instances are dropped.
+    pub fn flush(&mut self) {
+        for (_, teardown) in self.teardowns.drain() {
+            teardown();
+        }
+        self.constructed = Arc::default();
+        self.bindings.clear();
+        self.named_scopes.clear();
+        self.lazy_instances.clear();
+        self.swappable.clear();
+        self.copiers.clear();
+        self.arcs.clear();
+        self.emplacers.clear();
+        self.providers.clear();
+        self.booted = false;
+        self.eager.clear();
+        self.boot_callbacks.clear();
+        self.async_boot_hooks.clear();
+        self.async_shutdown_hooks.clear();
+        self.scheduled.clear();
+        self.halt_schedulers();
+        self.pools.clear();
+        self.forget_thread_scoped_instances();
+        self.thread_scoped.clear();
+        self.connection_bindings.clear();
+        self.sealed.clear();
+        self.refused = false;
+        self.frozen = false;
+        self.multi_bindings.clear();
+        self.named_bindings.clear();
+        self.parameterized.clear();
+        self.per_key.clear();
+        self.extenders.clear();
+        self.rebinding_callbacks.clear();
+        self.resolving_hooks = Hooks::default();
+        self.after_resolving_hooks = Hooks::default();
+        self.tags.clear();
+        self.metadata.clear();
+        self.missing_handler = None;
+        self.version = cache::next_version();
+        self.emit(ContainerEvent::Flushed);
+    }
+
+    /// Create a copy of the container whose bindings stay shared with it, so bindings registered concurrently (with the `concurrent` feature) are seen by both.
+    fn share(&self) -> Self {
+        let mut copy = self.snapshot();
+        copy.bindings = self.bindings.share();
+
+        copy
+    }
+
+    /// Create a copy of the container that shares its factories and instances.
+    fn snapshot(&self) -> Self {
+        Self {
+            bindings: self.bindings.clone(),
+            named_scopes: self.named_scopes.clone(),
+            lazy_instances: self.lazy_instances.clone(),
+            swappable: self.swappable.clone(),
+            copiers: self.copiers.clone(),
+            teardowns: self.teardowns.clone(),
+            pending_teardowns: None,
+            async_shutdown_hooks: self.async_shutdown_hooks.clone(),
+            constructed: Arc::clone(&self.constructed),
+            arcs: self.arcs.clone(),
+            emplacers: self.emplacers.clone(),
+            providers: self.providers.clone(),
+            booted: self.booted,
+            eager: self.eager.clone(),
+            boot_callbacks: self.boot_callbacks.clone(),
+            async_boot_hooks: self.async_boot_hooks.clone(),
+            scheduled: self.scheduled.clone(),
+            scheduler_halt: Arc::clone(&self.scheduler_halt),
+            pools: self.pools.clone(),
+            thread_scoped: self.thread_scoped.clone(),
+            connection_bindings: self.connection_bindings.clone(),
+            sealed: self.sealed.clone(),
+            refused: self.refused,
+            frozen: self.frozen,
+            multi_bindings: self.multi_bindings.clone(),
+            named_bindings: self.named_bindings.clone(),
+            parameterized: self.parameterized.clone(),
+            per_key: self.per_key.clone(),
+            extenders: self.extenders.clone(),
+            rebinding_callbacks: self.rebinding_callbacks.clone(),
+            resolving_hooks: self.resolving_hooks.clone(),
+            after_resolving_hooks: self.after_resolving_hooks.clone(),
+            tags: self.tags.clone(),
+            metadata: self.metadata.clone(),
+            missing_handler: self.missing_handler.clone(),
+            parent: self.parent.clone(),
+            inherited: self.inherited,
+            version: self.version,
+            subscribers: self.subscribers.clone(),
+        }
+    }
+}
+
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(not(feature = "nightly"))]
+const fn try_default_if_enabled<T>() -> Option<T> {
+    None
+}
+
+#[cfg(feature = "nightly")]
+fn try_default_if_enabled<T>() -> Option<T> {
+    T::try_default()
+}
+
+/// An error that can occur when interacting with the container.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// Binding not found.
+    #[error("Binding not found")]
+    NotFound,
+
+    /// Failed to cast binding to requested type.
+    #[error("Failed to cast binding to requested type")]
+    CastFailed,
+
+    /// The binding's factory has failed too many times, and is cooling down.
+    #[error("Binding is failing, and its circuit breaker is open")]
+    CircuitOpen,
+
+    /// The service providers' dependencies form a cycle.
+    #[error("Circular dependency between service providers: {}", .0.join(", "))]
+    ProviderCycle(Vec<&'static str>),
+
+    /// Resolving a dependency failed. The chain lists the type that failed first, followed by each type that requested it.
+    #[error("Failed to resolve {}: {source}", .chain.join(" ← "))]
+    Resolution {
+        /// The types being resolved, starting with the one that failed.
+        chain: Vec<&'static str>,
+        /// Why the innermost type couldn't be resolved.
+        source: Box<Self>,
+    },
+
+    /// The type's registration is sealed, and can't be replaced.
+    #[error("Binding is sealed, and can't be replaced")]
+    Sealed,
+
+    /// The container has been frozen, and can't accept registrations.
+    #[error("Container is frozen, and can't accept registrations")]
+    Frozen,
+
+    /// The merged container registers types that are already registered.
+    #[error("{} of the merged types are already registered", .0.len())]
+    Conflict(Vec<TypeId>),
+
+    /// A fallible factory returned an error.
+    #[error("Factory failed: {0}")]
+    Factory(#[source] FactoryError),
+}
+
+impl Error {
+    /// Convert the error returned by a fallible factory, keeping the container's own errors (such as those of the factory's dependencies) as they are.
+    pub(crate) fn from_factory(error: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Self {
+        match error.into().downcast::<Self>() {
+            Ok(error) => *error,
+            Err(error) => Self::Factory(FactoryError(error)),
+        }
+    }
+}
+
+/// The error returned by a fallible factory, registered with [`Container::try_bind`] or [`Container::try_singleton`].
+///
+/// Factory errors compare equal when their messages match, so [`Error`] can keep implementing [`PartialEq`].
+#[derive(Debug)]
+pub struct FactoryError(Box<dyn std::error::Error + Send + Sync>);
+
+impl FactoryError {
+    /// Wrap the given error.
+    pub fn new(error: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Self {
+        Self(error.into())
+    }
+
+    /// Unwrap the factory's error.
+    #[must_use]
+    pub fn into_inner(self) -> Box<dyn std::error::Error + Send + Sync> {
+        self.0
+    }
+}
+
+impl fmt::Display for FactoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for FactoryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.0.as_ref())
+    }
+}
+
+impl PartialEq for FactoryError {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_string() == other.0.to_string()
+    }
+}
+
+impl Eq for FactoryError {}
+
+/// A lazy singleton that couldn't be constructed by [`Container::warm_up`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WarmUpFailure {
+    /// The singleton's type.
+    pub type_id: TypeId,
+    /// The name of the singleton's type, as reported by [`std::any::type_name`].
+    pub type_name: &'static str,
+    /// The message its factory panicked with.
+    pub message: String,
+}
+
+impl WarmUpFailure {
+    /// Describe the failure from the factory's panic payload.
+    fn new(type_id: TypeId, type_name: &'static str, panic: &(dyn Any + Send)) -> Self {
+        let message = panic
+            .downcast_ref::<&str>()
+            .map(ToString::to_string)
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "factory panicked".to_string());
+
+        Self {
+            type_id,
+            type_name,
+            message,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct TestDependency {
+        value: String,
+    }
+
+    #[test]
+    fn can_register_a_binding() {
+        let mut container = Container::new();
+
+        container.bind(|_: &Container| TestDependency {
+            value: "Hello, world!".to_string(),
+        });
+
+        assert_eq!(container.bindings.counts(), (1, 0));
+    }
+
+    #[test]
+    fn can_register_a_singleton() {
+        let mut container = Container::new();
+
+        container.singleton(&|_: &Container| TestDependency {
+            value: "Hello, world!".to_string(),
+        });
+
+        assert_eq!(container.bindings.counts(), (0, 1));
+    }
+
+    #[test]
+    fn can_register_a_scoped_binding() {
+        let mut container = Container::new();
+
+        container.scoped(&|_: &Container| TestDependency {
+            value: "Hello, world!".to_string(),
+        });
+
+        assert_eq!(container.bindings.counts(), (0, 1));
+        assert_eq!(container.bindings.scoped().len(), 1);
+    }
+
+    #[test]
+    fn registering_a_binding_clears_previous_singleton() {
+        let mut container = Container::new();
+
+        container.singleton(&|_: &Container| TestDependency {
+            value: "Hello, world!".to_string(),
+        });
+
+        container.bind(|_: &Container| TestDependency {
+            value: "Goodbye, world!".to_string(),
+        });
+
+        assert_eq!(container.bindings.counts(), (1, 0));
+
+        assert_eq!(
+            container.resolve::<TestDependency>(),
+            Ok(TestDependency {
+                value: "Goodbye, world!".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn can_opt_into_default_fallbacks() {
+        let mut container = Container::new();
+        container.singleton(&|_| 42_u64);
+        register_defaults!(container, u64, String, Vec<u8>);
+
+        assert_eq!(container.resolve::<u64>(), Ok(42));
+        assert_eq!(container.resolve::<String>(), Ok(String::new()));
+
+        container.bind(|_| vec![1_u8]);
+
+        assert_eq!(container.resolve::<Vec<u8>>(), Ok(vec![1]));
+    }
+
+    #[test]
+    fn can_retrieve_a_registered_binding() {
+        let mut container = Container::new();
+
+        container.bind(|_: &Container| TestDependency {
+            value: "Hello, world!".to_string(),
+        });
+
+        let result = container.resolve::<TestDependency>().unwrap();
+
+        assert_eq!(result.value, "Hello, world!");
+    }
+
+    #[test]
+    fn can_retrieve_a_registered_scoped_binding_until_flush() {
+        let mut container = Container::new();
+
+        container.scoped(&|_: &Container| TestDependency {
+            value: "Hello, world!".to_string(),
+        });
+
+        assert_eq!(
+            container.resolve::<TestDependency>(),
+            Ok(TestDependency {
+                value: "Hello, world!".to_string()
+            })
+        );
+
+        container.forget_scoped_instances();
+
+        assert_eq!(container.resolve::<TestDependency>(), Err(Error::NotFound));
+    }
+
+    #[test]
+    fn can_retrieve_a_registered_singleton() {
+        let mut container = Container::new();
+
+        container.singleton(&|_: &Container| TestDependency {
+            value: "Hello, world!".to_string(),
+        });
+
+        let result = container.resolve::<TestDependency>().unwrap();
+
+        assert_eq!(result.value, "Hello, world!");
+    }
+
+    #[test]
+    fn re_registering_after_resolving_replaces_the_cached_value() {
+        let mut container = Container::new();
+        container.bind(|_| 1_u32);
+
+        assert_eq!(container.resolve::<u32>(), Ok(1));
+        assert_eq!(container.resolve::<u32>(), Ok(1));
+
+        container.singleton(&|_| 2_u32);
+        assert_eq!(container.resolve::<u32>(), Ok(2));
+
+        let mut other = Container::new();
+        other.bind(|_| 3_u32);
+        assert_eq!(other.resolve::<u32>(), Ok(3));
+        assert_eq!(container.resolve::<u32>(), Ok(2));
+
+        container.flush();
+        assert_eq!(container.resolve::<u32>(), Err(Error::NotFound));
+    }
+
+    #[test]
+    fn can_register_a_pre_constructed_instance() {
+        let mut container = Container::new();
+
+        container.instance(TestDependency {
+            value: "Hello, world!".to_string(),
+        });
+
+        assert_eq!(container.bindings.counts().1, 1);
+        assert_eq!(
+            container.resolve::<TestDependency>(),
+            Ok(TestDependency {
+                value: "Hello, world!".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn can_resolve_trait_objects_by_interface() {
+        trait Mailer: Send + Sync {
+            fn send(&self) -> &'static str;
+        }
+
+        #[derive(Clone)]
+        struct SmtpMailer;
+        impl Mailer for SmtpMailer {
+            fn send(&self) -> &'static str {
+                "smtp"
+            }
+        }
+
+        struct LogMailer;
+        impl Mailer for LogMailer {
+            fn send(&self) -> &'static str {
+                "log"
+            }
+        }
+
+        let mut container = Container::new();
+        container.bind(|_| SmtpMailer);
+        crate::bind_trait!(container, dyn Mailer => SmtpMailer);
+
+        assert_eq!(
+            container.resolve::<Arc<dyn Mailer>>().unwrap().send(),
+            "smtp"
+        );
+
+        container.singleton(&|_| Arc::new(LogMailer) as Arc<dyn Mailer>);
+        assert_eq!(
+            container.resolve::<Arc<dyn Mailer>>().unwrap().send(),
+            "log"
+        );
+
+        container.bind(|_| Box::new(SmtpMailer) as Box<dyn Mailer>);
+        assert_eq!(
+            container.resolve::<Box<dyn Mailer>>().unwrap().send(),
+            "smtp"
+        );
+
+        container.bind_trait::<dyn Mailer, LogMailer>(|mailer| mailer);
+        assert!(matches!(
+            container.resolve::<Arc<dyn Mailer>>(),
+            Err(Error::Resolution { .. })
+        ));
+
+        container.bind(|_| LogMailer);
+        container.bind_boxed::<dyn Mailer, LogMailer>(|mailer| mailer);
+        assert_eq!(
+            container.resolve::<Box<dyn Mailer>>().unwrap().send(),
+            "log"
+        );
+        assert_eq!(
+            container.resolve::<Box<LogMailer>>().err(),
+            Some(Error::NotFound)
+        );
+    }
+
+    #[test]
+    fn aliases_resolve_their_target() {
+        #[derive(Debug, PartialEq)]
+        struct Greeting(TestDependency);
+
+        impl From<TestDependency> for Greeting {
+            fn from(dependency: TestDependency) -> Self {
+                Self(dependency)
+            }
+        }
+
+        let mut container = Container::new();
+        container.alias::<Greeting, TestDependency>();
+        container.singleton(&|_| TestDependency {
+            value: "Hello, world!".to_string(),
+        });
+
+        assert_eq!(
+            container.resolve::<Greeting>(),
+            Ok(Greeting(TestDependency {
+                value: "Hello, world!".to_string()
+            }))
+        );
+
+        container.flush();
+        container.alias::<Greeting, TestDependency>();
+        assert!(matches!(
+            container.resolve::<Greeting>(),
+            Err(Error::Resolution { .. })
+        ));
+    }
+
+    #[test]
+    fn forgetting_a_type_only_removes_its_registration() {
+        let mut container = Container::new();
+        container.bind(|_| 42_u32);
+        container.scoped(&|_| 7_u64);
+
+        assert_eq!(container.resolve::<u32>(), Ok(42));
+
+        container.forget::<u32>();
+        container.forget::<u64>();
+
+        assert_eq!(container.resolve::<u32>(), Err(Error::NotFound));
+        assert_eq!(container.resolve::<u64>(), Err(Error::NotFound));
+        assert!(container.bindings.scoped().is_empty());
+
+        container.bind(|_| "still here");
+        container.forget::<u32>();
+        assert_eq!(container.resolve::<&str>(), Ok("still here"));
+    }
+
+    #[test]
+    fn can_inspect_registrations() {
+        let mut container = Container::new();
+        container.bind(|_| 1_u8);
+        container.singleton(&|_| 2_u16);
+        container.scoped(&|_| 3_u32);
+
+        assert!(container.has::<u8>() && container.has::<u16>() && container.has::<u32>());
+        assert!(!container.has::<u64>());
+
+        assert!(!container.is_singleton::<u8>() && !container.is_scoped::<u8>());
+        assert!(container.is_singleton::<u16>() && !container.is_scoped::<u16>());
+        assert!(!container.is_singleton::<u32>() && container.is_scoped::<u32>());
+
+        container.forget_scoped_instances();
+        assert!(!container.has::<u32>() && !container.is_scoped::<u32>());
+    }
+
+    #[test]
+    fn falls_back_when_a_type_is_not_registered() {
+        let mut container = Container::new();
+
+        assert_eq!(container.resolve_or_else(|| 7_u32), Ok(7));
+        assert_eq!(container.resolve_or_default::<u64>(), Ok(0));
+
+        container.bind(|_| 42_u32);
+        container.bind(|container| TestDependency {
+            value: container.resolve_or_default().unwrap(),
+        });
+
+        assert_eq!(container.resolve_or_else(|| 7_u32), Ok(42));
+        assert_eq!(
+            container.resolve::<TestDependency>(),
+            Ok(TestDependency {
+                value: String::new()
+            })
+        );
+    }
+
+    #[test]
+    fn fallible_factories_propagate_their_errors() {
+        let mut container = Container::new();
+        container.try_bind(|_| "42".parse::<u32>());
+        container.try_bind(|_| "nope".parse::<u64>());
+
+        assert_eq!(container.resolve::<u32>(), Ok(42));
+
+        let Err(Error::Factory(error)) = container.resolve::<u64>() else {
+            panic!("expected a factory error");
+        };
+        assert_eq!(error.to_string(), "invalid digit found in string");
+
+        assert_eq!(container.try_singleton(&|_| "7".parse::<u8>()), Ok(()));
+        assert_eq!(container.resolve::<u8>(), Ok(7));
+        assert!(container.try_singleton(&|_| "".parse::<u16>()).is_err());
+        assert!(!container.has::<u16>());
+    }
+
+    #[test]
+    fn extensions_stack_in_registration_order() {
+        let mut container = Container::new();
+        container.bind(|_| "logger".to_string());
+        container.extend(|logger: String, _| format!("[timestamped] {logger}"));
+        container.extend(|logger: String, _| format!("[buffered] {logger}"));
+
+        assert_eq!(
+            container.resolve::<String>(),
+            Ok("[buffered] [timestamped] logger".to_string())
+        );
+
+        container.singleton(&|_| "shared".to_string());
+        assert_eq!(
+            container.resolve::<String>(),
+            Ok("[buffered] [timestamped] shared".to_string())
+        );
+    }
+
+    #[test]
+    fn rebinding_callbacks_receive_the_replacement() {
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let mut container = Container::new();
+        let recorded = Arc::clone(&seen);
+        container.rebinding(move |_, value: u32| recorded.lock().unwrap().push(value));
+
+        container.bind(|_| 1_u32);
+        container.singleton(&|_| 2_u32);
+        container.bind(|_| 3_u32);
+
+        assert_eq!(*seen.lock().unwrap(), vec![2, 3]);
+    }
+
+    #[test]
+    fn rebinding_callbacks_receive_refreshed_values() {
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let mut container = Container::new();
+        let recorded = Arc::clone(&seen);
+        container.rebinding(move |_, value: usize| recorded.lock().unwrap().push(value));
+
+        let counter = Arc::clone(&calls);
+        container.singleton_lazy(move |_: &Container| counter.fetch_add(1, Ordering::SeqCst));
+        assert_eq!(container.resolve::<usize>(), Ok(0));
+
+        container.refresh::<usize>().unwrap();
+
+        assert_eq!(*seen.lock().unwrap(), vec![1]);
+        assert_eq!(container.resolve::<usize>(), Ok(1));
+    }
+
+    #[test]
+    fn can_swap_a_shared_instance() {
+        let mut container = Container::new();
+        container.singleton(&|_| TestDependency {
+            value: "Hello, world!".to_string(),
+        });
+        container.bind(|_| 42_u32);
+
+        let previous = container.swap(TestDependency {
+            value: "Goodbye, world!".to_string(),
+        });
+
+        assert_eq!(
+            previous,
+            Some(TestDependency {
+                value: "Hello, world!".to_string()
+            })
+        );
+        assert_eq!(
+            container.resolve::<TestDependency>(),
+            Ok(TestDependency {
+                value: "Goodbye, world!".to_string()
+            })
+        );
+        assert_eq!(container.swap(7_u32), None);
+        assert_eq!(container.resolve::<u32>(), Ok(42));
+    }
+
+    #[test]
+    fn one_shot_bindings_resolve_only_once() {
+        let mut container = Container::new();
+        container.bind_once(|_| "bootstrap-token".to_string());
+        assert!(container.has::<String>());
+
+        assert_eq!(
+            container.resolve::<String>(),
+            Ok("bootstrap-token".to_string())
+        );
+        assert!(!container.has::<String>());
+        assert_eq!(container.resolve::<String>(), Err(Error::NotFound));
+
+        container.bind(|_| "rebound".to_string());
+        assert!(container.has::<String>());
+    }
+
+    #[test]
+    fn weak_singletons_are_rebuilt_once_dropped() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut container = Container::new();
+
+        let counter = Arc::clone(&calls);
+        container.singleton_weak(move |_: &Container| counter.fetch_add(1, Ordering::SeqCst));
 
-    /// Register a binding with the container.
-    pub fn bind<T: 'static>(&mut self, factory: impl Fn(&Self) -> T + 'static + Sync + Send) {
-        self.instances.remove(&TypeId::of::<T>());
+        let first = container.resolve::<Arc<usize>>().unwrap();
+        let second = container.resolve::<Arc<usize>>().unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
 
-        self.bindings.insert(
-            TypeId::of::<T>(),
-            Box::new(move |container: &Self| {
-                let result = factory(container);
+        drop((first, second));
 
-                Box::new(result) as Box<dyn Any>
-            }),
-        );
+        assert_eq!(container.resolve::<Arc<usize>>().map(|value| *value), Ok(1));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
     }
 
-    /// Register a binding if it hasn't already been registered.
-    pub fn bind_if<T: 'static>(&mut self, factory: impl Fn(&Self) -> T + 'static + Sync + Send) {
-        if !self.bindings.contains_key(&TypeId::of::<T>()) {
-            self.bind(factory);
-        }
-    }
+    #[test]
+    fn arc_singletons_do_not_need_to_be_cloneable() {
+        struct Connection;
 
-    /// Register a scoped binding in the container.
-    pub fn scoped<T: 'static + Clone + Send + Sync>(
-        &mut self,
-        factory: &(impl Fn(&Self) -> T + 'static),
-    ) {
-        self.scoped_instances.push(TypeId::of::<T>());
+        let mut container = Container::new();
+        container.singleton_arc(&|_| Connection);
 
-        self.singleton(factory);
-    }
+        let first = container.resolve::<Arc<Connection>>().unwrap();
+        let second = container.resolve::<Arc<Connection>>().unwrap();
 
-    /// Register a scoped binding if it hasn't already been registered.
-    pub fn scoped_if<T: 'static + Clone + Send + Sync>(
-        &mut self,
-        factory: &(impl Fn(&Self) -> T + 'static),
-    ) {
-        if !self.scoped_instances.contains(&TypeId::of::<T>()) {
-            self.scoped(factory);
-        }
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(container.is_singleton::<Arc<Connection>>());
     }
 
-    /// Register a shared binding in the container.
-    pub fn singleton<T: 'static + Clone + Send + Sync>(
-        &mut self,
-        factory: &(impl Fn(&Self) -> T + 'static),
-    ) {
-        let result = factory(self);
+    #[test]
+    fn can_borrow_a_shared_instance() {
+        let mut container = Container::new();
+        container.singleton(&|_| TestDependency {
+            value: "Hello, world!".to_string(),
+        });
+        container.bind(|_| 42_u32);
 
-        self.instances.insert(
-            TypeId::of::<T>(),
-            Box::new(move || Box::new(result.clone()) as Box<dyn Any + Send + Sync>),
+        assert_eq!(
+            container.resolve_ref::<TestDependency>().unwrap().value,
+            "Hello, world!"
         );
+        assert!(matches!(
+            container.resolve_ref::<u32>(),
+            Err(Error::NotFound)
+        ));
     }
 
-    /// Register a shared binding if it hasn't already been registered.
-    pub fn singleton_if<T: 'static + Clone + Send + Sync>(
-        &mut self,
-        factory: &(impl Fn(&Self) -> T + 'static),
-    ) {
-        if !self.instances.contains_key(&TypeId::of::<T>()) {
-            self.singleton(factory);
-        }
+    #[test]
+    fn can_mutate_a_shared_instance_in_place() {
+        let mut container = Container::new();
+        container.singleton(&|_| vec!["first"]);
+
+        let len = container.resolve_mut(|registry: &mut Vec<&str>| {
+            registry.push("second");
+            registry.len()
+        });
+
+        assert_eq!(len, Ok(2));
+        assert_eq!(
+            container.resolve::<Vec<&str>>(),
+            Ok(vec!["first", "second"])
+        );
+        assert_eq!(
+            container.resolve_mut(|_: &mut u32| ()),
+            Err(Error::NotFound)
+        );
     }
 
-    /// Resolve the given type from the container.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the requested type cannot be found or if the requested type cannot be cast from the binding.
-    pub fn resolve<T: 'static>(&self) -> Result<T, Error> {
-        let type_id = TypeId::of::<T>();
+    #[test]
+    fn optional_dependencies_resolve_to_none_when_missing() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Metrics;
 
-        if let Some(instance) = self.instances.get(&type_id) {
-            return instance()
-                .downcast::<T>()
-                .map(|i| *i)
-                .map_err(|_| Error::CastFailed);
-        };
+        let mut container = Container::new();
+        container.optional::<Metrics>();
+        container.bind(|container| container.resolve::<Option<Metrics>>().unwrap().is_some());
 
-        if let Some(binding) = self.bindings.get(&type_id) {
-            return binding(self)
-                .downcast::<T>()
-                .map(|b| *b)
-                .map_err(|_| Error::CastFailed);
-        };
+        assert_eq!(container.resolve::<Option<Metrics>>(), Ok(None));
+        assert_eq!(container.resolve::<bool>(), Ok(false));
+        assert_eq!(container.resolve_optional::<Metrics>(), Ok(None));
 
-        try_default_if_enabled().ok_or(Error::NotFound)
+        container.singleton(&|_| Metrics);
+
+        assert_eq!(container.resolve::<Option<Metrics>>(), Ok(Some(Metrics)));
+        assert_eq!(container.resolve::<bool>(), Ok(true));
     }
 
-    /// Clear all of the scoped instances from the container.
-    pub fn forget_scoped_instances(&mut self) {
-        for instance in &self.scoped_instances {
-            self.instances.remove(instance);
-        }
+    #[test]
+    fn singletons_can_be_resolved_as_arcs() {
+        let mut container = Container::new();
+        container.singleton(&|_| TestDependency {
+            value: "Hello, world!".to_string(),
+        });
+        container.singleton_lazy(|_| 42_u32);
+
+        let first = container.resolve::<Arc<TestDependency>>().unwrap();
+        let second = container.resolve::<Arc<TestDependency>>().unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(first.value, "Hello, world!");
+        assert_eq!(container.resolve::<Arc<u32>>().map(|value| *value), Ok(42));
+
+        container.swap(TestDependency {
+            value: "Goodbye, world!".to_string(),
+        });
+        assert_eq!(first.value, "Hello, world!");
+        assert_eq!(
+            container.resolve::<Arc<TestDependency>>().unwrap().value,
+            "Goodbye, world!"
+        );
+
+        container.bind(|_| 7_u32);
+        assert_eq!(container.resolve::<Arc<u32>>().err(), Some(Error::NotFound));
+
+        container.bind(|_| {
+            Arc::new(TestDependency {
+                value: "Explicit".to_string(),
+            })
+        });
+        assert_eq!(
+            container.resolve::<Arc<TestDependency>>().unwrap().value,
+            "Explicit"
+        );
     }
 
-    /// Flush the container of all bindings and resolved instances.
-    pub fn flush(&mut self) {
-        self.bindings.clear();
-        self.instances.clear();
-        self.scoped_instances.clear();
+    #[test]
+    fn can_take_a_shared_instance_out_of_the_container() {
+        let mut container = Container::new();
+        container.singleton(&|_| TestDependency {
+            value: "Hello, world!".to_string(),
+        });
+        container.bind(|_| 42_u32);
+
+        assert_eq!(
+            container
+                .take::<TestDependency>()
+                .map(|dependency| dependency.value),
+            Some("Hello, world!".to_string())
+        );
+        assert_eq!(container.take::<TestDependency>(), None);
+        assert_eq!(container.take::<u32>(), None);
+        assert_eq!(container.resolve::<TestDependency>(), Err(Error::NotFound));
+        assert_eq!(
+            container.resolve::<Arc<TestDependency>>().err(),
+            Some(Error::NotFound)
+        );
     }
-}
 
-impl Default for Container {
-    fn default() -> Self {
-        Self::new()
+    #[test]
+    fn can_register_a_binding_with_a_runtime_lifetime() {
+        let mut container = Container::new();
+
+        container.register(Lifetime::Transient, |_| 1_u8);
+        container.register(Lifetime::Singleton, |_| 2_u16);
+        container.register(Lifetime::Scoped, |_| 3_u32);
+
+        assert!(container.has::<u8>() && !container.is_singleton::<u8>());
+        assert!(container.is_singleton::<u16>());
+        assert!(container.is_scoped::<u32>());
+        assert_eq!(container.resolve::<u32>(), Ok(3));
     }
-}
 
-#[cfg(not(feature = "nightly"))]
-const fn try_default_if_enabled<T>() -> Option<T> {
-    None
-}
+    #[test]
+    fn lazy_singletons_are_constructed_on_first_resolve() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut container = Container::new();
 
-#[cfg(feature = "nightly")]
-fn try_default_if_enabled<T>() -> Option<T> {
-    T::try_default()
-}
+        let counter = Arc::clone(&calls);
+        container.singleton_lazy(move |_: &Container| {
+            counter.fetch_add(1, Ordering::SeqCst);
 
-/// An error that can occur when interacting with the container.
-#[derive(Debug, PartialEq, Eq, thiserror::Error)]
-pub enum Error {
-    /// Binding not found.
-    #[error("Binding not found")]
-    NotFound,
+            TestDependency {
+                value: "Hello, world!".to_string(),
+            }
+        });
 
-    /// Failed to cast binding to requested type.
-    #[error("Failed to cast binding to requested type")]
-    CastFailed,
-}
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use serial_test::serial;
+        container.resolve::<TestDependency>().unwrap();
+        container.resolve::<TestDependency>().unwrap();
 
-    #[derive(Debug, Clone, PartialEq)]
-    struct TestDependency {
-        value: String,
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
     }
 
     #[test]
-    fn can_register_a_binding() {
+    fn refreshing_a_lazy_singleton_reruns_its_factory() {
+        let calls = Arc::new(AtomicUsize::new(0));
         let mut container = Container::new();
 
-        container.bind(|_: &Container| TestDependency {
-            value: "Hello, world!".to_string(),
-        });
+        let counter = Arc::clone(&calls);
+        container.singleton_lazy(move |_: &Container| counter.fetch_add(1, Ordering::SeqCst));
+
+        assert_eq!(container.resolve::<usize>(), Ok(0));
+        assert_eq!(container.resolve::<usize>(), Ok(0));
+
+        container.refresh::<usize>().unwrap();
 
-        assert_eq!(container.bindings.len(), 1);
-        assert_eq!(container.instances.len(), 0);
+        assert_eq!(container.resolve::<usize>(), Ok(1));
+        assert_eq!(container.refresh::<u32>(), Err(Error::NotFound));
+
+        container.singleton(&|_| 42_u32);
+        assert_eq!(container.refresh::<u32>(), Err(Error::NotFound));
     }
 
     #[test]
-    fn can_register_a_singleton() {
+    fn refreshing_a_singleton_reruns_its_factory() {
+        let calls = Arc::new(AtomicUsize::new(0));
         let mut container = Container::new();
 
-        container.singleton(&|_: &Container| TestDependency {
-            value: "Hello, world!".to_string(),
-        });
+        let counter = Arc::clone(&calls);
+        container.singleton_refreshable(move |_| counter.fetch_add(1, Ordering::SeqCst));
+        assert_eq!(container.resolve::<usize>(), Ok(0));
+
+        container.refresh::<usize>().unwrap();
 
-        assert_eq!(container.bindings.len(), 0);
-        assert_eq!(container.instances.len(), 1);
+        assert_eq!(container.resolve::<usize>(), Ok(1));
+        assert_eq!(
+            container.resolve::<std::sync::Arc<usize>>(),
+            Ok(Arc::new(1))
+        );
     }
 
     #[test]
-    fn can_register_a_scoped_binding() {
+    fn warm_up_constructs_lazy_singletons() {
+        let calls = Arc::new(AtomicUsize::new(0));
         let mut container = Container::new();
 
-        container.scoped(&|_: &Container| TestDependency {
-            value: "Hello, world!".to_string(),
+        let counter = Arc::clone(&calls);
+        container.singleton_lazy(move |_: &Container| counter.fetch_add(1, Ordering::SeqCst));
+
+        assert!(container.warm_up().is_empty());
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(container.resolve::<usize>(), Ok(0));
+    }
+
+    #[test]
+    fn warm_up_reports_failing_singletons() {
+        let mut container = Container::new();
+        container.singleton_lazy(|_| 42_u32);
+        container.singleton_lazy(|container| -> u64 {
+            container.resolve::<u16>().expect("missing u16").into()
         });
 
-        assert_eq!(container.bindings.len(), 0);
-        assert_eq!(container.instances.len(), 1);
-        assert_eq!(container.scoped_instances.len(), 1);
+        assert_eq!(
+            container.warm_up(),
+            vec![WarmUpFailure {
+                type_id: TypeId::of::<u64>(),
+                type_name: "u64",
+                message: "missing u16: NotFound".to_string(),
+            }]
+        );
+
+        container.bind(|_| 7_u16);
+        assert!(container.warm_up().is_empty());
+        assert_eq!(container.resolve::<u64>(), Ok(7));
     }
 
     #[test]
-    fn registering_a_binding_clears_previous_singleton() {
+    fn parallel_warm_up_constructs_each_singleton_once() {
+        let calls = Arc::new(AtomicUsize::new(0));
         let mut container = Container::new();
 
-        container.singleton(&|_: &Container| TestDependency {
-            value: "Hello, world!".to_string(),
-        });
+        let counter = Arc::clone(&calls);
+        container.singleton_lazy(move |_: &Container| {
+            counter.fetch_add(1, Ordering::SeqCst);
 
-        container.bind(|_: &Container| TestDependency {
-            value: "Goodbye, world!".to_string(),
+            42_u32
+        });
+        container.singleton_lazy(|container| u64::from(container.resolve::<u32>().unwrap()));
+        container.singleton_lazy(|container| i64::from(container.resolve::<u32>().unwrap()));
+        container.singleton_lazy(|container| -> u16 {
+            container.resolve::<u8>().expect("missing u8").into()
         });
-
-        assert_eq!(container.bindings.len(), 1);
-        assert_eq!(container.instances.len(), 0);
 
         assert_eq!(
-            container.resolve::<TestDependency>(),
-            Ok(TestDependency {
-                value: "Goodbye, world!".to_string()
-            })
+            container.warm_up_parallel(),
+            vec![WarmUpFailure {
+                type_id: TypeId::of::<u16>(),
+                type_name: "u16",
+                message: "missing u8: NotFound".to_string(),
+            }]
         );
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(container.resolve::<u64>(), Ok(42));
+        assert_eq!(container.resolve::<i64>(), Ok(42));
     }
 
     #[test]
-    fn can_retrieve_a_registered_binding() {
+    fn returns_singleton_over_binding() {
         let mut container = Container::new();
 
         container.bind(|_: &Container| TestDependency {
             value: "Hello, world!".to_string(),
         });
 
+        container.singleton(&|_: &Container| TestDependency {
+            value: "Goodbye, world!".to_string(),
+        });
+
         let result = container.resolve::<TestDependency>().unwrap();
 
-        assert_eq!(result.value, "Hello, world!");
+        assert_eq!(result.value, "Goodbye, world!");
     }
 
     #[test]
-    fn can_retrieve_a_registered_scoped_binding_until_flush() {
+    fn can_register_a_batch_of_bindings() {
         let mut container = Container::new();
 
-        container.scoped(&|_: &Container| TestDependency {
+        container
+            .register_batch(|tx| {
+                tx.bind(|_: &Container| 42_u32);
+                tx.singleton(&|_: &Container| TestDependency {
+                    value: "Hello, world!".to_string(),
+                });
+
+                Ok::<_, Error>(())
+            })
+            .unwrap();
+
+        assert_eq!(container.resolve::<u32>(), Ok(42));
+        assert_eq!(container.bindings.counts().1, 1);
+    }
+
+    #[test]
+    fn failed_batch_rolls_back_registrations() {
+        let mut container = Container::new();
+
+        container.bind(|_: &Container| TestDependency {
             value: "Hello, world!".to_string(),
         });
 
+        let result = container.register_batch(|tx| {
+            tx.bind(|_: &Container| 42_u32);
+            tx.bind(|_: &Container| TestDependency {
+                value: "Goodbye, world!".to_string(),
+            });
+
+            Err(Error::NotFound)
+        });
+
+        assert_eq!(result, Err(Error::NotFound));
+        assert_eq!(container.resolve::<u32>(), Err(Error::NotFound));
         assert_eq!(
             container.resolve::<TestDependency>(),
             Ok(TestDependency {
                 value: "Hello, world!".to_string()
             })
         );
+    }
 
-        container.forget_scoped_instances();
+    #[test]
+    fn failed_batch_forgets_the_instances_it_constructed() {
+        let mut container = Container::new();
+        container.singleton(&|_| 1_u8);
+        container.singleton(&|_| 1_u16);
 
-        assert_eq!(container.resolve::<TestDependency>(), Err(Error::NotFound));
+        let result = container.register_batch(|tx| {
+            tx.singleton(&|_| 2_u8);
+            tx.singleton(&|_| 2_u32);
+
+            Err(Error::NotFound)
+        });
+
+        assert_eq!(result, Err(Error::NotFound));
+        assert_eq!(
+            container.constructed_order(),
+            vec![TypeId::of::<u8>(), TypeId::of::<u16>()]
+        );
     }
 
     #[test]
-    fn can_retrieve_a_registered_singleton() {
+    fn limited_bindings_cap_concurrent_factory_calls() {
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
         let mut container = Container::new();
 
-        container.singleton(&|_: &Container| TestDependency {
-            value: "Hello, world!".to_string(),
+        let (current, max) = (Arc::clone(&running), Arc::clone(&peak));
+        container.bind_limited(NonZeroUsize::new(2).unwrap(), move |_: &Container| {
+            let now = current.fetch_add(1, Ordering::SeqCst) + 1;
+            max.fetch_max(now, Ordering::SeqCst);
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            current.fetch_sub(1, Ordering::SeqCst);
+
+            TestDependency {
+                value: "Hello, world!".to_string(),
+            }
         });
 
-        let result = container.resolve::<TestDependency>().unwrap();
+        std::thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| container.resolve::<TestDependency>().unwrap());
+            }
+        });
 
-        assert_eq!(result.value, "Hello, world!");
+        assert!(peak.load(Ordering::SeqCst) <= 2);
     }
 
     #[test]
-    fn returns_singleton_over_binding() {
+    fn can_resolve_an_in_place_binding_into_caller_storage() {
+        struct LargeDependency {
+            buffer: [u8; 4096],
+        }
+
+        let mut container = Container::new();
+
+        container.bind_in_place(|_, slot: &mut MaybeUninit<LargeDependency>| {
+            slot.write(LargeDependency { buffer: [7; 4096] })
+        });
+
+        let mut slot = MaybeUninit::uninit();
+        let result = container
+            .resolve_into::<LargeDependency>(&mut slot)
+            .unwrap();
+
+        assert!(result.buffer.iter().all(|&byte| byte == 7));
+    }
+
+    #[test]
+    fn in_place_bindings_replace_earlier_registrations() {
+        let closed = Arc::new(AtomicUsize::new(0));
+        let rebound = Arc::new(Mutex::new(Vec::new()));
+        let mut container = Container::new();
+
+        let counter = Arc::clone(&closed);
+        container.singleton_with_drop(&|_| 1_u32, move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        let seen = Arc::clone(&rebound);
+        container.rebinding(move |_, value: u32| seen.lock().unwrap().push(value));
+        container.bind_in_place(|_, slot: &mut MaybeUninit<u32>| slot.write(2));
+
+        assert_eq!(closed.load(Ordering::SeqCst), 1);
+        assert!(rebound.lock().unwrap().is_empty());
+        assert_eq!(
+            container.resolve_into(&mut MaybeUninit::uninit()),
+            Ok(&mut 2_u32)
+        );
+
+        container.thread_scoped(|_| 3_u64);
+        assert_eq!(container.resolve::<u64>(), Ok(3));
+        container.bind_in_place(|_, slot: &mut MaybeUninit<u64>| slot.write(4));
+
+        assert!(!container.thread_scoped.contains_key(&TypeId::of::<u64>()));
+        assert_eq!(container.resolve::<u64>(), Err(Error::NotFound));
+        assert_eq!(
+            container.resolve_into(&mut MaybeUninit::uninit()),
+            Ok(&mut 4_u64)
+        );
+    }
+
+    #[test]
+    fn resolve_into_falls_back_to_regular_bindings() {
         let mut container = Container::new();
 
         container.bind(|_: &Container| TestDependency {
             value: "Hello, world!".to_string(),
         });
 
-        container.singleton(&|_: &Container| TestDependency {
-            value: "Goodbye, world!".to_string(),
-        });
+        let mut slot = MaybeUninit::uninit();
 
-        let result = container.resolve::<TestDependency>().unwrap();
+        assert_eq!(
+            container
+                .resolve_into::<TestDependency>(&mut slot)
+                .unwrap()
+                .value,
+            "Hello, world!"
+        );
+    }
 
-        assert_eq!(result.value, "Goodbye, world!");
+    #[test]
+    fn nested_failures_report_the_resolution_chain() {
+        struct Connection;
+        struct Reports;
+        struct Handler;
+
+        let mut container = Container::new();
+        container.try_bind(|c| c.resolve::<Connection>().map(|_| Reports));
+        container.try_bind(|c| c.resolve::<Reports>().map(|_| Handler));
+
+        let Err(Error::Resolution { chain, source }) = container.resolve::<Handler>() else {
+            panic!("expected a resolution error");
+        };
+
+        assert_eq!(
+            chain,
+            vec![
+                type_name::<Connection>(),
+                type_name::<Reports>(),
+                type_name::<Handler>()
+            ]
+        );
+        assert_eq!(*source, Error::NotFound);
+        assert!(matches!(
+            container.resolve::<Connection>(),
+            Err(Error::NotFound)
+        ));
     }
 
     #[test]
@@ -0,0 +1,58 @@
+use std::{
+    cell::{Ref, RefCell, RefMut},
+    time::Instant,
+};
+
+/// A lock for a value that's only used from one thread, which hands out [`RefCell`] borrows instead of synchronizing.
+///
+/// Its methods mirror `parking_lot`'s `RwLock`, so the facade can use either.
+pub struct LocalLock<T>(RefCell<T>);
+
+impl<T> LocalLock<T> {
+    /// Wrap the given value.
+    pub const fn new(value: T) -> Self {
+        Self(RefCell::new(value))
+    }
+
+    /// Borrow the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is being modified.
+    pub fn read(&self) -> Ref<'_, T> {
+        self.0.borrow()
+    }
+
+    /// Borrow the value mutably.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is borrowed.
+    pub fn write(&self) -> RefMut<'_, T> {
+        self.0.borrow_mut()
+    }
+
+    /// Borrow the value, unless it's being modified.
+    pub fn try_read(&self) -> Option<Ref<'_, T>> {
+        self.0.try_borrow().ok()
+    }
+
+    /// Borrow the value mutably, unless it's borrowed.
+    pub fn try_write(&self) -> Option<RefMut<'_, T>> {
+        self.0.try_borrow_mut().ok()
+    }
+
+    /// Borrow the value, unless it's being modified.
+    ///
+    /// Nothing else could release the value while waiting, so this never waits for the deadline.
+    pub fn try_read_until(&self, _deadline: Instant) -> Option<Ref<'_, T>> {
+        self.0.try_borrow().ok()
+    }
+
+    /// Borrow the value mutably, unless it's borrowed.
+    ///
+    /// Nothing else could release the value while waiting, so this never waits for the deadline.
+    pub fn try_write_until(&self, _deadline: Instant) -> Option<RefMut<'_, T>> {
+        self.0.try_borrow_mut().ok()
+    }
+}
@@ -0,0 +1,130 @@
+/// Build a [`Container`](crate::Container) from a list of registrations.
+///
+/// Each registration names a lifetime (`bind`, `singleton` or `scoped`), the registered type, and its factory.
+/// Trait objects can't be stored directly, so register them behind a pointer (like `Box<dyn Mailer>` or `Arc<dyn Mailer>`).
+///
+/// ```rust
+/// use std::sync::Arc;
+///
+/// trait Mailer: Send + Sync {}
+/// struct SmtpMailer;
+/// impl Mailer for SmtpMailer {}
+///
+/// #[derive(Clone)]
+/// struct DbPool;
+///
+/// let container = silhouette::container! {
+///     singleton DbPool = |_| DbPool;
+///     bind Arc<dyn Mailer> = |_| Arc::new(SmtpMailer) as Arc<dyn Mailer>;
+/// };
+///
+/// assert!(container.resolve::<Arc<dyn Mailer>>().is_ok());
+/// ```
+///
+/// Registering the same type twice is caught at compile time, instead of the second registration silently replacing the first:
+///
+/// ```rust,compile_fail
+/// #[derive(Clone)]
+/// struct DbPool;
+///
+/// let container = silhouette::container! {
+///     singleton DbPool = |_| DbPool;
+///     bind DbPool = |_| DbPool;
+/// };
+/// ```
+#[macro_export]
+macro_rules! container {
+    ($($lifetime:ident $type:ty = $factory:expr;)*) => {{
+        #[allow(dead_code)]
+        struct Registrations;
+        #[allow(dead_code)]
+        trait Registered<T: ?Sized> {}
+        $(impl Registered<$type> for Registrations {})*
+
+        #[allow(unused_mut)]
+        let mut container = $crate::Container::new();
+        $($crate::container!(@register container, $lifetime, $type, $factory);)*
+        container
+    }};
+    (@register $container:ident, bind, $type:ty, $factory:expr) => {
+        $container.bind::<$type>($factory)
+    };
+    (@register $container:ident, singleton, $type:ty, $factory:expr) => {
+        $container.singleton::<$type>(&$factory)
+    };
+    (@register $container:ident, scoped, $type:ty, $factory:expr) => {
+        $container.scoped::<$type>(&$factory)
+    };
+}
+
+/// Resolve each of the given types with its [`Default`] implementation, unless it's already registered.
+///
+/// This calls [`Container::enable_default_for`](crate::Container::enable_default_for) for every type, and is the stable, opt-in alternative to the `nightly` feature.
+///
+/// ```rust
+/// #[derive(Default, Clone)]
+/// struct Config {
+///     verbose: bool,
+/// }
+///
+/// let mut container = silhouette::Container::new();
+/// silhouette::register_defaults!(container, u64, String, Config);
+///
+/// assert_eq!(container.resolve::<u64>(), Ok(0));
+/// ```
+#[macro_export]
+macro_rules! register_defaults {
+    ($container:expr, $($type:ty),+ $(,)?) => {{
+        $($container.enable_default_for::<$type>();)+
+    }};
+}
+
+/// Bind a trait object to an implementation, so `Arc<dyn Trait>` resolves to the implementation resolved from the container.
+///
+/// This calls [`Container::bind_trait`](crate::Container::bind_trait) with the identity conversion from `Arc<Implementation>` to `Arc<dyn Trait>`.
+///
+/// ```rust
+/// use std::sync::Arc;
+///
+/// trait Mailer: Send + Sync {}
+/// struct SmtpMailer;
+/// impl Mailer for SmtpMailer {}
+///
+/// let mut container = silhouette::Container::new();
+/// container.bind(|_| SmtpMailer);
+/// silhouette::bind_trait!(container, dyn Mailer => SmtpMailer);
+///
+/// assert!(container.resolve::<Arc<dyn Mailer>>().is_ok());
+/// ```
+#[macro_export]
+macro_rules! bind_trait {
+    ($container:expr, $interface:ty => $implementation:ty) => {
+        $container.bind_trait::<$interface, $implementation>(|implementation| implementation)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[derive(Debug, Clone, PartialEq)]
+    struct TestDependency {
+        value: String,
+    }
+
+    #[test]
+    fn can_build_a_container_from_registrations() {
+        let container = crate::container! {
+            singleton TestDependency = |_| TestDependency {
+                value: "Hello, world!".to_string(),
+            };
+            bind u32 = |_| 42;
+            scoped u64 = |_| 7;
+        };
+
+        assert_eq!(container.resolve::<u32>(), Ok(42));
+        assert_eq!(container.resolve::<u64>(), Ok(7));
+        assert_eq!(
+            container.resolve::<TestDependency>().unwrap().value,
+            "Hello, world!"
+        );
+    }
+}
@@ -0,0 +1,459 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+    str::FromStr,
+    sync::{Arc, Mutex, OnceLock, PoisonError},
+};
+
+use crate::{Container, Lifetime};
+
+/// Registers a constructor with the container under the given service name, using the given lifetime.
+type Constructor = Arc<dyn Fn(&mut Container, &'static str, Lifetime) + Send + Sync>;
+
+/// A set of named constructors that a [`Manifest`] can refer to.
+///
+/// Constructors are usually declared with the [`constructors!`](crate::constructors) macro.
+#[derive(Default, Clone)]
+pub struct Constructors {
+    /// The constructors, by name.
+    constructors: HashMap<String, Constructor>,
+}
+
+impl Constructors {
+    /// Create an empty set of constructors.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a named constructor.
+    ///
+    /// Services built by the constructor are registered both for their type and under their own name, so several services can share a type and still be resolved individually with [`Container::resolve_named`].
+    pub fn add<T: 'static + Clone + Send + Sync>(
+        &mut self,
+        name: impl Into<String>,
+        factory: impl Fn(&Container) -> T + 'static + Send + Sync,
+    ) {
+        let factory = Arc::new(factory);
+
+        self.constructors.insert(
+            name.into(),
+            Arc::new(move |container: &mut Container, service, lifetime| {
+                let factory = Arc::clone(&factory);
+
+                match lifetime {
+                    Lifetime::Transient => {
+                        let named = Arc::clone(&factory);
+                        container.bind_named(service, move |c| named(c));
+                        container.bind(move |c| factory(c));
+                    }
+                    Lifetime::Singleton => {
+                        let named = Arc::clone(&factory);
+                        let instance = OnceLock::new();
+                        container.bind_named(service, move |c| {
+                            instance.get_or_init(|| named(c)).clone()
+                        });
+                        container.singleton_lazy(move |c| factory(c));
+                    }
+                    // Named bindings outlive the scope, so the named value is the one constructed for the scope the manifest was applied in.
+                    Lifetime::Scoped => {
+                        let instance = factory(container);
+                        container.bind_named(service, move |_| instance.clone());
+                        container.scoped(&move |c: &Container| factory(c));
+                    }
+                }
+            }),
+        );
+    }
+}
+
+/// Declare a set of named [`Constructors`] for use with a [`Manifest`].
+///
+/// ```rust
+/// use silhouette::{constructors, Container};
+///
+/// let constructors = constructors! {
+///     "smtp" => |_: &Container| "smtp://localhost",
+///     "log" => |_: &Container| "log://stderr",
+/// };
+/// ```
+#[macro_export]
+macro_rules! constructors {
+    ($($name:expr => $factory:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut constructors = $crate::manifest::Constructors::new();
+        $(constructors.add($name, $factory);)*
+        constructors
+    }};
+}
+
+/// A single service declared in a [`Manifest`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Service {
+    /// The service's name, which it can be resolved by with [`Container::resolve_named`].
+    pub name: String,
+    /// The name of the constructor building the service.
+    pub constructor: String,
+    /// The service's lifetime.
+    pub lifetime: Lifetime,
+}
+
+/// A declarative description of which constructor (and lifetime) each service should use.
+///
+/// Manifests are written in a small subset of TOML, with a table per service, named after the service:
+///
+/// ```toml
+/// [mailer]
+/// constructor = "smtp"
+/// lifetime = "singleton" # or "transient" (the default), or "scoped"
+/// ```
+///
+/// Only bare table headers and `key = "value"` pairs with basic strings are supported, one per line. Strings can contain the `\\`, `\"`, `\n` and `\t` escapes, and `#` starts a comment outside of strings. Every other TOML construct, such as literal or multi-line strings, dotted keys, arrays and inline tables, is rejected.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Manifest {
+    /// The declared services, in declaration order.
+    pub services: Vec<Service>,
+}
+
+impl Manifest {
+    /// Read and parse a manifest file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or isn't a valid manifest.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        fs::read_to_string(path)
+            .map_err(|e| Error::Io(e.to_string()))?
+            .parse()
+    }
+
+    /// Register every declared service with the container.
+    ///
+    /// Services are registered atomically: if any of them refers to an unknown constructor, none of them are registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a service refers to a constructor that wasn't declared.
+    pub fn apply(
+        &self,
+        container: &mut Container,
+        constructors: &Constructors,
+    ) -> Result<(), Error> {
+        container.register_batch(|container| {
+            for service in &self.services {
+                let constructor = constructors
+                    .constructors
+                    .get(&service.constructor)
+                    .ok_or_else(|| Error::UnknownConstructor(service.constructor.clone()))?;
+
+                constructor(container, intern(&service.name), service.lifetime);
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl FromStr for Manifest {
+    type Err = Error;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let mut services: Vec<(String, Option<String>, Lifetime)> = Vec::new();
+
+        for (index, line) in source.lines().enumerate() {
+            let line_number = index + 1;
+            let line = strip_comment(line).trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let name = name.trim();
+                if !is_bare_key(name) {
+                    return Err(Error::Parse(
+                        line_number,
+                        format!("invalid service name `{name}`"),
+                    ));
+                }
+
+                services.push((name.to_string(), None, Lifetime::Transient));
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| Error::Parse(line_number, "expected `key = \"value\"`".into()))?;
+            let value = parse_string(value.trim())
+                .ok_or_else(|| Error::Parse(line_number, "expected a quoted string".into()))?;
+            let service = services
+                .last_mut()
+                .ok_or_else(|| Error::Parse(line_number, "expected a `[service]` header".into()))?;
+
+            match key.trim() {
+                "constructor" => service.1 = Some(value),
+                "lifetime" => service.2 = value.parse()?,
+                key => return Err(Error::Parse(line_number, format!("unknown key `{key}`"))),
+            }
+        }
+
+        let services = services
+            .into_iter()
+            .map(|(name, constructor, lifetime)| {
+                Ok(Service {
+                    constructor: constructor
+                        .ok_or_else(|| Error::MissingConstructor(name.clone()))?,
+                    name,
+                    lifetime,
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(Self { services })
+    }
+}
+
+impl FromStr for Lifetime {
+    type Err = Error;
+
+    fn from_str(lifetime: &str) -> Result<Self, Self::Err> {
+        match lifetime {
+            "transient" => Ok(Self::Transient),
+            "singleton" => Ok(Self::Singleton),
+            "scoped" => Ok(Self::Scoped),
+            lifetime => Err(Error::UnknownLifetime(lifetime.to_string())),
+        }
+    }
+}
+
+/// Strip a trailing `#` comment, ignoring `#` characters inside quoted strings.
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+    let mut escaped = false;
+
+    for (index, character) in line.char_indices() {
+        match character {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..index],
+            _ => {}
+        }
+    }
+
+    line
+}
+
+/// Parse a basic string, resolving its escapes, or return `None` if it isn't one.
+fn parse_string(value: &str) -> Option<String> {
+    let mut characters = value.strip_prefix('"')?.chars();
+    let mut string = String::new();
+
+    loop {
+        match characters.next()? {
+            '"' => return characters.next().is_none().then_some(string),
+            '\\' => string.push(match characters.next()? {
+                '\\' => '\\',
+                '"' => '"',
+                'n' => '\n',
+                't' => '\t',
+                _ => return None,
+            }),
+            character => string.push(character),
+        }
+    }
+}
+
+/// Whether the given service name is a bare TOML key.
+fn is_bare_key(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric() || matches!(character, '_' | '-'))
+}
+
+/// Get a `'static` copy of the given service name, allocating it only the first time it's seen, since named bindings are keyed by `'static` names.
+fn intern(name: &str) -> &'static str {
+    static NAMES: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+
+    let mut names = NAMES
+        .get_or_init(Mutex::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    if let Some(name) = names.get(name) {
+        return name;
+    }
+
+    let name: &'static str = Box::leak(name.into());
+    names.insert(name);
+
+    name
+}
+
+/// An error that can occur when loading a manifest.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The manifest file couldn't be read.
+    #[error("Failed to read manifest: {0}")]
+    Io(String),
+
+    /// The manifest isn't valid.
+    #[error("Invalid manifest on line {0}: {1}")]
+    Parse(usize, String),
+
+    /// A service doesn't declare its constructor.
+    #[error("Service `{0}` doesn't declare a constructor")]
+    MissingConstructor(String),
+
+    /// A service refers to a constructor that wasn't declared.
+    #[error("Unknown constructor `{0}`")]
+    UnknownConstructor(String),
+
+    /// A service uses an unknown lifetime.
+    #[error("Unknown lifetime `{0}`")]
+    UnknownLifetime(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    trait Mailer: Send + Sync {
+        fn driver(&self) -> &'static str;
+    }
+
+    struct SmtpMailer;
+    struct LogMailer;
+
+    impl Mailer for SmtpMailer {
+        fn driver(&self) -> &'static str {
+            "smtp"
+        }
+    }
+
+    impl Mailer for LogMailer {
+        fn driver(&self) -> &'static str {
+            "log"
+        }
+    }
+
+    fn constructors() -> Constructors {
+        crate::constructors! {
+            "smtp" => |_: &Container| Arc::new(SmtpMailer) as Arc<dyn Mailer>,
+            "log" => |_: &Container| Arc::new(LogMailer) as Arc<dyn Mailer>,
+        }
+    }
+
+    #[test]
+    fn can_rewire_services_from_a_manifest() {
+        let manifest: Manifest = r#"
+            # use the log mailer locally
+            [mailer]
+            constructor = "log"
+            lifetime = "singleton"
+        "#
+        .parse()
+        .unwrap();
+
+        let mut container = Container::new();
+        manifest.apply(&mut container, &constructors()).unwrap();
+
+        assert_eq!(
+            container.resolve::<Arc<dyn Mailer>>().unwrap().driver(),
+            "log"
+        );
+    }
+
+    #[test]
+    fn unknown_constructors_leave_the_container_untouched() {
+        let manifest: Manifest = "[mailer]\nconstructor = \"log\"\n[queue]\nconstructor = \"sqs\""
+            .parse()
+            .unwrap();
+
+        let mut container = Container::new();
+
+        assert_eq!(
+            manifest.apply(&mut container, &constructors()),
+            Err(Error::UnknownConstructor("sqs".to_string()))
+        );
+        assert!(container.resolve::<Arc<dyn Mailer>>().is_err());
+    }
+
+    #[test]
+    fn registers_services_under_their_names() {
+        let manifest: Manifest = r#"
+            [mailer]
+            constructor = "smtp"
+            lifetime = "singleton"
+
+            [fallback-mailer]
+            constructor = "log"
+        "#
+        .parse()
+        .unwrap();
+
+        let mut container = Container::new();
+        manifest.apply(&mut container, &constructors()).unwrap();
+
+        let mailer = |name| {
+            container
+                .resolve_named::<Arc<dyn Mailer>>(name)
+                .map(|mailer| mailer.driver())
+        };
+        assert_eq!(mailer("mailer"), Ok("smtp"));
+        assert_eq!(mailer("fallback-mailer"), Ok("log"));
+        assert!(Arc::ptr_eq(
+            &container
+                .resolve_named::<Arc<dyn Mailer>>("mailer")
+                .unwrap(),
+            &container
+                .resolve_named::<Arc<dyn Mailer>>("mailer")
+                .unwrap()
+        ));
+    }
+
+    #[test]
+    fn resolves_escapes_in_strings() {
+        let manifest: Manifest = r#"
+            [mailer]
+            constructor = "say \"hi\" # not a comment" # a comment
+        "#
+        .parse()
+        .unwrap();
+
+        assert_eq!(
+            manifest.services[0].constructor,
+            r#"say "hi" # not a comment"#
+        );
+    }
+
+    #[test]
+    fn reports_invalid_manifests() {
+        assert_eq!(
+            "constructor = \"log\"".parse::<Manifest>(),
+            Err(Error::Parse(1, "expected a `[service]` header".to_string()))
+        );
+        assert_eq!(
+            "[mailer]\nconstructor = \"log\"\nlifetime = \"forever\"".parse::<Manifest>(),
+            Err(Error::UnknownLifetime("forever".to_string()))
+        );
+        assert_eq!(
+            "[mailer]\nconstructor = 'log'".parse::<Manifest>(),
+            Err(Error::Parse(2, "expected a quoted string".to_string()))
+        );
+        assert_eq!(
+            "[mailer]\nconstructor = \"\\u0041\"".parse::<Manifest>(),
+            Err(Error::Parse(2, "expected a quoted string".to_string()))
+        );
+        assert_eq!(
+            "[mail.er]".parse::<Manifest>(),
+            Err(Error::Parse(
+                1,
+                "invalid service name `mail.er`".to_string()
+            ))
+        );
+    }
+}
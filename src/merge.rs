@@ -0,0 +1,303 @@
+use std::{
+    any::TypeId,
+    collections::HashMap,
+    hash::{BuildHasher, Hash},
+};
+
+use crate::{
+    cache,
+    hash::{TypeMap, TypeSet},
+    multi::insert_by_priority,
+    Container, Error, Lifetime,
+};
+
+/// What [`Container::merge`] does when both containers register the same type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnConflict {
+    /// Keep the container's own registration.
+    Skip,
+    /// Replace the container's registration with the merged one.
+    Overwrite,
+    /// Refuse to merge anything, returning [`Error::Conflict`].
+    Error,
+}
+
+impl Container {
+    /// Move every registration from another container into this one.
+    ///
+    /// This combines bindings assembled independently (for example, by different modules) into a single container at startup. Types registered by both containers are handled according to `on_conflict`, and so are named, parameterized and per-key bindings registered under the same key. Additive bindings, tags, decorators, callbacks, service providers and scheduled services are appended to the container's own. The other container's subscribers, parent and missing-type handler are discarded.
+    ///
+    /// Sealed types are never replaced, and types sealed in the other container stay sealed once merged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Frozen`] if the container is frozen, or [`Error::Conflict`], listing the conflicting types, if `on_conflict` is [`OnConflict::Error`] and any registration conflicts. Nothing is merged in either case.
+    pub fn merge(&mut self, mut other: Self, on_conflict: OnConflict) -> Result<(), Error> {
+        self.ensure_mutable()?;
+
+        let ours = self.registered_types();
+        let theirs = other.registered_types();
+
+        if on_conflict == OnConflict::Error {
+            let mut conflicts = theirs
+                .iter()
+                .copied()
+                .filter(|type_id| ours.contains(type_id))
+                .chain(conflicting(
+                    &self.named_bindings,
+                    &other.named_bindings,
+                    |key| key.0,
+                ))
+                .chain(conflicting(
+                    &self.parameterized,
+                    &other.parameterized,
+                    |key| key.0,
+                ))
+                .chain(conflicting(&self.per_key, &other.per_key, |key| *key))
+                .collect::<Vec<_>>();
+            conflicts.sort_unstable();
+            conflicts.dedup();
+
+            if !conflicts.is_empty() {
+                return Err(Error::Conflict(conflicts));
+            }
+        }
+
+        for type_id in theirs {
+            if (on_conflict == OnConflict::Skip && ours.contains(&type_id))
+                || self.refuse_sealed(type_id)
+            {
+                continue;
+            }
+
+            self.adopt(&mut other, type_id);
+        }
+
+        let overwrite = on_conflict == OnConflict::Overwrite;
+        merge_keyed(&mut self.named_bindings, other.named_bindings, overwrite);
+        merge_keyed(&mut self.parameterized, other.parameterized, overwrite);
+        merge_keyed(&mut self.per_key, other.per_key, overwrite);
+
+        for (type_id, bindings) in other.multi_bindings {
+            let merged = self.multi_bindings.entry(type_id).or_default();
+            for (priority, binding) in bindings {
+                insert_by_priority(merged, priority, binding);
+            }
+        }
+        for (tag, bindings) in other.tags {
+            let merged = self.tags.entry(tag).or_default();
+            for (priority, binding) in bindings {
+                insert_by_priority(merged, priority, binding);
+            }
+        }
+        for (type_id, extenders) in other.extenders {
+            self.extenders.entry(type_id).or_default().extend(extenders);
+        }
+        for (type_id, callbacks) in other.rebinding_callbacks {
+            self.rebinding_callbacks
+                .entry(type_id)
+                .or_default()
+                .extend(callbacks);
+        }
+        self.resolving_hooks.append(other.resolving_hooks);
+        self.after_resolving_hooks
+            .append(other.after_resolving_hooks);
+        self.providers.extend(other.providers);
+        self.scheduled.extend(other.scheduled);
+        self.eager.extend(other.eager);
+        self.boot_callbacks.extend(other.boot_callbacks);
+        self.async_boot_hooks.extend(other.async_boot_hooks);
+        self.async_shutdown_hooks.extend(other.async_shutdown_hooks);
+        self.sealed.extend(other.sealed);
+
+        self.version = cache::next_version();
+
+        Ok(())
+    }
+
+    /// The types with a binding, shared instance, pool or connection-scoped binding registered.
+    fn registered_types(&self) -> TypeSet {
+        self.bindings
+            .types()
+            .iter()
+            .chain(self.emplacers.keys())
+            .chain(self.connection_bindings.keys())
+            .chain(self.pools.keys())
+            .copied()
+            .collect()
+    }
+
+    /// Replace this container's registration for the given type with the other container's.
+    fn adopt(&mut self, other: &mut Self, type_id: TypeId) {
+        let lifetime = if other.connection_bindings.contains_key(&type_id)
+            || other.thread_scoped.contains_key(&type_id)
+            || other.bindings.is_scoped(&type_id)
+            || other.in_named_scope(type_id)
+        {
+            Lifetime::Scoped
+        } else if other.bindings.contains_instance(&type_id) {
+            Lifetime::Singleton
+        } else {
+            Lifetime::Transient
+        };
+        let event = self.registration(type_id, lifetime);
+
+        self.tear_down(type_id);
+        self.bindings.remove(&type_id);
+        self.lazy_instances.remove(&type_id);
+        self.swappable.remove(&type_id);
+        self.copiers.remove(&type_id);
+        self.emplacers.remove(&type_id);
+        self.pools.remove(&type_id);
+        self.thread_scoped.remove(&type_id);
+        self.connection_bindings.remove(&type_id);
+        self.arcs.retain(|_, (owner, _)| *owner != type_id);
+        for types in self.named_scopes.values_mut() {
+            types.retain(|scoped| *scoped != type_id);
+        }
+
+        if let Some(registration) = other.bindings.remove(&type_id) {
+            self.bindings.insert(type_id, registration);
+        }
+        move_entry(&mut self.lazy_instances, &mut other.lazy_instances, type_id);
+        move_entry(&mut self.swappable, &mut other.swappable, type_id);
+        move_entry(&mut self.copiers, &mut other.copiers, type_id);
+        move_entry(&mut self.teardowns, &mut other.teardowns, type_id);
+        move_entry(&mut self.emplacers, &mut other.emplacers, type_id);
+        move_entry(&mut self.pools, &mut other.pools, type_id);
+        move_entry(&mut self.thread_scoped, &mut other.thread_scoped, type_id);
+        move_entry(
+            &mut self.connection_bindings,
+            &mut other.connection_bindings,
+            type_id,
+        );
+        move_entry(&mut self.metadata, &mut other.metadata, type_id);
+        let arcs = other
+            .arcs
+            .iter()
+            .filter(|(_, (owner, _))| *owner == type_id)
+            .map(|(arc, _)| *arc)
+            .collect::<Vec<_>>();
+        for arc in arcs {
+            move_entry(&mut self.arcs, &mut other.arcs, arc);
+        }
+        for (scope, types) in &other.named_scopes {
+            if types.contains(&type_id) {
+                self.named_scopes.entry(scope).or_default().push(type_id);
+            }
+        }
+
+        self.registered(event);
+    }
+}
+
+/// Move the value stored for the given type from one map to another, if there is one.
+fn move_entry<V>(to: &mut TypeMap<V>, from: &mut TypeMap<V>, type_id: TypeId) {
+    if let Some(value) = from.remove(&type_id) {
+        to.insert(type_id, value);
+    }
+}
+
+/// The types of the keys registered in both maps.
+fn conflicting<'a, K: Eq + Hash, V, S: BuildHasher>(
+    ours: &'a HashMap<K, V, S>,
+    theirs: &'a HashMap<K, V, S>,
+    type_of: impl Fn(&K) -> TypeId + 'a,
+) -> impl Iterator<Item = TypeId> + 'a {
+    theirs
+        .keys()
+        .filter(|key| ours.contains_key(key))
+        .map(type_of)
+}
+
+/// Move every entry from `theirs` into `ours`, keeping our own entry for keys in both unless `overwrite` is set.
+fn merge_keyed<K: Eq + Hash, V, S: BuildHasher>(
+    ours: &mut HashMap<K, V, S>,
+    theirs: HashMap<K, V, S>,
+    overwrite: bool,
+) {
+    for (key, value) in theirs {
+        if overwrite || !ours.contains_key(&key) {
+            ours.insert(key, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    fn modules() -> (Container, Container) {
+        let mut ours = Container::new();
+        ours.bind(|_| 1_u8);
+        ours.singleton(&|_| "ours");
+        ours.bind_many(|_| 1_u16);
+
+        let mut theirs = Container::new();
+        theirs.bind(|_| 2_u32);
+        theirs.singleton(&|_| "theirs");
+        theirs.bind_many(|_| 2_u16);
+
+        (ours, theirs)
+    }
+
+    #[test]
+    fn merges_registrations_from_another_container() {
+        let (mut ours, theirs) = modules();
+        ours.merge(theirs, OnConflict::Skip).unwrap();
+
+        assert_eq!(ours.resolve::<u8>(), Ok(1));
+        assert_eq!(ours.resolve::<u32>(), Ok(2));
+        assert_eq!(ours.resolve::<&str>(), Ok("ours"));
+        assert_eq!(ours.resolve_all::<u16>(), Ok(vec![1, 2]));
+
+        let (mut ours, theirs) = modules();
+        ours.merge(theirs, OnConflict::Overwrite).unwrap();
+
+        assert_eq!(ours.resolve::<&str>(), Ok("theirs"));
+        assert_eq!(
+            ours.resolve::<Arc<&str>>().map(|value| *value),
+            Ok("theirs")
+        );
+        assert!(ours.is_singleton::<&str>());
+    }
+
+    #[test]
+    fn refuses_to_merge_conflicting_registrations() {
+        let (mut ours, theirs) = modules();
+
+        assert_eq!(
+            ours.merge(theirs, OnConflict::Error),
+            Err(Error::Conflict(vec![TypeId::of::<&str>()]))
+        );
+        assert!(ours.resolve::<u32>().is_err());
+        assert_eq!(ours.resolve_all::<u16>(), Ok(vec![1]));
+    }
+
+    #[test]
+    fn refuses_to_merge_into_a_frozen_container() {
+        let (mut ours, mut theirs) = modules();
+        theirs.bind_named("answer", |_| 42_u32);
+        theirs.tag::<u32>("numbers");
+        ours.freeze();
+
+        assert_eq!(ours.merge(theirs, OnConflict::Skip), Err(Error::Frozen));
+        assert!(ours.resolve::<u32>().is_err());
+        assert!(ours.resolve_named::<u32>("answer").is_err());
+        assert_eq!(ours.resolve_tagged::<u32>("numbers"), Ok(vec![]));
+        assert_eq!(ours.resolve_all::<u16>(), Ok(vec![1]));
+    }
+
+    #[test]
+    fn keeps_merged_types_sealed() {
+        let (mut ours, mut theirs) = modules();
+        theirs.seal::<u32>();
+        ours.merge(theirs, OnConflict::Skip).unwrap();
+
+        ours.bind(|_| 3_u32);
+
+        assert_eq!(ours.take_refused(), Err(Error::Sealed));
+        assert_eq!(ours.resolve::<u32>(), Ok(2));
+    }
+}
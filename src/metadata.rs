@@ -0,0 +1,132 @@
+use std::any::{type_name, TypeId};
+
+use crate::Container;
+
+/// Human-readable information about a registered service, for introspection and debugging.
+///
+/// ```rust
+/// use silhouette::{metadata::Metadata, Container};
+///
+/// let mut container = Container::new();
+/// container.bind_with_meta(
+///     Metadata::new("Sends transactional email").tag("mail").version("2.1"),
+///     |_| "smtp://localhost",
+/// );
+///
+/// for (type_name, metadata) in container.services() {
+///     println!("{type_name}: {}", metadata.description);
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Metadata {
+    /// What the service does.
+    pub description: String,
+    /// Free-form labels for grouping services.
+    pub tags: Vec<String>,
+    /// The version of the service's implementation, if it has one.
+    pub version: Option<String>,
+}
+
+impl Metadata {
+    /// Describe a service.
+    #[must_use]
+    pub fn new(description: impl Into<String>) -> Self {
+        Self {
+            description: description.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Add a label to the service.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Set the version of the service's implementation.
+    #[must_use]
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+}
+
+impl Container {
+    /// Register a binding with the container, along with metadata describing it.
+    pub fn bind_with_meta<T: 'static>(
+        &mut self,
+        metadata: Metadata,
+        factory: impl Fn(&Self) -> T + 'static + Sync + Send,
+    ) {
+        self.bind(factory);
+        self.describe::<T>(metadata);
+    }
+
+    /// Attach metadata to the given type, whatever its lifetime.
+    ///
+    /// The metadata is kept when the type is registered again, until it's described again or [forgotten](Container::forget).
+    pub fn describe<T: 'static>(&mut self, metadata: Metadata) {
+        self.metadata
+            .insert(TypeId::of::<T>(), (type_name::<T>(), metadata));
+    }
+
+    /// The metadata attached to the given type, if any.
+    #[must_use]
+    pub fn metadata<T: 'static>(&self) -> Option<&Metadata> {
+        self.metadata
+            .get(&TypeId::of::<T>())
+            .map(|(_, metadata)| metadata)
+    }
+
+    /// Every described service, as its type name and metadata, sorted by type name.
+    #[must_use]
+    pub fn services(&self) -> Vec<(&'static str, &Metadata)> {
+        let mut services = self
+            .metadata
+            .values()
+            .map(|(name, metadata)| (*name, metadata))
+            .collect::<Vec<_>>();
+        services.sort_unstable_by_key(|(name, _)| *name);
+
+        services
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_described_services() {
+        let mut container = Container::new();
+        container.bind_with_meta(
+            Metadata::new("The answer").tag("math").version("1.0"),
+            |_| 42_u32,
+        );
+        container.singleton(&|_| 7_u64);
+        container.describe::<u64>(Metadata::new("A lucky number"));
+        container.bind(|_| 1_u8);
+
+        assert_eq!(container.resolve::<u32>(), Ok(42));
+        assert_eq!(
+            container.metadata::<u32>(),
+            Some(&Metadata {
+                description: "The answer".to_string(),
+                tags: vec!["math".to_string()],
+                version: Some("1.0".to_string()),
+            })
+        );
+        assert_eq!(
+            container
+                .services()
+                .into_iter()
+                .map(|(name, metadata)| (name, metadata.description.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("u32", "The answer"), ("u64", "A lucky number")]
+        );
+
+        container.forget::<u32>();
+        assert_eq!(container.metadata::<u32>(), None);
+    }
+}
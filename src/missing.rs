@@ -0,0 +1,63 @@
+use std::{
+    any::{Any, TypeId},
+    sync::Arc,
+};
+
+use crate::{Container, Error};
+
+/// Resolves a type that has no registration, given its [`TypeId`].
+pub type MissingHandler =
+    Arc<dyn Fn(&Container, TypeId) -> Result<Box<dyn Any>, Error> + Sync + Send>;
+
+impl Container {
+    /// Handle resolutions of types that aren't registered, instead of failing with [`Error::NotFound`].
+    ///
+    /// The handler receives the requested type's [`TypeId`], and must return a value of that type (or resolving it fails with [`Error::CastFailed`]). It can build the value itself, log the miss and return a stub, or consult another container through [`ContainerInterface::resolve_erased`](crate::interface::ContainerInterface::resolve_erased). Returning [`Error::NotFound`] keeps the default behaviour. Setting a new handler replaces the previous one.
+    pub fn set_missing_handler(
+        &mut self,
+        handler: impl Fn(&Self, TypeId) -> Result<Box<dyn Any>, Error> + 'static + Sync + Send,
+    ) {
+        if self.refuse_frozen() {
+            return;
+        }
+
+        self.missing_handler = Some(Arc::new(handler));
+    }
+
+    /// Resolve a type that has no registration with the missing handler, if one is set.
+    pub(crate) fn resolve_missing(&self, type_id: TypeId) -> Result<Box<dyn Any>, Error> {
+        self.missing_handler
+            .as_ref()
+            .map_or(Err(Error::NotFound), |handler| handler(self, type_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::ContainerInterface;
+
+    #[test]
+    fn missing_types_are_resolved_by_the_handler() {
+        let mut fallback = Container::new();
+        fallback.bind(|_| "from the fallback container");
+
+        let mut container = Container::new();
+        container.set_missing_handler(move |_, type_id| {
+            if type_id == TypeId::of::<u32>() {
+                return Ok(Box::new(u32::default()));
+            }
+
+            fallback.resolve_erased(type_id)
+        });
+        container.bind(|_| 42_u64);
+
+        assert_eq!(container.resolve::<u64>(), Ok(42));
+        assert_eq!(container.resolve::<u32>(), Ok(0));
+        assert_eq!(
+            container.resolve::<&str>(),
+            Ok("from the fallback container")
+        );
+        assert_eq!(container.resolve::<u8>(), Err(Error::NotFound));
+    }
+}
@@ -0,0 +1,120 @@
+use std::{
+    any::{Any, TypeId},
+    sync::Arc,
+};
+
+use crate::{Binding, Container, Error};
+
+/// Add a binding to a list ordered by descending priority, after any bindings with the same priority.
+pub fn insert_by_priority(bindings: &mut Vec<(i32, Binding)>, priority: i32, binding: Binding) {
+    let index = bindings.partition_point(|(existing, _)| *existing >= priority);
+
+    bindings.insert(index, (priority, binding));
+}
+
+impl Container {
+    /// Register an additional binding for the given type, alongside any registered before it.
+    ///
+    /// Unlike [`Container::bind`], this never replaces an earlier registration. Every binding registered this way is resolved with [`Container::resolve_all`], which is useful for assembling event handlers, validators or middleware chains from multiple modules.
+    pub fn bind_many<T: 'static>(&mut self, factory: impl Fn(&Self) -> T + 'static + Sync + Send) {
+        self.bind_many_with_priority(0, factory);
+    }
+
+    /// Register an additional binding for the given type, ordered by priority among the others.
+    ///
+    /// [`Container::resolve_all`] returns higher-priority bindings first. Bindings registered with [`Container::bind_many`] have a priority of `0`.
+    pub fn bind_many_with_priority<T: 'static>(
+        &mut self,
+        priority: i32,
+        factory: impl Fn(&Self) -> T + 'static + Sync + Send,
+    ) {
+        if self.refuse_frozen() {
+            return;
+        }
+
+        insert_by_priority(
+            self.multi_bindings.entry(TypeId::of::<T>()).or_default(),
+            priority,
+            Arc::new(move |container: &Self| Ok(Box::new(factory(container)) as Box<dyn Any>)),
+        );
+    }
+
+    /// Resolve every binding registered for the given type with [`Container::bind_many`], from highest to lowest priority, and in the order they were registered within a priority.
+    ///
+    /// Resolving a type without any such bindings returns an empty list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the bindings fails, or if its value cannot be cast to the requested type.
+    pub fn resolve_all<T: 'static>(&self) -> Result<Vec<T>, Error> {
+        self.multi_bindings
+            .get(&TypeId::of::<T>())
+            .into_iter()
+            .flatten()
+            .map(|(_, binding)| {
+                binding(self)?
+                    .downcast::<T>()
+                    .map(|value| *value)
+                    .map_err(|_| Error::CastFailed)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    trait Validator: Send + Sync {
+        fn validate(&self, input: &str) -> bool;
+    }
+
+    struct NotEmpty;
+    impl Validator for NotEmpty {
+        fn validate(&self, input: &str) -> bool {
+            !input.is_empty()
+        }
+    }
+
+    struct ShorterThan(usize);
+    impl Validator for ShorterThan {
+        fn validate(&self, input: &str) -> bool {
+            input.len() < self.0
+        }
+    }
+
+    #[test]
+    fn resolves_every_binding_for_a_type() {
+        let mut container = Container::new();
+        container.bind_many(|_| Arc::new(NotEmpty) as Arc<dyn Validator>);
+        container.bind_many(|_| Arc::new(ShorterThan(5)) as Arc<dyn Validator>);
+
+        let validators = container.resolve_all::<Arc<dyn Validator>>().unwrap();
+
+        assert_eq!(validators.len(), 2);
+        assert!(validators.iter().all(|validator| validator.validate("abc")));
+        assert!(!validators
+            .iter()
+            .all(|validator| validator.validate("abcdef")));
+        assert!(container.resolve_all::<u32>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn orders_bindings_by_priority() {
+        let mut container = Container::new();
+        container.bind_many(|_| "default");
+        container.bind_many_with_priority(-10, |_| "last");
+        container.bind_many_with_priority(10, |_| "first");
+        container.bind_many(|_| "default, registered later");
+
+        assert_eq!(
+            container.resolve_all::<&str>(),
+            Ok(vec![
+                "first",
+                "default",
+                "default, registered later",
+                "last"
+            ])
+        );
+    }
+}
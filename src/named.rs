@@ -0,0 +1,82 @@
+use std::{
+    any::{Any, TypeId},
+    sync::Arc,
+};
+
+use crate::{Container, Error};
+
+impl Container {
+    /// Register a binding for the given type under a name, replacing any binding registered for the type under the same name.
+    ///
+    /// Named bindings live alongside the type's regular registration, so several differently-configured values of one type can be registered and resolved with [`Container::resolve_named`].
+    pub fn bind_named<T: 'static>(
+        &mut self,
+        name: &'static str,
+        factory: impl Fn(&Self) -> T + 'static + Sync + Send,
+    ) {
+        if self.refuse_frozen() {
+            return;
+        }
+
+        self.named_bindings.insert(
+            (TypeId::of::<T>(), name),
+            Arc::new(move |container: &Self| Ok(Box::new(factory(container)) as Box<dyn Any>)),
+        );
+    }
+
+    /// Resolve the binding registered for the given type under a name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no binding is registered for the type under that name, or if its value cannot be cast to the requested type.
+    pub fn resolve_named<T: 'static>(&self, name: &str) -> Result<T, Error> {
+        let binding = self
+            .named_bindings
+            .get(&(TypeId::of::<T>(), name))
+            .ok_or(Error::NotFound)?;
+
+        binding(self)?
+            .downcast::<T>()
+            .map(|value| *value)
+            .map_err(|_| Error::CastFailed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct HttpClient {
+        base_url: &'static str,
+    }
+
+    #[test]
+    fn resolves_bindings_by_name() {
+        let mut container = Container::new();
+        container.bind_named("internal", |_| HttpClient {
+            base_url: "http://internal",
+        });
+        container.bind_named("external", |_| HttpClient {
+            base_url: "https://example.com",
+        });
+
+        assert_eq!(
+            container.resolve_named::<HttpClient>("internal"),
+            Ok(HttpClient {
+                base_url: "http://internal"
+            })
+        );
+        assert_eq!(
+            container.resolve_named::<HttpClient>("external"),
+            Ok(HttpClient {
+                base_url: "https://example.com"
+            })
+        );
+        assert_eq!(
+            container.resolve_named::<HttpClient>("missing"),
+            Err(Error::NotFound)
+        );
+        assert_eq!(container.resolve::<HttpClient>(), Err(Error::NotFound));
+    }
+}
@@ -0,0 +1,79 @@
+use std::any::TypeId;
+
+use crate::{cache, events::ContainerEvent, Container};
+
+impl Container {
+    /// Register a binding scoped to the named scope, such as `"request"`, `"session"` or `"job"`.
+    ///
+    /// Named scopes have independent lifecycles: [`Container::forget_scope`] ends one of them without touching the others, or the instances registered with [`Container::scoped`].
+    pub fn scoped_in<T: 'static + Clone + Send + Sync>(
+        &mut self,
+        scope: &'static str,
+        factory: &(impl Fn(&Self) -> T + 'static),
+    ) {
+        let type_id = TypeId::of::<T>();
+        if self.refuse_sealed(type_id) {
+            return;
+        }
+
+        let types = self.named_scopes.entry(scope).or_default();
+        if !types.contains(&type_id) {
+            types.push(type_id);
+        }
+
+        self.singleton(factory);
+    }
+
+    /// Clear the instances scoped to the named scope, ending it.
+    pub fn forget_scope(&mut self, scope: &'static str) {
+        for type_id in self.named_scopes.get(scope).cloned().into_iter().flatten() {
+            self.tear_down(type_id);
+            self.bindings.remove_instance(&type_id);
+            self.swappable.remove(&type_id);
+        }
+
+        self.version = cache::next_version();
+        self.emit(ContainerEvent::NamedScopeEnded { scope });
+    }
+
+    /// Whether the given type is scoped to any named scope.
+    pub(crate) fn in_named_scope(&self, type_id: TypeId) -> bool {
+        self.named_scopes
+            .values()
+            .any(|types| types.contains(&type_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct RequestId(u32);
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct SessionId(u32);
+
+    #[test]
+    fn named_scopes_end_independently() {
+        let mut container = Container::new();
+        container.scoped_in("request", &|_| RequestId(1));
+        container.scoped_in("session", &|_| SessionId(2));
+        container.scoped(&|_| 3_u32);
+
+        assert!(container.is_scoped::<RequestId>());
+        assert!(!container.is_singleton::<SessionId>());
+
+        container.forget_scope("request");
+        assert_eq!(container.resolve::<RequestId>(), Err(Error::NotFound));
+        assert_eq!(container.resolve::<SessionId>(), Ok(SessionId(2)));
+        assert_eq!(container.resolve::<u32>(), Ok(3));
+
+        container.forget_scoped_instances();
+        assert_eq!(container.resolve::<SessionId>(), Ok(SessionId(2)));
+
+        container.forget_scope("session");
+        assert_eq!(container.resolve::<SessionId>(), Err(Error::NotFound));
+    }
+}
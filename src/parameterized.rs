@@ -0,0 +1,74 @@
+use std::{any::TypeId, sync::Arc};
+
+use crate::{Container, Error};
+
+/// A factory that receives caller-supplied arguments along with the container.
+type ParameterizedFactory<T, Args> = dyn Fn(&Container, Args) -> T + Sync + Send;
+
+impl Container {
+    /// Register a binding whose factory receives arguments supplied by the caller, along with the container.
+    ///
+    /// The binding is resolved with [`Container::resolve_with`], passing arguments of the same `Args` type. Registering another factory for the same `T` and `Args` replaces it.
+    pub fn bind_with<T: 'static, Args: 'static>(
+        &mut self,
+        factory: impl Fn(&Self, Args) -> T + 'static + Sync + Send,
+    ) {
+        if self.refuse_frozen() {
+            return;
+        }
+
+        self.parameterized.insert(
+            (TypeId::of::<T>(), TypeId::of::<Args>()),
+            Arc::new(Box::new(factory) as Box<ParameterizedFactory<T, Args>>),
+        );
+    }
+
+    /// Resolve the given type with per-call arguments, using the factory registered with [`Container::bind_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no factory taking `Args` is registered for the requested type.
+    pub fn resolve_with<T: 'static, Args: 'static>(&self, args: Args) -> Result<T, Error> {
+        let factory = self
+            .parameterized
+            .get(&(TypeId::of::<T>(), TypeId::of::<Args>()))
+            .ok_or(Error::NotFound)?
+            .downcast_ref::<Box<ParameterizedFactory<T, Args>>>()
+            .ok_or(Error::CastFailed)?;
+
+        Ok(factory(self, args))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Report {
+        tenant: u32,
+        title: String,
+    }
+
+    #[test]
+    fn passes_caller_arguments_to_the_factory() {
+        let mut container = Container::new();
+        container.bind(|_| "Quarterly".to_string());
+        container.bind_with(|container, tenant: u32| Report {
+            tenant,
+            title: container.resolve().unwrap(),
+        });
+
+        assert_eq!(
+            container.resolve_with::<Report, _>(7_u32),
+            Ok(Report {
+                tenant: 7,
+                title: "Quarterly".to_string()
+            })
+        );
+        assert_eq!(
+            container.resolve_with::<Report, _>("7"),
+            Err(Error::NotFound)
+        );
+    }
+}
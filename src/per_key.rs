@@ -0,0 +1,218 @@
+use std::{
+    any::TypeId,
+    collections::HashMap,
+    hash::Hash,
+    sync::{Arc, OnceLock, PoisonError, RwLock},
+};
+
+use crate::{Container, Error};
+
+/// A factory that builds the instance for a key.
+type KeyedFactory<T, K> = dyn Fn(&Container, &K) -> T + Sync + Send;
+
+/// The instances of a type scoped by key, such as one per tenant, constructed on demand by the factory.
+struct PerKey<T, K> {
+    /// Builds the instance for a key the first time it's resolved.
+    factory: Box<KeyedFactory<T, K>>,
+    /// The cell holding the instance for each key, filled once by whichever resolution gets to it first.
+    instances: RwLock<HashMap<Arc<K>, Arc<OnceLock<T>>>>,
+}
+
+impl Container {
+    /// Register a binding that's shared per key, so every key (such as a tenant identifier) gets its own cached instance.
+    ///
+    /// Instances are resolved with [`Container::resolve_for`], which runs the factory the first time each key is resolved. A single key's instance can be discarded with [`Container::evict_key`]. Registering another factory for `T` replaces it, along with every instance it built.
+    pub fn scoped_by_key<T, K>(&mut self, factory: impl Fn(&Self, &K) -> T + 'static + Sync + Send)
+    where
+        T: 'static + Clone + Send + Sync,
+        K: 'static + Eq + Hash + Send + Sync,
+    {
+        if self.refuse_frozen() {
+            return;
+        }
+
+        self.per_key.insert(
+            TypeId::of::<T>(),
+            Arc::new(PerKey {
+                factory: Box::new(factory),
+                instances: RwLock::default(),
+            }),
+        );
+    }
+
+    /// Resolve the instance of the given type for the given key, constructing it if the key hasn't been resolved yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the type isn't registered with [`Container::scoped_by_key`], or was registered with a different key type.
+    pub fn resolve_for<T, K>(&self, key: K) -> Result<T, Error>
+    where
+        T: 'static + Clone + Send + Sync,
+        K: 'static + Eq + Hash + Send + Sync,
+    {
+        let per_key = self.per_key::<T, K>()?;
+
+        let cell = per_key
+            .instances
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&key)
+            .map(Arc::clone);
+        if let Some(value) = cell.as_ref().and_then(|cell| cell.get()) {
+            return Ok(value.clone());
+        }
+
+        let key = Arc::new(key);
+        let cell = cell.unwrap_or_else(|| {
+            Arc::clone(
+                per_key
+                    .instances
+                    .write()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .entry(Arc::clone(&key))
+                    .or_default(),
+            )
+        });
+
+        // The factory runs without holding the map's lock, so a slow constructor doesn't block the other keys, while concurrent resolutions of the same key wait for it on the key's cell.
+        Ok(cell.get_or_init(|| (per_key.factory)(self, &key)).clone())
+    }
+
+    /// Discard the instance of the given type cached for the given key, so it's constructed again the next time the key is resolved.
+    ///
+    /// Returns whether an instance was cached for the key.
+    pub fn evict_key<T, K>(&self, key: &K) -> bool
+    where
+        T: 'static + Clone + Send + Sync,
+        K: 'static + Eq + Hash + Send + Sync,
+    {
+        self.per_key::<T, K>().is_ok_and(|per_key| {
+            per_key
+                .instances
+                .write()
+                .unwrap_or_else(PoisonError::into_inner)
+                .remove(key)
+                .is_some()
+        })
+    }
+
+    /// Find the instances of `T` scoped by keys of type `K`.
+    fn per_key<T: 'static + Send + Sync, K: 'static + Send + Sync>(
+        &self,
+    ) -> Result<Arc<PerKey<T, K>>, Error> {
+        Arc::clone(
+            self.per_key
+                .get(&TypeId::of::<T>())
+                .ok_or(Error::NotFound)?,
+        )
+        .downcast::<PerKey<T, K>>()
+        .map_err(|_| Error::CastFailed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        thread,
+        time::Duration,
+    };
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct TenantConfig {
+        tenant: u32,
+        build: usize,
+    }
+
+    #[test]
+    fn caches_an_instance_per_key() {
+        let builds = Arc::new(AtomicUsize::new(0));
+        let mut container = Container::new();
+
+        let counter = Arc::clone(&builds);
+        container.scoped_by_key(move |_, tenant: &u32| TenantConfig {
+            tenant: *tenant,
+            build: counter.fetch_add(1, Ordering::SeqCst),
+        });
+
+        let first = container.resolve_for::<TenantConfig, _>(1_u32).unwrap();
+        assert_eq!(
+            first,
+            TenantConfig {
+                tenant: 1,
+                build: 0
+            }
+        );
+        assert_eq!(container.resolve_for::<TenantConfig, _>(1_u32), Ok(first));
+        assert_eq!(
+            container.resolve_for::<TenantConfig, _>(2_u32),
+            Ok(TenantConfig {
+                tenant: 2,
+                build: 1
+            })
+        );
+
+        assert!(container.evict_key::<TenantConfig, _>(&1_u32));
+        assert!(!container.evict_key::<TenantConfig, _>(&3_u32));
+        assert_eq!(
+            container.resolve_for::<TenantConfig, _>(1_u32),
+            Ok(TenantConfig {
+                tenant: 1,
+                build: 2
+            })
+        );
+        assert_eq!(
+            container.resolve_for::<TenantConfig, _>(2_u32),
+            Ok(TenantConfig {
+                tenant: 2,
+                build: 1
+            })
+        );
+    }
+
+    #[test]
+    fn concurrent_resolutions_of_a_key_build_it_once() {
+        let builds = Arc::new(AtomicUsize::new(0));
+        let mut container = Container::new();
+
+        let counter = Arc::clone(&builds);
+        container.scoped_by_key(move |_, tenant: &u32| {
+            thread::sleep(Duration::from_millis(20));
+
+            TenantConfig {
+                tenant: *tenant,
+                build: counter.fetch_add(1, Ordering::SeqCst),
+            }
+        });
+
+        let container = &container;
+        let configs: Vec<_> = thread::scope(|scope| {
+            (0..8)
+                .map(|_| scope.spawn(|| container.resolve_for::<TenantConfig, _>(1_u32)))
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(|worker| worker.join().unwrap())
+                .collect()
+        });
+
+        assert_eq!(builds.load(Ordering::SeqCst), 1);
+        assert!(configs.iter().all(|config| config
+            == &Ok(TenantConfig {
+                tenant: 1,
+                build: 0
+            })));
+    }
+
+    #[test]
+    fn keys_must_match_the_registered_key_type() {
+        let mut container = Container::new();
+        container.scoped_by_key(|_, tenant: &u32| *tenant);
+
+        assert_eq!(
+            container.resolve_for::<u32, _>("acme"),
+            Err(Error::CastFailed)
+        );
+        assert_eq!(container.resolve_for::<u64, _>(1_u32), Err(Error::NotFound));
+    }
+}
@@ -0,0 +1,72 @@
+use std::{
+    any::{Any, TypeId},
+    sync::Arc,
+};
+
+use crate::{context, Container};
+
+impl Container {
+    /// Register a binding that's built at most once per top-level resolution.
+    ///
+    /// Resolving a deep graph of transient bindings can construct the same dependency many times. Within a single call to [`Container::resolve`] (including every dependency it resolves in turn), a per-resolution binding's factory runs once, and every dependent receives a clone of its value. The next top-level resolution builds a new one.
+    pub fn bind_per_resolution<T: 'static + Clone>(
+        &mut self,
+        factory: impl Fn(&Self) -> T + 'static + Sync + Send,
+    ) {
+        self.insert_binding(
+            TypeId::of::<T>(),
+            Arc::new(move |container: &Self| {
+                if !context::resolving() {
+                    return Ok(Box::new(factory(container)) as Box<dyn Any>);
+                }
+
+                let key = (std::ptr::from_ref(container) as usize, TypeId::of::<T>());
+                if let Some(value) = context::memoized::<T>(key) {
+                    return Ok(Box::new(value) as Box<dyn Any>);
+                }
+
+                let value = factory(container);
+                context::memoize(key, value.clone());
+
+                Ok(Box::new(value) as Box<dyn Any>)
+            }),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Clone)]
+    struct Connection(usize);
+
+    struct Repository(Connection);
+
+    struct Service {
+        users: Repository,
+        orders: Repository,
+    }
+
+    #[test]
+    fn builds_dependencies_once_per_resolution() {
+        let builds = Arc::new(AtomicUsize::new(0));
+        let mut container = Container::new();
+
+        let counter = Arc::clone(&builds);
+        container.bind_per_resolution(move |_| Connection(counter.fetch_add(1, Ordering::SeqCst)));
+        container.bind(|container| Repository(container.resolve().unwrap()));
+        container.bind(|container| Service {
+            users: container.resolve().unwrap(),
+            orders: container.resolve().unwrap(),
+        });
+
+        let first = container.resolve::<Service>().unwrap();
+        assert_eq!((first.users.0 .0, first.orders.0 .0), (0, 0));
+
+        let second = container.resolve::<Service>().unwrap();
+        assert_eq!((second.users.0 .0, second.orders.0 .0), (1, 1));
+        assert_eq!(builds.load(Ordering::SeqCst), 2);
+    }
+}
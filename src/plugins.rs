@@ -0,0 +1,322 @@
+use std::{
+    any::{Any, TypeId},
+    ffi::{c_char, c_void, CStr, CString},
+    path::Path,
+    sync::{Arc, OnceLock},
+};
+
+use crate::{Binding, Container};
+
+/// The symbol every plugin must export with its registration function, usually through [`export_plugin!`](crate::export_plugin).
+pub const REGISTER_SYMBOL: &CStr = c"silhouette_register";
+
+/// The symbol every plugin must export with the [`Abi`] it was built for, usually through [`export_plugin!`](crate::export_plugin).
+pub const ABI_SYMBOL: &CStr = c"silhouette_abi";
+
+/// The version of this crate, checked against the version a plugin was built with before it's registered.
+pub const VERSION: &CStr =
+    match CStr::from_bytes_with_nul(concat!(env!("CARGO_PKG_VERSION"), "\0").as_bytes()) {
+        Ok(version) => version,
+        Err(_) => panic!("the crate version contains a NUL byte"),
+    };
+
+/// The features that change the layout of a [`Container`], checked against the features a plugin was built with.
+pub const FEATURES: u32 = cfg!(feature = "concurrent") as u32
+    | (cfg!(feature = "parking_lot") as u32) << 1
+    | (cfg!(feature = "single_threaded") as u32) << 2
+    | (cfg!(feature = "tokio") as u32) << 3
+    | (cfg!(feature = "manifest") as u32) << 4
+    | (cfg!(feature = "nightly") as u32) << 5;
+
+/// The build of this crate a plugin was compiled against.
+///
+/// Plugins hand the host closures that receive its [`Container`], so a plugin can only be registered if it was built against the same version of this crate, with the same features.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct Abi {
+    /// The crate version, as a NUL-terminated string.
+    pub version: *const c_char,
+    /// The enabled features, as a bitmask of [`FEATURES`].
+    pub features: u32,
+}
+
+impl Abi {
+    /// The build of this crate the current binary was compiled against.
+    #[must_use]
+    pub const fn current() -> Self {
+        Self {
+            version: VERSION.as_ptr(),
+            features: FEATURES,
+        }
+    }
+
+    /// A description of the build, for error messages.
+    ///
+    /// # Safety
+    ///
+    /// `version` must point to a valid, NUL-terminated string.
+    unsafe fn describe(&self) -> String {
+        let version = CStr::from_ptr(self.version).to_string_lossy();
+
+        format!("{version} (features {:#b})", self.features)
+    }
+}
+
+/// The signature of the function reporting a plugin's [`Abi`].
+pub type AbiFn = extern "C" fn() -> Abi;
+
+/// The signature of a plugin's registration function.
+pub type RegisterFn = unsafe extern "C" fn(*mut Host);
+
+/// The signature of the host's function installing a plugin's binding into its container.
+type InstallFn = unsafe extern "C" fn(container: *mut c_void, binding: *mut PluginBinding);
+
+/// A binding a plugin hands to the host.
+struct PluginBinding {
+    /// The type the binding produces.
+    type_id: TypeId,
+    /// The plugin's factory.
+    binding: Binding,
+}
+
+/// The host loading a plugin, handed to the plugin's registration function.
+///
+/// The host's container never crosses into the plugin: the plugin hands its factories to the host, whose own code registers them, so the container's bookkeeping (and the global container behind [`facade::Container`](crate::facade::Container)) stays the host's. The plugin links its own copy of this crate, with its own global container, so factories should resolve their dependencies from the container they're given instead of through the facade.
+#[repr(C)]
+pub struct Host {
+    /// The host's container, only ever used by the host's own code.
+    container: *mut c_void,
+    /// Installs a binding into the host's container.
+    install: InstallFn,
+}
+
+impl Host {
+    /// Register a binding with the host's container.
+    pub fn bind<T: 'static>(&mut self, factory: impl Fn(&Container) -> T + 'static + Sync + Send) {
+        self.install::<T>(Arc::new(move |container: &Container| {
+            Ok(Box::new(factory(container)) as Box<dyn Any>)
+        }));
+    }
+
+    /// Register a shared binding with the host's container, constructed the first time it's resolved.
+    pub fn singleton<T: 'static + Clone + Send + Sync>(
+        &mut self,
+        factory: impl Fn(&Container) -> T + 'static + Sync + Send,
+    ) {
+        let value = OnceLock::new();
+
+        self.bind(move |container: &Container| value.get_or_init(|| factory(container)).clone());
+    }
+
+    /// Hand a binding over to the host.
+    fn install<T: 'static>(&mut self, binding: Binding) {
+        let binding = Box::new(PluginBinding {
+            type_id: TypeId::of::<T>(),
+            binding,
+        });
+
+        // SAFETY: the host built this handle for the duration of the registration function, and takes ownership of the binding.
+        unsafe { (self.install)(self.container, Box::into_raw(binding)) };
+    }
+}
+
+/// Install a plugin's binding into the host's container.
+///
+/// # Safety
+///
+/// `container` must point to the container loading the plugin, and `binding` must have been allocated by [`Host::install`].
+unsafe extern "C" fn install(container: *mut c_void, binding: *mut PluginBinding) {
+    let container = &mut *container.cast::<Container>();
+    let PluginBinding { type_id, binding } = *Box::from_raw(binding);
+
+    container.insert_binding(type_id, binding);
+}
+
+/// Export a function as the plugin's registration entry point.
+///
+/// The function must take a `&mut` [`Host`], and will be called when the plugin is loaded to register the plugin's bindings with the host's container. The macro also exports the [`Abi`] the plugin was built for, so hosts built differently refuse to load it. Panics inside the function abort the process instead of unwinding into the host.
+///
+/// ```rust,ignore
+/// fn register(host: &mut silhouette::plugins::Host) {
+///     host.bind(|_| MyPluginService::new());
+/// }
+///
+/// silhouette::export_plugin!(register);
+/// ```
+#[macro_export]
+macro_rules! export_plugin {
+    ($register:path) => {
+        #[no_mangle]
+        pub extern "C" fn silhouette_abi() -> $crate::plugins::Abi {
+            $crate::plugins::Abi::current()
+        }
+
+        /// # Safety
+        ///
+        /// `host` must point to a handle built by the host for the duration of the call.
+        #[no_mangle]
+        pub unsafe extern "C" fn silhouette_register(host: *mut $crate::plugins::Host) {
+            $register(unsafe { &mut *host });
+        }
+    };
+}
+
+impl Container {
+    /// Load the shared library at `path` and let it install its bindings into the container.
+    ///
+    /// Libraries are never unloaded, since the bindings they register keep referring to their code. Loading the same library twice runs its registration function again.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the library cannot be loaded, if it doesn't export a registration function, or if it was built against a different version of this crate or with different features.
+    ///
+    /// # Safety
+    ///
+    /// Loading a library runs arbitrary code. The library must export its entry points with [`export_plugin!`](crate::export_plugin), and must have been built with the same compiler as the host, since its factories are handed to the host (and receive the host's container) as Rust types.
+    pub unsafe fn load_plugin(&mut self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+        let filename = CString::new(path.to_string_lossy().into_owned())
+            .map_err(|_| Error::Load(path.display().to_string()))?;
+
+        let handle = libc::dlopen(filename.as_ptr(), libc::RTLD_NOW | libc::RTLD_LOCAL);
+        if handle.is_null() {
+            return Err(Error::Load(last_error()));
+        }
+
+        let abi = libc::dlsym(handle, ABI_SYMBOL.as_ptr());
+        let register = libc::dlsym(handle, REGISTER_SYMBOL.as_ptr());
+        if abi.is_null() || register.is_null() {
+            return Err(Error::MissingSymbol(path.display().to_string()));
+        }
+
+        let abi = std::mem::transmute::<*mut libc::c_void, AbiFn>(abi)();
+        if CStr::from_ptr(abi.version) != VERSION || abi.features != FEATURES {
+            return Err(Error::Incompatible {
+                plugin: abi.describe(),
+                host: Abi::current().describe(),
+            });
+        }
+
+        let register = std::mem::transmute::<*mut libc::c_void, RegisterFn>(register);
+        let mut host = Host {
+            container: std::ptr::from_mut(self).cast(),
+            install,
+        };
+        register(&raw mut host);
+
+        Ok(())
+    }
+}
+
+/// The most recent error reported by the dynamic loader.
+fn last_error() -> String {
+    // SAFETY: `dlerror` returns either null or a valid, NUL-terminated string owned by the loader.
+    unsafe {
+        let error = libc::dlerror();
+
+        if error.is_null() {
+            "unknown error".to_string()
+        } else {
+            CStr::from_ptr(error).to_string_lossy().into_owned()
+        }
+    }
+}
+
+/// An error that can occur when loading a plugin.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The library couldn't be loaded.
+    #[error("Failed to load plugin: {0}")]
+    Load(String),
+
+    /// The library doesn't export its entry points.
+    #[error("Plugin `{0}` doesn't export `silhouette_abi` and `silhouette_register` functions")]
+    MissingSymbol(String),
+
+    /// The library was built against a different version of this crate, or with different features.
+    #[error("Plugin was built against silhouette {plugin}, but the host uses {host}")]
+    Incompatible {
+        /// The build the plugin was compiled against.
+        plugin: String,
+        /// The build the host was compiled against.
+        host: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{path::PathBuf, process::Command};
+
+    /// Build the plugin in `tests/fixtures/plugin` against this crate, with the same features, returning the path to the library.
+    fn build_fixture() -> PathBuf {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let target = root.join("target").join("plugin-fixture");
+        let features = [
+            (cfg!(feature = "concurrent"), "silhouette/concurrent"),
+            (cfg!(feature = "parking_lot"), "silhouette/parking_lot"),
+            (
+                cfg!(feature = "single_threaded"),
+                "silhouette/single_threaded",
+            ),
+            (cfg!(feature = "tokio"), "silhouette/tokio"),
+            (cfg!(feature = "manifest"), "silhouette/manifest"),
+            (cfg!(feature = "nightly"), "silhouette/nightly"),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, feature)| enabled.then_some(feature))
+        .collect::<Vec<_>>()
+        .join(",");
+
+        let status = Command::new(env!("CARGO"))
+            .args(["build", "--offline", "--quiet", "--features", &features])
+            .arg("--manifest-path")
+            .arg(root.join("tests/fixtures/plugin/Cargo.toml"))
+            .arg("--target-dir")
+            .arg(&target)
+            .status()
+            .unwrap();
+        assert!(status.success());
+
+        target.join("debug").join(format!(
+            "{}plugin{}",
+            std::env::consts::DLL_PREFIX,
+            std::env::consts::DLL_SUFFIX
+        ))
+    }
+
+    #[test]
+    fn loads_plugins_built_against_the_same_crate() {
+        let mut container = Container::new();
+
+        unsafe { container.load_plugin(build_fixture()) }.unwrap();
+
+        assert_eq!(
+            container.resolve::<String>(),
+            Ok("Hello from a plugin!".to_string())
+        );
+        assert_eq!(container.resolve::<u64>(), Ok(1));
+        assert_eq!(container.resolve::<u64>(), Ok(1));
+        assert!(container.has::<String>());
+    }
+
+    #[test]
+    fn plugins_cannot_register_on_frozen_containers() {
+        let mut container = Container::new();
+        container.freeze();
+
+        unsafe { container.load_plugin(build_fixture()) }.unwrap();
+
+        assert_eq!(container.take_refused(), Err(crate::Error::Frozen));
+        assert!(!container.has::<String>());
+    }
+
+    #[test]
+    fn reports_libraries_that_cannot_be_loaded() {
+        let mut container = Container::new();
+
+        let result = unsafe { container.load_plugin("/nonexistent/libplugin.so") };
+
+        assert!(matches!(result, Err(Error::Load(_))));
+    }
+}
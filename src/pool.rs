@@ -0,0 +1,239 @@
+use std::{
+    fmt, mem,
+    num::NonZeroUsize,
+    ops::{Deref, DerefMut},
+    sync::{Arc, Condvar, Mutex, PoisonError},
+};
+
+use crate::Container;
+
+/// A factory creating new pool members.
+type PoolFactory<T> = Box<dyn Fn(&Container) -> T + Send + Sync>;
+
+/// A bounded pool of reusable instances, registered with [`Container::pooled`].
+pub(crate) struct Pool<T> {
+    /// The maximum number of instances checked out at once.
+    max: NonZeroUsize,
+    /// Creates new instances when the pool has no idle ones.
+    factory: PoolFactory<T>,
+    /// The pool's idle instances and checked-out count.
+    state: Mutex<PoolState<T>>,
+    /// Notified whenever an instance is returned to the pool.
+    returned: Condvar,
+}
+
+/// The mutable state of a [`Pool`].
+struct PoolState<T> {
+    /// Instances ready to be checked out.
+    idle: Vec<T>,
+    /// The number of instances currently checked out.
+    checked_out: usize,
+}
+
+impl<T> Pool<T> {
+    pub(crate) fn new(
+        max: NonZeroUsize,
+        factory: impl Fn(&Container) -> T + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            max,
+            factory: Box::new(factory),
+            state: Mutex::new(PoolState {
+                idle: Vec::new(),
+                checked_out: 0,
+            }),
+            returned: Condvar::new(),
+        }
+    }
+
+    /// Check an instance out of the pool, waiting for one to be returned if the pool is exhausted.
+    pub(crate) fn checkout(self: &Arc<Self>, container: &Container) -> Pooled<T> {
+        let mut state = self
+            .returned
+            .wait_while(
+                self.state.lock().unwrap_or_else(PoisonError::into_inner),
+                |state| state.idle.is_empty() && state.checked_out >= self.max.get(),
+            )
+            .unwrap_or_else(PoisonError::into_inner);
+
+        state.checked_out += 1;
+        let idle = state.idle.pop();
+        drop(state);
+
+        let value = idle.unwrap_or_else(|| {
+            let reservation = Reservation(self);
+            let value = (self.factory)(container);
+            mem::forget(reservation);
+
+            value
+        });
+
+        Pooled {
+            value: Some(value),
+            pool: Arc::clone(self),
+        }
+    }
+
+    fn checkin(&self, value: T) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        state.checked_out -= 1;
+        state.idle.push(value);
+        drop(state);
+
+        self.returned.notify_one();
+    }
+
+    /// Give back a checked-out slot that never received an instance.
+    fn release(&self) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        state.checked_out -= 1;
+        drop(state);
+
+        self.returned.notify_one();
+    }
+}
+
+/// A slot checked out while the factory creates its instance, given back if the factory panics.
+struct Reservation<'a, T>(&'a Pool<T>);
+
+impl<T> Drop for Reservation<'_, T> {
+    fn drop(&mut self) {
+        self.0.release();
+    }
+}
+
+/// An instance checked out of a pool, returned to it when dropped.
+pub struct Pooled<T> {
+    /// The checked-out instance. Only `None` while being returned to the pool.
+    value: Option<T>,
+    /// The pool the instance belongs to.
+    pool: Arc<Pool<T>>,
+}
+
+impl<T> Deref for Pooled<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.value
+            .as_ref()
+            .expect("pooled value is only taken on drop")
+    }
+}
+
+impl<T> DerefMut for Pooled<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.value
+            .as_mut()
+            .expect("pooled value is only taken on drop")
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Pooled<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Pooled").field(&self.value).finish()
+    }
+}
+
+impl<T> Drop for Pooled<T> {
+    fn drop(&mut self) {
+        if let Some(value) = self.value.take() {
+            self.pool.checkin(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Pooled;
+    use crate::{Container, Error};
+    use std::{
+        num::NonZeroUsize,
+        panic::{self, AssertUnwindSafe},
+        sync::{
+            atomic::{AtomicBool, AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+
+    #[test]
+    fn reuses_returned_instances() {
+        let created = Arc::new(AtomicUsize::new(0));
+        let mut container = Container::new();
+
+        let counter = Arc::clone(&created);
+        container.pooled(NonZeroUsize::new(2).unwrap(), move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            Vec::<u8>::with_capacity(1024)
+        });
+
+        let mut buffer = container.resolve_pooled::<Vec<u8>>().unwrap();
+        buffer.push(1);
+        drop(buffer);
+
+        let buffer = container.resolve_pooled::<Vec<u8>>().unwrap();
+
+        assert_eq!(*buffer, vec![1]);
+        assert_eq!(created.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn waits_for_an_instance_when_the_pool_is_exhausted() {
+        let mut container = Container::new();
+        container.pooled(NonZeroUsize::MIN, |_| 0_u32);
+
+        let first = container.resolve_pooled::<u32>().unwrap();
+
+        std::thread::scope(|scope| {
+            let waiter = scope.spawn(|| *container.resolve_pooled::<u32>().unwrap());
+
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            assert!(!waiter.is_finished());
+
+            drop(first);
+            assert_eq!(waiter.join().unwrap(), 0);
+        });
+    }
+
+    #[test]
+    fn pooled_instances_can_be_injected() {
+        let mut container = Container::new();
+        container.pooled(NonZeroUsize::MIN, |_| 7_u32);
+
+        let doubled = container.call(|number: Pooled<u32>| *number * 2);
+        assert_eq!(doubled, Ok(14));
+
+        let first = container.resolve::<Pooled<u32>>().unwrap();
+        drop(first);
+        assert_eq!(container.resolve::<Pooled<u32>>().map(|n| *n), Ok(7));
+    }
+
+    #[test]
+    fn frees_the_slot_when_the_factory_panics() {
+        let mut container = Container::new();
+
+        let panicked = AtomicBool::new(false);
+        container.pooled(NonZeroUsize::MIN, move |_| {
+            assert!(panicked.swap(true, Ordering::SeqCst), "connection refused");
+
+            5_u32
+        });
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| container.resolve_pooled::<u32>()));
+        assert!(result.is_err());
+
+        assert_eq!(container.resolve_pooled::<u32>().map(|n| *n), Ok(5));
+    }
+
+    #[test]
+    fn returns_error_when_no_pool_is_registered() {
+        let container = Container::new();
+
+        assert!(matches!(
+            container.resolve_pooled::<u32>(),
+            Err(Error::NotFound)
+        ));
+    }
+}
@@ -0,0 +1,192 @@
+use std::{
+    any::{type_name, TypeId},
+    sync::Arc,
+};
+
+use crate::{Container, Error};
+
+/// A self-contained bundle of bindings, with an optional boot phase.
+///
+/// Providers register their bindings as soon as they're added to the container, and are booted by [`Container::boot`] once every provider has been registered.
+/// Boot phases run in dependency order: a provider is only booted after every provider it [depends on](ServiceProvider::depends_on).
+pub trait ServiceProvider: Send + Sync {
+    /// Register the provider's bindings with the container.
+    fn register(&self, container: &mut Container);
+
+    /// Boot the provider, after all providers have been registered.
+    fn boot(&self, _container: &mut Container) {}
+
+    /// The types this provider registers, so other providers can depend on them.
+    fn provides(&self) -> Vec<TypeId> {
+        Vec::new()
+    }
+
+    /// The providers (or provided types) that must be booted before this provider.
+    fn depends_on(&self) -> Vec<TypeId> {
+        Vec::new()
+    }
+}
+
+/// A provider registered with the container.
+#[derive(Clone)]
+pub(crate) struct RegisteredProvider {
+    /// The provider's type.
+    pub(crate) id: TypeId,
+    /// The provider's type name, for error reporting.
+    pub(crate) name: &'static str,
+    /// The provider itself.
+    pub(crate) provider: Arc<dyn ServiceProvider>,
+    /// Whether the provider has been booted.
+    pub(crate) booted: bool,
+}
+
+impl RegisteredProvider {
+    pub(crate) fn new<P: ServiceProvider + 'static>(provider: P) -> Self {
+        Self {
+            id: TypeId::of::<P>(),
+            name: type_name::<P>(),
+            provider: Arc::new(provider),
+            booted: false,
+        }
+    }
+
+    /// Whether this provider satisfies the given dependency.
+    fn satisfies(&self, dependency: TypeId) -> bool {
+        self.id == dependency || self.provider.provides().contains(&dependency)
+    }
+}
+
+/// Compute the order in which the pending providers should be booted, as indices into `providers`.
+///
+/// Providers without dependencies between them keep their registration order.
+pub(crate) fn boot_order(providers: &[RegisteredProvider]) -> Result<Vec<usize>, Error> {
+    let mut pending: Vec<usize> = (0..providers.len())
+        .filter(|&i| !providers[i].booted)
+        .collect();
+    let mut order = Vec::with_capacity(pending.len());
+
+    while !pending.is_empty() {
+        let ready = pending.iter().position(|&candidate| {
+            providers[candidate]
+                .provider
+                .depends_on()
+                .into_iter()
+                .all(|dependency| {
+                    !pending
+                        .iter()
+                        .any(|&other| other != candidate && providers[other].satisfies(dependency))
+                })
+        });
+
+        let Some(ready) = ready else {
+            return Err(Error::ProviderCycle(
+                pending.iter().map(|&i| providers[i].name).collect(),
+            ));
+        };
+
+        order.push(pending.remove(ready));
+    }
+
+    Ok(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    static BOOTED: Mutex<Vec<&str>> = Mutex::new(Vec::new());
+
+    struct DatabaseProvider;
+    struct CacheProvider;
+    struct ReportProvider;
+
+    impl ServiceProvider for DatabaseProvider {
+        fn register(&self, container: &mut Container) {
+            container.bind(|_| "database");
+        }
+
+        fn boot(&self, _: &mut Container) {
+            BOOTED.lock().unwrap().push("database");
+        }
+    }
+
+    impl ServiceProvider for CacheProvider {
+        fn register(&self, container: &mut Container) {
+            container.bind(|_| 42_u32);
+        }
+
+        fn boot(&self, _: &mut Container) {
+            BOOTED.lock().unwrap().push("cache");
+        }
+
+        fn provides(&self) -> Vec<TypeId> {
+            vec![TypeId::of::<u32>()]
+        }
+
+        fn depends_on(&self) -> Vec<TypeId> {
+            vec![TypeId::of::<DatabaseProvider>()]
+        }
+    }
+
+    impl ServiceProvider for ReportProvider {
+        fn register(&self, _: &mut Container) {}
+
+        fn boot(&self, _: &mut Container) {
+            BOOTED.lock().unwrap().push("report");
+        }
+
+        fn depends_on(&self) -> Vec<TypeId> {
+            vec![TypeId::of::<u32>()]
+        }
+    }
+
+    #[test]
+    fn boots_providers_in_dependency_order() {
+        let mut container = Container::new();
+
+        container.register_provider(ReportProvider);
+        container.register_provider(CacheProvider);
+        container.register_provider(DatabaseProvider);
+
+        container.boot().unwrap();
+
+        assert_eq!(*BOOTED.lock().unwrap(), vec!["database", "cache", "report"]);
+        assert_eq!(container.resolve::<u32>(), Ok(42));
+    }
+
+    #[test]
+    fn reports_cycles_between_providers() {
+        struct First;
+        struct Second;
+
+        impl ServiceProvider for First {
+            fn register(&self, _: &mut Container) {}
+
+            fn depends_on(&self) -> Vec<TypeId> {
+                vec![TypeId::of::<Second>()]
+            }
+        }
+
+        impl ServiceProvider for Second {
+            fn register(&self, _: &mut Container) {}
+
+            fn depends_on(&self) -> Vec<TypeId> {
+                vec![TypeId::of::<First>()]
+            }
+        }
+
+        let mut container = Container::new();
+
+        container.register_provider(First);
+        container.register_provider(Second);
+
+        assert_eq!(
+            container.boot(),
+            Err(Error::ProviderCycle(vec![
+                type_name::<First>(),
+                type_name::<Second>()
+            ]))
+        );
+    }
+}
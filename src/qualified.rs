@@ -0,0 +1,160 @@
+use std::{
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{Container, Error};
+
+/// A value of type `T`, qualified by the zero-sized marker type `Q`.
+///
+/// Each qualifier produces a distinct type, so multiple bindings of the same underlying type can live side by side in the container while remaining fully type-checked.
+///
+/// ```rust
+/// use silhouette::{qualified::Qualified, Container};
+///
+/// struct Primary;
+/// struct Replica;
+///
+/// let mut container = Container::new();
+///
+/// container.bind(|_| Qualified::<Primary, _>::new("postgres://primary"));
+/// container.bind(|_| Qualified::<Replica, _>::new("postgres://replica"));
+///
+/// let dsn = container.resolve::<Qualified<Replica, &str>>().unwrap();
+/// assert_eq!(*dsn, "postgres://replica");
+/// ```
+pub struct Qualified<Q, T> {
+    /// The qualified value.
+    value: T,
+    /// The qualifier marker.
+    qualifier: PhantomData<fn() -> Q>,
+}
+
+impl<Q, T> Qualified<Q, T> {
+    /// Qualify the given value.
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            qualifier: PhantomData,
+        }
+    }
+
+    /// Unwrap the qualified value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<Q, T> Deref for Qualified<Q, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<Q, T> DerefMut for Qualified<Q, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<Q, T: Clone> Clone for Qualified<Q, T> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<Q, T: fmt::Debug> fmt::Debug for Qualified<Q, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Qualified").field(&self.value).finish()
+    }
+}
+
+impl<Q, T: PartialEq> PartialEq for Qualified<Q, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<Q, T: Eq> Eq for Qualified<Q, T> {}
+
+impl Container {
+    /// Register a binding for the given type, keyed by the zero-sized marker type `K`.
+    ///
+    /// This registers a [`Qualified<K, T>`] binding without wrapping the factory's value by hand, so several bindings of one type can be told apart by marker types, and resolved with [`Container::resolve_keyed`].
+    pub fn bind_keyed<T: 'static, K: 'static>(
+        &mut self,
+        factory: impl Fn(&Self) -> T + 'static + Sync + Send,
+    ) {
+        self.bind(move |container| Qualified::<K, T>::new(factory(container)));
+    }
+
+    /// Register a shared binding for the given type, keyed by the zero-sized marker type `K`.
+    pub fn singleton_keyed<T: 'static + Clone + Send + Sync, K: 'static>(
+        &mut self,
+        factory: &(impl Fn(&Self) -> T + 'static),
+    ) {
+        let value = factory(self);
+
+        self.instance(Qualified::<K, T>::new(value));
+    }
+
+    /// Resolve the given type's binding keyed by the marker type `K`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no binding is registered for the type under that key, or if its value cannot be cast to the requested type.
+    pub fn resolve_keyed<T: 'static, K: 'static>(&self) -> Result<T, Error> {
+        self.resolve::<Qualified<K, T>>().map(Qualified::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Primary;
+    struct Secondary;
+
+    #[test]
+    fn qualifiers_keep_bindings_of_the_same_type_apart() {
+        let mut container = Container::new();
+
+        container.singleton(&|_| Qualified::<Primary, _>::new("primary".to_string()));
+        container.singleton(&|_| Qualified::<Secondary, _>::new("secondary".to_string()));
+
+        assert_eq!(
+            container
+                .resolve::<Qualified<Primary, String>>()
+                .map(Qualified::into_inner),
+            Ok("primary".to_string())
+        );
+        assert_eq!(
+            container
+                .resolve::<Qualified<Secondary, String>>()
+                .map(Qualified::into_inner),
+            Ok("secondary".to_string())
+        );
+        assert_eq!(container.resolve::<String>(), Err(Error::NotFound));
+    }
+
+    #[test]
+    fn keyed_bindings_resolve_to_the_underlying_type() {
+        let mut container = Container::new();
+
+        container.singleton_keyed::<_, Primary>(&|_| "postgres://primary");
+        container.bind_keyed::<_, Secondary>(|_| "postgres://replica");
+
+        assert_eq!(
+            container.resolve_keyed::<&str, Primary>(),
+            Ok("postgres://primary")
+        );
+        assert_eq!(
+            container.resolve_keyed::<&str, Secondary>(),
+            Ok("postgres://replica")
+        );
+        assert_eq!(container.resolve::<&str>(), Err(Error::NotFound));
+    }
+}
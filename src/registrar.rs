@@ -0,0 +1,41 @@
+use crate::Container;
+
+/// A bundle of registrations that can be applied to a container.
+///
+/// Plugin systems can collect registrars from many crates and apply them in one pass with [`Container::register_all`].
+pub trait Registrar {
+    /// Register this registrar's bindings with the container.
+    fn register(&self, container: &mut Container);
+}
+
+impl<F: Fn(&mut Container)> Registrar for F {
+    fn register(&self, container: &mut Container) {
+        self(container);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct NumberRegistrar;
+
+    impl Registrar for NumberRegistrar {
+        fn register(&self, container: &mut Container) {
+            container.bind(|_| 42_u32);
+        }
+    }
+
+    #[test]
+    fn can_apply_registrars_in_one_pass() {
+        let mut container = Container::new();
+
+        container.register_all([
+            Box::new(NumberRegistrar) as Box<dyn Registrar>,
+            Box::new(|container: &mut Container| container.bind(|_| "Hello, world!")),
+        ]);
+
+        assert_eq!(container.resolve::<u32>(), Ok(42));
+        assert_eq!(container.resolve::<&str>(), Ok("Hello, world!"));
+    }
+}
@@ -0,0 +1,154 @@
+use std::{
+    any::{type_name, Any, TypeId},
+    fmt,
+    marker::PhantomData,
+    sync::{Arc, Weak},
+};
+
+use crate::{Binding, Container, Error, Instance};
+
+/// Where a registered type's values come from.
+#[derive(Clone)]
+pub(crate) enum Source {
+    /// A shared instance.
+    Instance(Instance),
+    /// A binding.
+    Binding(Binding),
+}
+
+/// A weak reference to a shared instance.
+type WeakInstance = Weak<dyn Fn(&Container) -> Box<dyn Any> + Sync + Send>;
+/// A weak reference to a binding.
+type WeakBinding = Weak<dyn Fn(&Container) -> Result<Box<dyn Any>, Error> + Sync + Send>;
+
+/// A [`Source`] that doesn't keep its registration alive, for caching it.
+pub(crate) enum WeakSource {
+    /// A shared instance.
+    Instance(WeakInstance),
+    /// A binding.
+    Binding(WeakBinding),
+}
+
+impl WeakSource {
+    /// Get the source back, unless its registration was dropped.
+    pub(crate) fn upgrade(&self) -> Option<Source> {
+        match self {
+            Self::Instance(instance) => instance.upgrade().map(Source::Instance),
+            Self::Binding(binding) => binding.upgrade().map(Source::Binding),
+        }
+    }
+}
+
+/// A handle that resolves `T` without looking up its binding every time.
+///
+/// Resolvers are created with [`Container::resolver`], which validates that `T` is registered. They keep using the binding that was registered when they were created, even if `T` is re-bound afterwards.
+pub struct Resolver<T> {
+    /// The binding or instance the resolver uses.
+    source: Source,
+    /// The type being resolved.
+    resolves: PhantomData<fn() -> T>,
+}
+
+impl<T: 'static> Resolver<T> {
+    /// Resolve a value, using the given container for the binding's own dependencies and the extensions registered for `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the binding fails, or if its value cannot be cast to the requested type.
+    pub fn resolve(&self, container: &Container) -> Result<T, Error> {
+        let value = self.source.produce(container)?;
+
+        container
+            .extend_any(TypeId::of::<T>(), value)?
+            .downcast::<T>()
+            .map(|value| *value)
+            .map_err(|_| Error::CastFailed)
+    }
+}
+
+impl Source {
+    /// Find the shared instance or binding registered for the given type, falling back to an `Arc` of a singleton.
+    pub(crate) fn lookup(container: &Container, type_id: TypeId) -> Option<Self> {
+        container
+            .bindings
+            .get(&type_id)
+            .map(|registration| registration.source())
+            .or_else(|| container.shared_arc(type_id).map(Self::Instance))
+    }
+
+    /// Refer to the source without keeping its registration alive.
+    pub(crate) fn downgrade(&self) -> WeakSource {
+        match self {
+            Self::Instance(instance) => WeakSource::Instance(Arc::downgrade(instance)),
+            Self::Binding(binding) => WeakSource::Binding(Arc::downgrade(binding)),
+        }
+    }
+
+    /// Produce a value, using the given container for the binding's own dependencies.
+    pub(crate) fn produce(&self, container: &Container) -> Result<Box<dyn Any>, Error> {
+        match self {
+            Self::Instance(instance) => Ok(instance(container)),
+            Self::Binding(binding) => binding(container),
+        }
+    }
+}
+
+impl<T> Clone for Resolver<T> {
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source.clone(),
+            resolves: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for Resolver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Resolver")
+            .field("type", &type_name::<T>())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Container {
+    /// Create a [`Resolver`] for the given type, which can resolve it repeatedly without looking up its binding each time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no binding or shared instance is registered for the requested type.
+    pub fn resolver<T: 'static>(&self) -> Result<Resolver<T>, Error> {
+        let source = Source::lookup(self, TypeId::of::<T>()).ok_or(Error::NotFound)?;
+
+        Ok(Resolver {
+            source,
+            resolves: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_resolve_repeatedly_through_a_resolver() {
+        let mut container = Container::new();
+        container.bind(|_| 42_u32);
+        container.singleton(&|_| "shared");
+
+        let numbers = container.resolver::<u32>().unwrap();
+        let strings = container.resolver::<&str>().unwrap();
+
+        for _ in 0..3 {
+            assert_eq!(numbers.resolve(&container), Ok(42));
+            assert_eq!(strings.resolve(&container), Ok("shared"));
+        }
+    }
+
+    #[test]
+    fn validates_registration_when_created() {
+        let container = Container::new();
+
+        assert!(matches!(container.resolver::<u32>(), Err(Error::NotFound)));
+    }
+}
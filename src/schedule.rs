@@ -0,0 +1,477 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{Container, Error};
+
+/// A boxed, sendable future.
+pub type BoxFuture<'a> = Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+
+/// A service that runs periodically, driven by the container's scheduler.
+pub trait Scheduled: Send + Sync + 'static {
+    /// When the service should run.
+    fn schedule(&self) -> Schedule;
+
+    /// Run the service once.
+    fn run(&self) -> BoxFuture<'_>;
+}
+
+/// When a [`Scheduled`] service runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Schedule {
+    /// Run repeatedly, waiting for the given duration before every run.
+    Every(Duration),
+    /// Run at every minute matching the given cron expression.
+    Cron(Cron),
+}
+
+/// The async runtime the scheduler spawns its tasks on.
+pub trait Runtime: Send + Sync + 'static {
+    /// Spawn a task onto the runtime.
+    fn spawn(&self, task: BoxFuture<'static>);
+
+    /// Wait for the given duration.
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static>;
+
+    /// The current time, which cron schedules are computed from.
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Resolves a scheduled service from the container.
+pub(crate) type ScheduledResolver =
+    Arc<dyn Fn(&Container) -> Result<Arc<dyn Scheduled>, Error> + Send + Sync>;
+
+/// A handle to a running scheduler. Dropping it stops every scheduled service after its current run.
+///
+/// Schedulers also stop once the container they were started from is [shut down](Container::shutdown) or [flushed](Container::flush).
+#[must_use = "the scheduler stops as soon as its handle is dropped"]
+pub struct Scheduler {
+    /// Whether the scheduler has been stopped.
+    stopped: Arc<AtomicBool>,
+}
+
+impl Scheduler {
+    pub(crate) fn start(
+        runtime: impl Runtime,
+        services: Vec<Arc<dyn Scheduled>>,
+        halted: &Arc<AtomicBool>,
+    ) -> Self {
+        let runtime = Arc::new(runtime);
+        let stopped = Arc::new(AtomicBool::new(false));
+
+        for service in services {
+            let stopped = Arc::clone(&stopped);
+            let halted = Arc::clone(halted);
+            let clock = Arc::clone(&runtime);
+
+            runtime.spawn(Box::pin(async move {
+                loop {
+                    let delay = match service.schedule() {
+                        Schedule::Every(interval) => interval,
+                        Schedule::Cron(cron) => {
+                            let now = clock.now();
+                            let Some(next) = cron.next_after(now) else {
+                                break;
+                            };
+
+                            next.duration_since(now).unwrap_or_default()
+                        }
+                    };
+
+                    clock.sleep(delay).await;
+
+                    if stopped.load(Ordering::Acquire) || halted.load(Ordering::Acquire) {
+                        break;
+                    }
+
+                    service.run().await;
+                }
+            }));
+        }
+
+        Self { stopped }
+    }
+
+    /// Stop every scheduled service after its current run.
+    pub fn shutdown(self) {
+        drop(self);
+    }
+}
+
+impl Drop for Scheduler {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Release);
+    }
+}
+
+impl Container {
+    /// Stop every scheduler started from the container after its services' current runs, so schedulers started afterwards run as usual.
+    pub(crate) fn halt_schedulers(&mut self) {
+        self.scheduler_halt.store(true, Ordering::Release);
+        self.scheduler_halt = Arc::default();
+    }
+}
+
+/// A standard five-field cron expression (`minute hour day-of-month month day-of-week`), evaluated in UTC.
+///
+/// Each field is `*`, a number, a range (`1-5`), or a comma-separated list of them, optionally followed by a step (`*/15`, `0-30/10`). Days of the week run from `0` (Sunday) to `7` (Sunday again). Like in most cron implementations, a time matches when either the day of the month or the day of the week does, if both are restricted.
+///
+/// ```rust
+/// use silhouette::schedule::Cron;
+///
+/// let cron: Cron = "*/15 9-17 * * 1-5".parse().unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cron {
+    /// The matching minutes, as a bitmask.
+    minutes: u64,
+    /// The matching hours, as a bitmask.
+    hours: u64,
+    /// The matching days of the month, as a bitmask.
+    days: u64,
+    /// The matching months, as a bitmask.
+    months: u64,
+    /// The matching days of the week, as a bitmask starting on Sunday.
+    weekdays: u64,
+    /// Whether the day of the month field isn't `*`.
+    days_restricted: bool,
+    /// Whether the day of the week field isn't `*`.
+    weekdays_restricted: bool,
+}
+
+/// The number of days searched for the next matching time, enough to reach every day of the month on every day of the week.
+const SEARCHED_DAYS: u64 = 366 * 28;
+
+impl Cron {
+    /// The first time matching the expression strictly after the given time, or `None` if no time ever matches, such as on February 30th.
+    #[must_use]
+    pub fn next_after(&self, time: SystemTime) -> Option<SystemTime> {
+        let seconds = time.duration_since(UNIX_EPOCH).ok()?.as_secs() / 60 * 60 + 60;
+        let first_day = seconds / 86_400;
+
+        (first_day..first_day + SEARCHED_DAYS)
+            .filter(|&day| self.matches_day(day))
+            .find_map(|day| {
+                let start = if day == first_day {
+                    seconds % 86_400
+                } else {
+                    0
+                };
+
+                (start / 60..24 * 60)
+                    .find(|minute| {
+                        self.hours & 1 << (minute / 60) != 0
+                            && self.minutes & 1 << (minute % 60) != 0
+                    })
+                    .map(|minute| UNIX_EPOCH + Duration::from_secs(day * 86_400 + minute * 60))
+            })
+    }
+
+    /// Whether the given number of days since the Unix epoch matches the expression's day and month fields.
+    const fn matches_day(&self, day: u64) -> bool {
+        let (month, day_of_month) = month_and_day(day);
+        // The Unix epoch was a Thursday.
+        let weekday = (day + 4) % 7;
+
+        let days = self.days & 1 << day_of_month != 0;
+        let weekdays = self.weekdays & 1 << weekday != 0;
+        let matches_day = if self.days_restricted && self.weekdays_restricted {
+            days || weekdays
+        } else {
+            days && weekdays
+        };
+
+        matches_day && self.months & 1 << month != 0
+    }
+}
+
+/// The month (from 1) and day of the month (from 1) of the given number of days since the Unix epoch, in the proleptic Gregorian calendar.
+const fn month_and_day(day: u64) -> (u64, u64) {
+    // Shift the epoch to March 1st, 0000, so leap days fall at the end of the year.
+    let days = day + 719_468;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day_of_month = day_of_year - (153 * month + 2) / 5 + 1;
+
+    (if month < 10 { month + 3 } else { month - 9 }, day_of_month)
+}
+
+/// Parse a single cron field into a bitmask of the values it matches between `min` and `max`, returning whether it's restricted (not `*`).
+fn parse_field(field: &str, min: u64, max: u64) -> Option<(u64, bool)> {
+    let mut mask = 0;
+
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse().ok().filter(|&step| step > 0)?),
+            None => (part, 1),
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (start.parse().ok()?, end.parse().ok()?)
+        } else {
+            let value = range.parse().ok()?;
+            (value, if part.contains('/') { max } else { value })
+        };
+
+        if start < min || end > max || start > end {
+            return None;
+        }
+
+        mask |= (start..=end)
+            .step_by(step)
+            .fold(0, |mask, value| mask | 1 << value);
+    }
+
+    Some((mask, field != "*"))
+}
+
+impl FromStr for Cron {
+    type Err = ParseCronError;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        let error = || ParseCronError(expression.to_string());
+
+        let [minutes, hours, days, months, weekdays] = expression
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|_| error())?;
+
+        let (minutes, _) = parse_field(minutes, 0, 59).ok_or_else(error)?;
+        let (hours, _) = parse_field(hours, 0, 23).ok_or_else(error)?;
+        let (days, days_restricted) = parse_field(days, 1, 31).ok_or_else(error)?;
+        let (months, _) = parse_field(months, 1, 12).ok_or_else(error)?;
+        let (weekdays, weekdays_restricted) = parse_field(weekdays, 0, 7).ok_or_else(error)?;
+
+        Ok(Self {
+            minutes,
+            hours,
+            days,
+            months,
+            // Sunday can be written as either 0 or 7.
+            weekdays: (weekdays | weekdays >> 7) & 0x7f,
+            days_restricted,
+            weekdays_restricted,
+        })
+    }
+}
+
+/// An error returned when a cron expression can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Invalid cron expression `{0}`")]
+pub struct ParseCronError(String);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        sync::{
+            atomic::AtomicUsize,
+            mpsc::{self, Receiver, Sender},
+        },
+        thread,
+    };
+
+    /// A sleep started by the scheduler, along with a sender that finishes it.
+    type Sleep = (Duration, Sender<()>);
+
+    /// A runtime whose clock only moves when the test lets a sleep finish.
+    struct ManualRuntime {
+        /// Reports every sleep the scheduler starts, along with a sender that finishes it.
+        sleeps: Sender<Sleep>,
+        /// Reports every task that finished.
+        finished: Sender<()>,
+        /// The time reported to the scheduler.
+        now: SystemTime,
+    }
+
+    impl Runtime for ManualRuntime {
+        fn spawn(&self, task: BoxFuture<'static>) {
+            let finished = self.finished.clone();
+
+            // The test may have ended by the time the task finishes, so reports are allowed to go unread.
+            thread::spawn(move || {
+                futures::executor::block_on(task);
+                let _ = finished.send(());
+            });
+        }
+
+        fn sleep(&self, duration: Duration) -> BoxFuture<'static> {
+            let (wake, woken) = mpsc::channel();
+            let _ = self.sleeps.send((duration, wake));
+
+            Box::pin(async move {
+                let _ = woken.recv();
+            })
+        }
+
+        fn now(&self) -> SystemTime {
+            self.now
+        }
+    }
+
+    /// Start a manual runtime at the given time, returning it along with the receivers for its sleeps and finished tasks.
+    fn manual_runtime(now: SystemTime) -> (ManualRuntime, Receiver<Sleep>, Receiver<()>) {
+        let (sleeps, slept) = mpsc::channel();
+        let (finished, done) = mpsc::channel();
+
+        let runtime = ManualRuntime {
+            sleeps,
+            finished,
+            now,
+        };
+
+        (runtime, slept, done)
+    }
+
+    #[derive(Clone)]
+    struct Heartbeat {
+        beats: Arc<AtomicUsize>,
+        schedule: Schedule,
+    }
+
+    impl Scheduled for Heartbeat {
+        fn schedule(&self) -> Schedule {
+            self.schedule
+        }
+
+        fn run(&self) -> BoxFuture<'_> {
+            Box::pin(async move {
+                self.beats.fetch_add(1, Ordering::SeqCst);
+            })
+        }
+    }
+
+    fn heartbeat(container: &mut Container, schedule: Schedule) -> Arc<AtomicUsize> {
+        let beats = Arc::new(AtomicUsize::new(0));
+
+        let counter = Arc::clone(&beats);
+        container.bind(move |_| Heartbeat {
+            beats: Arc::clone(&counter),
+            schedule,
+        });
+        container.schedule::<Heartbeat>();
+
+        beats
+    }
+
+    #[test]
+    fn runs_scheduled_services_until_shutdown() {
+        let mut container = Container::new();
+        let beats = heartbeat(&mut container, Schedule::Every(Duration::from_secs(5)));
+        let (runtime, sleeps, finished) = manual_runtime(UNIX_EPOCH);
+
+        let scheduler = container.start_scheduler(runtime).unwrap();
+        for _ in 0..3 {
+            let (duration, wake) = sleeps.recv().unwrap();
+            assert_eq!(duration, Duration::from_secs(5));
+            wake.send(()).unwrap();
+        }
+
+        let (_, wake) = sleeps.recv().unwrap();
+        scheduler.shutdown();
+        wake.send(()).unwrap();
+
+        finished.recv().unwrap();
+        assert_eq!(beats.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn stops_when_the_container_shuts_down() {
+        let mut container = Container::new();
+        let beats = heartbeat(&mut container, Schedule::Every(Duration::from_secs(5)));
+        let (runtime, sleeps, finished) = manual_runtime(UNIX_EPOCH);
+
+        let _scheduler = container.start_scheduler(runtime).unwrap();
+        let (_, wake) = sleeps.recv().unwrap();
+        container.shutdown();
+        wake.send(()).unwrap();
+
+        finished.recv().unwrap();
+        assert_eq!(beats.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn waits_for_the_next_cron_match() {
+        let mut container = Container::new();
+        heartbeat(
+            &mut container,
+            Schedule::Cron("*/15 * * * *".parse().unwrap()),
+        );
+        // 1970-01-01 00:07:30 UTC.
+        let (runtime, sleeps, _finished) = manual_runtime(UNIX_EPOCH + Duration::from_secs(450));
+
+        let _scheduler = container.start_scheduler(runtime).unwrap();
+
+        let (duration, _wake) = sleeps.recv().unwrap();
+        assert_eq!(duration, Duration::from_secs(450));
+    }
+
+    #[test]
+    fn finds_the_next_matching_time() {
+        let at = |seconds| UNIX_EPOCH + Duration::from_secs(seconds);
+        let next = |expression: &str, seconds| {
+            expression
+                .parse::<Cron>()
+                .unwrap()
+                .next_after(at(seconds))
+                .map(|time| time.duration_since(UNIX_EPOCH).unwrap().as_secs())
+        };
+
+        // 2024-02-28 23:59:00 UTC, a Wednesday.
+        let wednesday = 1_709_164_740;
+        assert_eq!(next("* * * * *", wednesday), Some(wednesday + 60));
+        // Midnight on the leap day.
+        assert_eq!(next("0 0 29 2 *", wednesday), Some(wednesday + 60));
+        // 09:30 on the following Monday, March 4th.
+        assert_eq!(next("30 9 * * 1", wednesday), Some(1_709_544_600));
+        // Either the 1st of the month or a Sunday, whichever comes first: Friday, March 1st.
+        assert_eq!(next("0 12 1 * 0", wednesday), Some(1_709_294_400));
+        assert_eq!(next("0 0 30 2 *", wednesday), None);
+    }
+
+    #[test]
+    fn rejects_invalid_expressions() {
+        for expression in [
+            "* * * *",
+            "60 * * * *",
+            "* * 0 * *",
+            "*/0 * * * *",
+            "5-1 * * * *",
+            "a * * * *",
+        ] {
+            assert_eq!(
+                expression.parse::<Cron>(),
+                Err(ParseCronError(expression.to_string()))
+            );
+        }
+        assert_eq!("* * * * 7".parse::<Cron>(), "* * * * 0".parse::<Cron>());
+    }
+
+    #[test]
+    fn fails_to_start_when_a_service_cannot_be_resolved() {
+        let mut container = Container::new();
+        let (runtime, _sleeps, _finished) = manual_runtime(UNIX_EPOCH);
+
+        container.schedule::<Heartbeat>();
+
+        assert!(matches!(
+            container.start_scheduler(runtime),
+            Err(Error::NotFound)
+        ));
+    }
+}
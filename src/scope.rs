@@ -0,0 +1,452 @@
+use std::{
+    any::{Any, TypeId},
+    future::Future,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use crate::{
+    arena::Arena,
+    events::ContainerEvent,
+    hash::TypeMap,
+    interface::ContainerInterface,
+    task::{self, TaskScoped},
+    Binding, Container, Error, Instance,
+};
+
+/// The values cached by a scope, constructed from the container's connection-scoped bindings, along with the bindings registered in it.
+#[derive(Default)]
+pub(crate) struct ScopedInstances(Mutex<TypeMap<Binding>>);
+
+impl ScopedInstances {
+    /// Resolve the given type, constructing it once for this scope if it's connection-scoped, and falling back to the container otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the requested type cannot be found or if the requested type cannot be cast from the binding.
+    pub(crate) fn resolve<T: 'static>(&self, container: &Container) -> Result<T, Error> {
+        let Some(value) = self.resolve_any(TypeId::of::<T>(), container) else {
+            return container.resolve();
+        };
+
+        value?
+            .downcast::<T>()
+            .map(|value| *value)
+            .map_err(|_| Error::CastFailed)
+    }
+
+    /// Resolve the given type from this scope, constructing it once if it's connection-scoped, or return `None` if the scope doesn't handle it.
+    pub(crate) fn resolve_any(
+        &self,
+        type_id: TypeId,
+        container: &Container,
+    ) -> Option<Result<Box<dyn Any>, Error>> {
+        if let Some(binding) = self.lock().get(&type_id).cloned() {
+            return Some(binding(container));
+        }
+
+        let factory = container.connection_bindings.get(&type_id)?;
+
+        // The factory runs without holding the lock, so a slow constructor doesn't block other resolutions in this scope.
+        let instance = factory(container);
+        let mut instances = self.lock();
+        let created = !instances.contains_key(&type_id);
+        let binding = Arc::clone(
+            instances
+                .entry(type_id)
+                .or_insert_with(|| Arc::new(move |container: &Container| Ok(instance(container)))),
+        );
+        drop(instances);
+
+        if created {
+            container.emit(ContainerEvent::ScopedInstanceCreated { type_id });
+        }
+
+        Some(binding(container))
+    }
+
+    /// Store a value for this scope, replacing any value cached for its type.
+    pub(crate) fn insert<T: 'static + Clone + Send + Sync>(&self, value: T) {
+        self.insert_instance(
+            TypeId::of::<T>(),
+            Arc::new(move |_: &Container| Box::new(value.clone()) as Box<dyn Any>),
+        );
+    }
+
+    /// Store a type-erased value for this scope, replacing any value cached for its type.
+    fn insert_instance(&self, type_id: TypeId, instance: Instance) {
+        self.insert_binding(
+            type_id,
+            Arc::new(move |container: &Container| Ok(instance(container))),
+        );
+    }
+
+    /// Register a type-erased binding in this scope alone, replacing any value cached for its type.
+    fn insert_binding(&self, type_id: TypeId, binding: Binding) {
+        self.lock().insert(type_id, binding);
+    }
+
+    /// Drop every cached value, returning their types.
+    pub(crate) fn clear(&self) -> Vec<TypeId> {
+        let instances = std::mem::take(&mut *self.lock());
+        let types = instances.keys().copied().collect();
+        drop(instances);
+
+        types
+    }
+
+    fn lock(&self) -> MutexGuard<'_, TypeMap<Binding>> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// A child scope of a [`Container`], for the lifetime of a single request or job.
+///
+/// Scopes are created with [`Container::create_scope`]. They resolve everything the container can, but types registered with [`Container::connection_scoped`] are constructed once per scope, and values can be stored in the scope alone with [`Scope::instance`]. Every value cached by the scope is dropped when the scope ends.
+///
+/// ```rust
+/// use silhouette::Container;
+///
+/// #[derive(Clone)]
+/// struct RequestId(u64);
+///
+/// let mut container = Container::new();
+/// container.bind(|_| "postgres://localhost");
+///
+/// let scope = container.create_scope();
+/// scope.instance(RequestId(42));
+///
+/// assert_eq!(scope.resolve::<RequestId>().map(|id| id.0), Ok(42));
+/// assert_eq!(scope.resolve::<&str>(), Ok("postgres://localhost"));
+/// assert!(container.resolve::<RequestId>().is_err());
+/// ```
+pub struct Scope<'a> {
+    /// The container the scope belongs to.
+    container: &'a Container,
+    /// The values cached by the scope, shared with its handles.
+    instances: Arc<ScopedInstances>,
+    /// The callbacks run when the scope ends, in the order they were registered.
+    terminating: Mutex<Vec<Terminating<'a>>>,
+    /// The values resolved with [`Scope::resolve_ref`], released when the scope ends.
+    arena: Arena,
+}
+
+/// A callback run with a [`Scope`] when it ends.
+type Terminating<'a> = Box<dyn FnOnce(&Scope<'a>) + Send + 'a>;
+
+impl<'a> Scope<'a> {
+    /// Resolve the given type in this scope.
+    ///
+    /// Values stored in the scope are returned first. Connection-scoped types are constructed the first time they're resolved in this scope, and every other type is resolved from the container as usual.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the requested type cannot be found or if the requested type cannot be cast from the binding.
+    pub fn resolve<T: 'static + Clone + Send + Sync>(&self) -> Result<T, Error> {
+        self.instances.resolve(self.container)
+    }
+
+    /// Resolve the given type in this scope into the scope's [arena](Arena), returning a reference that lives as long as the scope.
+    ///
+    /// Unlike [`Scope::resolve`], the type doesn't need to be [`Clone`], and the values are dropped together when the scope ends.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the requested type cannot be found or if the requested type cannot be cast from the binding.
+    pub fn resolve_ref<T: 'static + Send + Sync>(&self) -> Result<&T, Error> {
+        Ok(self.arena.alloc(self.instances.resolve(self.container)?))
+    }
+
+    /// Store a value in this scope, so resolving its type in the scope returns it.
+    pub fn instance<T: 'static + Clone + Send + Sync>(&self, value: T) {
+        self.instances.insert(value);
+        self.container.emit(ContainerEvent::ScopedInstanceCreated {
+            type_id: TypeId::of::<T>(),
+        });
+    }
+
+    /// Create a handle to this scope that can be moved to other threads or tasks, so background work launched from the scope keeps resolving its values.
+    ///
+    /// Handles are cheap to clone, and keep the scope's values alive until the last one is dropped, even if the scope itself ends first.
+    #[must_use]
+    pub fn handle(&self) -> ScopeHandle {
+        ScopeHandle {
+            instances: Arc::clone(&self.instances),
+        }
+    }
+
+    /// Register a callback that runs when this scope ends, such as flushing a request's logs or committing its unit of work.
+    ///
+    /// Callbacks run exactly once, in the order they were registered, and before the scope's values are dropped, so they can still resolve them from the scope.
+    pub fn on_terminate(&self, callback: impl FnOnce(&Self) + Send + 'a) {
+        self.terminating
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(Box::new(callback));
+    }
+}
+
+/// A cloneable, [`Send`] handle to a [`Scope`], created with [`Scope::handle`].
+///
+/// Handles re-enter the scope on another thread or task: they resolve the scope's values directly, or make the scope active for the [facade](crate::facade::Container) while a closure or future runs.
+///
+/// ```rust
+/// use silhouette::{facade::Container, Container as Local};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct RequestId(u64);
+///
+/// let container = Local::new();
+/// let scope = container.create_scope();
+/// scope.instance(RequestId(42));
+///
+/// let handle = scope.handle();
+/// let worker = std::thread::spawn(move || handle.run(|| Container::resolve::<RequestId>()));
+///
+/// assert_eq!(worker.join().unwrap(), Ok(RequestId(42)));
+/// ```
+#[derive(Clone)]
+pub struct ScopeHandle {
+    /// The values cached by the scope.
+    instances: Arc<ScopedInstances>,
+}
+
+impl ScopeHandle {
+    /// Resolve the given type in the scope, using the given container for everything the scope doesn't cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the requested type cannot be found or if the requested type cannot be cast from the binding.
+    pub fn resolve<T: 'static + Clone + Send + Sync>(
+        &self,
+        container: &Container,
+    ) -> Result<T, Error> {
+        self.instances.resolve(container)
+    }
+
+    /// Store a value in the scope, so resolving its type in the scope returns it.
+    pub fn instance<T: 'static + Clone + Send + Sync>(&self, value: T) {
+        self.instances.insert(value);
+    }
+
+    /// Run the given closure with the scope active on this thread, so resolving through the facade consults it first.
+    pub fn run<R>(&self, f: impl FnOnce() -> R) -> R {
+        task::run_in(Arc::clone(&self.instances), f)
+    }
+
+    /// Wrap the given future so the scope is active whenever it's polled, for spawning onto an async runtime.
+    pub fn enter<F: Future>(&self, future: F) -> TaskScoped<F> {
+        task::scope_in(Arc::clone(&self.instances), future)
+    }
+}
+
+impl Scope<'_> {
+    /// Drop every value cached by the scope, including the ones its handles see.
+    fn clear(&self) {
+        for type_id in self.instances.clear() {
+            self.container
+                .emit(ContainerEvent::ScopedInstanceDropped { type_id });
+        }
+    }
+}
+
+/// Registrations made through the interface only apply to the scope, and are dropped along with its values.
+impl ContainerInterface for Scope<'_> {
+    fn bind_erased(
+        &mut self,
+        type_id: TypeId,
+        _: &'static str,
+        binding: Binding,
+    ) -> Result<(), Error> {
+        self.instances.insert_binding(type_id, binding);
+
+        Ok(())
+    }
+
+    fn singleton_erased(
+        &mut self,
+        type_id: TypeId,
+        type_name: &'static str,
+        build: &dyn Fn(&Container) -> Instance,
+    ) -> Result<(), Error> {
+        self.scoped_erased(type_id, type_name, build)
+    }
+
+    fn scoped_erased(
+        &mut self,
+        type_id: TypeId,
+        _: &'static str,
+        build: &dyn Fn(&Container) -> Instance,
+    ) -> Result<(), Error> {
+        self.instances
+            .insert_instance(type_id, build(self.container));
+        self.container
+            .emit(ContainerEvent::ScopedInstanceCreated { type_id });
+
+        Ok(())
+    }
+
+    fn resolve_erased(&self, type_id: TypeId) -> Result<Box<dyn Any>, Error> {
+        self.instances
+            .resolve_any(type_id, self.container)
+            .unwrap_or_else(|| self.container.resolve_any(type_id))
+    }
+
+    fn forget_scoped_instances(&mut self) -> Result<(), Error> {
+        self.clear();
+
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        self.clear();
+
+        Ok(())
+    }
+}
+
+impl Drop for Scope<'_> {
+    fn drop(&mut self) {
+        let terminating = std::mem::take(
+            self.terminating
+                .get_mut()
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+        for callback in terminating {
+            callback(self);
+        }
+
+        // Values still used through a handle are dropped along with the last handle instead.
+        if Arc::get_mut(&mut self.instances).is_some() {
+            self.clear();
+        }
+
+        self.container.emit(ContainerEvent::ScopeEnded);
+    }
+}
+
+impl Container {
+    /// Begin a child scope for a single request or job.
+    ///
+    /// The scope inherits every registration from the container, and disposes of its own values when it's dropped.
+    #[must_use]
+    pub fn create_scope(&self) -> Scope<'_> {
+        self.emit(ContainerEvent::ScopeStarted);
+
+        Scope {
+            container: self,
+            instances: Arc::default(),
+            terminating: Mutex::default(),
+            arena: Arena::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    #[derive(Clone)]
+    struct Transaction(Arc<AtomicUsize>);
+
+    impl Drop for Transaction {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn scopes_cache_their_own_values_until_they_end() {
+        let dropped = Arc::new(AtomicUsize::new(0));
+
+        let mut container = Container::new();
+        let drops = Arc::clone(&dropped);
+        container.connection_scoped(move |_| Arc::new(Transaction(Arc::clone(&drops))));
+        container.bind(|_| 42_u32);
+
+        let first = container.create_scope();
+        let second = container.create_scope();
+        first.instance(7_u32);
+
+        let a = first.resolve::<Arc<Transaction>>().unwrap();
+        let b = first.resolve::<Arc<Transaction>>().unwrap();
+        let c = second.resolve::<Arc<Transaction>>().unwrap();
+
+        assert!(Arc::ptr_eq(&a, &b));
+        assert!(!Arc::ptr_eq(&a, &c));
+        assert_eq!(first.resolve::<u32>(), Ok(7));
+        assert_eq!(second.resolve::<u32>(), Ok(42));
+
+        drop((a, b, c));
+        drop(first);
+        assert_eq!(dropped.load(Ordering::SeqCst), 1);
+
+        drop(second);
+        assert_eq!(dropped.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn runs_terminating_callbacks_when_the_scope_ends() {
+        let log = Mutex::new(Vec::new());
+        let container = Container::new();
+
+        let scope = container.create_scope();
+        scope.instance(42_u32);
+        scope.on_terminate(|scope| log.lock().unwrap().push(scope.resolve::<u32>()));
+        scope.on_terminate(|_| log.lock().unwrap().push(Ok(0)));
+        assert!(log.lock().unwrap().is_empty());
+
+        drop(scope);
+        assert_eq!(*log.lock().unwrap(), vec![Ok(42), Ok(0)]);
+    }
+
+    #[test]
+    fn releases_values_resolved_into_the_scope_when_it_ends() {
+        struct Connection(Arc<AtomicUsize>);
+
+        impl Drop for Connection {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let dropped = Arc::new(AtomicUsize::new(0));
+        let mut container = Container::new();
+        let drops = Arc::clone(&dropped);
+        container.bind(move |_| Connection(Arc::clone(&drops)));
+
+        let scope = container.create_scope();
+        let first = scope.resolve_ref::<Connection>().unwrap();
+        let second = scope.resolve_ref::<Connection>().unwrap();
+        assert!(!std::ptr::eq(first, second));
+        assert_eq!(dropped.load(Ordering::SeqCst), 0);
+
+        drop(scope);
+        assert_eq!(dropped.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn handles_keep_resolving_the_scope_elsewhere() {
+        let mut container = Container::new();
+        container.connection_scoped(|_| Arc::new(AtomicUsize::new(0)));
+
+        let scope = container.create_scope();
+        let counter = scope.resolve::<Arc<AtomicUsize>>().unwrap();
+        let handle = scope.handle();
+        drop(scope);
+
+        std::thread::scope(|threads| {
+            threads.spawn(|| {
+                let shared = handle.resolve::<Arc<AtomicUsize>>(&container).unwrap();
+                shared.fetch_add(1, Ordering::SeqCst);
+            });
+        });
+
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+        assert!(handle.run(task::in_scope));
+        assert!(!task::in_scope());
+    }
+}
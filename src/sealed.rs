@@ -0,0 +1,86 @@
+use std::any::TypeId;
+
+use crate::{Container, Error};
+
+impl Container {
+    /// Seal the given type's registration, so it can no longer be replaced or forgotten.
+    ///
+    /// This lets a framework guarantee that application code can't silently override its critical services. Registering over (or forgetting) a sealed type leaves its registration in place. The facade's registration methods report the attempt as [`Error::Sealed`], and [`Container::take_refused`] reports it for registrations made directly on the container. Flushing the container removes the seal along with everything else.
+    pub fn seal<T: 'static>(&mut self) {
+        self.sealed.insert(TypeId::of::<T>());
+    }
+
+    /// Register a binding with the container, and seal it so it can't be replaced.
+    pub fn bind_final<T: 'static>(&mut self, factory: impl Fn(&Self) -> T + 'static + Sync + Send) {
+        self.bind(factory);
+        self.seal::<T>();
+    }
+
+    /// Whether the given type's registration is sealed.
+    #[must_use]
+    pub fn is_sealed<T: 'static>(&self) -> bool {
+        self.sealed.contains(&TypeId::of::<T>())
+    }
+
+    /// Check whether a registration for the given type has to be refused because it's sealed (or the container is frozen), remembering the attempt if so.
+    pub(crate) fn refuse_sealed(&mut self, type_id: TypeId) -> bool {
+        let refused = self.frozen || self.sealed.contains(&type_id);
+        if refused {
+            self.refused = true;
+        }
+
+        refused
+    }
+
+    /// Check whether a registration that doesn't replace a type's own (such as a named binding, a tag or a hook) has to be refused because the container is frozen, remembering the attempt if so.
+    pub(crate) const fn refuse_frozen(&mut self) -> bool {
+        if self.frozen {
+            self.refused = true;
+        }
+
+        self.frozen
+    }
+
+    /// Report whether any registration was refused because its type was sealed or the container [frozen](Container::freeze), since the last time this was checked.
+    ///
+    /// The container's own registration methods don't fail, so call this after registering to find out whether any of them were ignored: `container.bind(factory); container.take_refused()?;`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Frozen`] if a registration was refused and the container is frozen, or [`Error::Sealed`] if it was refused because its type was sealed.
+    pub fn take_refused(&mut self) -> Result<(), Error> {
+        if std::mem::take(&mut self.refused) {
+            return Err(if self.frozen {
+                Error::Frozen
+            } else {
+                Error::Sealed
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sealed_registrations_cannot_be_replaced() {
+        let mut container = Container::new();
+        container.bind_final(|_| "framework");
+
+        container.bind(|_| "application");
+        container.singleton(&|_| "application");
+        container.forget::<&str>();
+
+        assert!(container.is_sealed::<&str>());
+        assert_eq!(container.resolve::<&str>(), Ok("framework"));
+        assert_eq!(container.take_refused(), Err(Error::Sealed));
+        assert_eq!(container.take_refused(), Ok(()));
+
+        container.bind(|_| "application");
+        container.flush();
+        assert_eq!(container.take_refused(), Ok(()));
+    }
+}
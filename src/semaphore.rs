@@ -0,0 +1,55 @@
+use std::{
+    num::NonZeroUsize,
+    sync::{Condvar, Mutex, PoisonError},
+};
+
+/// A counting semaphore, limiting how many threads can hold a permit at once.
+pub struct Semaphore {
+    /// The number of permits currently available.
+    permits: Mutex<usize>,
+    /// Notified whenever a permit is released.
+    released: Condvar,
+}
+
+impl Semaphore {
+    pub const fn new(permits: NonZeroUsize) -> Self {
+        Self {
+            permits: Mutex::new(permits.get()),
+            released: Condvar::new(),
+        }
+    }
+
+    /// Wait until a permit is available, and take it.
+    pub fn acquire(&self) -> Permit<'_> {
+        let mut permits = self
+            .released
+            .wait_while(
+                self.permits.lock().unwrap_or_else(PoisonError::into_inner),
+                |permits| *permits == 0,
+            )
+            .unwrap_or_else(PoisonError::into_inner);
+
+        *permits -= 1;
+        drop(permits);
+
+        Permit { semaphore: self }
+    }
+}
+
+/// A permit taken from a [`Semaphore`], released when dropped.
+pub struct Permit<'a> {
+    /// The semaphore the permit was taken from.
+    semaphore: &'a Semaphore,
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        *self
+            .semaphore
+            .permits
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) += 1;
+
+        self.semaphore.released.notify_one();
+    }
+}
@@ -0,0 +1,99 @@
+use std::sync::{Arc, PoisonError, RwLock, RwLockWriteGuard};
+
+use crate::{Container, SingletonFactory};
+
+/// The value of an eagerly-constructed shared instance, which can be swapped, mutated in place, or handed out as an `Arc`.
+pub struct Shared<T> {
+    /// The current value.
+    value: RwLock<Arc<T>>,
+    /// Copies the value, for when it has to change while an `Arc` to it is still held elsewhere.
+    clone: fn(&T) -> T,
+    /// Constructs the value again when it's [refreshed](Container::refresh), for singletons registered with a factory.
+    factory: Option<SingletonFactory<T>>,
+}
+
+impl<T> Shared<T> {
+    /// Store the given value.
+    pub fn new(value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            value: RwLock::new(Arc::new(value)),
+            clone: T::clone,
+            factory: None,
+        }
+    }
+
+    /// Keep the factory the value was constructed with, so it can be constructed again.
+    pub fn with_factory(mut self, factory: Option<SingletonFactory<T>>) -> Self {
+        self.factory = factory;
+
+        self
+    }
+
+    /// The factory the value was constructed with, if it was kept.
+    pub const fn factory(&self) -> Option<&SingletonFactory<T>> {
+        self.factory.as_ref()
+    }
+
+    /// Construct the value again with its factory, replacing the current one.
+    ///
+    /// Returns `false` if the cell has no factory.
+    pub fn rebuild(&self, container: &Container) -> bool {
+        let Some(factory) = &self.factory else {
+            return false;
+        };
+
+        let value = factory(container);
+        *self.write() = Arc::new(value);
+
+        true
+    }
+
+    /// A pointer to the current value.
+    pub fn get(&self) -> Arc<T> {
+        Arc::clone(&self.value.read().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Replace the current value, returning the previous one.
+    ///
+    /// Pointers handed out before the swap keep the previous value.
+    pub fn replace(&self, value: T) -> T {
+        let previous = std::mem::replace(&mut *self.write(), Arc::new(value));
+
+        Arc::try_unwrap(previous).unwrap_or_else(|previous| (self.clone)(&previous))
+    }
+
+    /// Mutate the current value in place, copying it first if pointers to it are still held elsewhere.
+    pub fn update<R>(&self, mutate: impl FnOnce(&mut T) -> R) -> R {
+        let mut current = self.write();
+        if Arc::get_mut(&mut current).is_none() {
+            *current = Arc::new((self.clone)(&current));
+        }
+
+        let result = mutate(Arc::get_mut(&mut current).expect("the value was just copied"));
+        drop(current);
+
+        result
+    }
+
+    /// Take the value out, copying it if it's still shared elsewhere.
+    pub fn into_value(cell: Arc<Self>) -> T {
+        match Arc::try_unwrap(cell) {
+            Ok(shared) => {
+                let value = shared
+                    .value
+                    .into_inner()
+                    .unwrap_or_else(PoisonError::into_inner);
+
+                Arc::try_unwrap(value).unwrap_or_else(|value| (shared.clone)(&value))
+            }
+            Err(cell) => (cell.clone)(&cell.get()),
+        }
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Arc<T>> {
+        self.value.write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
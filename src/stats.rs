@@ -0,0 +1,196 @@
+use std::{
+    collections::{HashMap, HashSet},
+    mem,
+    sync::PoisonError,
+};
+
+use crate::Container;
+
+/// How much a container holds, reported by [`Container::stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    /// The number of bindings building a new value for every resolution.
+    pub bindings: usize,
+    /// The number of shared instances, including scoped ones.
+    pub instances: usize,
+    /// How many of the shared instances are scoped.
+    pub scoped: usize,
+    /// The approximate number of bytes allocated for the container's own tables.
+    ///
+    /// This counts the space reserved for each registration, not the factories or values it points to, so it grows with the number of registered types.
+    pub heap_bytes: usize,
+}
+
+/// A table the container keeps, whose allocation can be measured and trimmed.
+trait Table {
+    /// The number of bytes allocated for the table's entries.
+    fn heap_bytes(&self) -> usize;
+
+    /// Free the space the table holds beyond its entries.
+    fn shrink_to_fit(&mut self);
+}
+
+impl<K: Eq + std::hash::Hash, V, S: std::hash::BuildHasher> Table for HashMap<K, V, S> {
+    fn heap_bytes(&self) -> usize {
+        self.capacity() * mem::size_of::<(K, V)>()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit();
+    }
+}
+
+impl<T: Eq + std::hash::Hash, S: std::hash::BuildHasher> Table for HashSet<T, S> {
+    fn heap_bytes(&self) -> usize {
+        self.capacity() * mem::size_of::<T>()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit();
+    }
+}
+
+impl<T> Table for Vec<T> {
+    fn heap_bytes(&self) -> usize {
+        self.capacity() * mem::size_of::<T>()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit();
+    }
+}
+
+impl Container {
+    /// Count the container's registrations, and estimate how much memory its tables take up.
+    ///
+    /// Use it to audit long-running processes whose containers keep growing. Registrations inherited from a [parent](Container::with_parent) aren't included.
+    #[must_use]
+    pub fn stats(&self) -> Stats {
+        let (bindings, instances, scoped) = self.bindings.tally();
+
+        Stats {
+            bindings,
+            instances: instances + scoped,
+            scoped,
+            heap_bytes: self.bindings.heap_bytes()
+                + self.tables().map(Table::heap_bytes).sum::<usize>()
+                + self
+                    .constructed
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .heap_bytes(),
+        }
+    }
+
+    /// Free the space the container's tables hold beyond their current registrations, for example after forgetting many of them.
+    pub fn shrink_to_fit(&mut self) {
+        self.bindings.shrink_to_fit();
+        for table in self.tables_mut() {
+            table.shrink_to_fit();
+        }
+    }
+
+    /// Make room for at least `additional` more registered types, so registering them doesn't reallocate the container's tables.
+    ///
+    /// With the `concurrent` feature, only the shared instances' table is grown, since the registrations are kept in a map that can't reserve space ahead of time.
+    pub fn reserve(&mut self, additional: usize) {
+        #[cfg(not(feature = "concurrent"))]
+        self.bindings.reserve(additional);
+        self.swappable.reserve(additional);
+    }
+}
+
+/// Implement `Container::tables` and `Container::tables_mut` over the listed fields.
+///
+/// Every other field of the container has to be listed as untracked, so adding a field fails to compile until it's sorted into one of the two lists.
+macro_rules! tables {
+    (tables: [$($table:ident),* $(,)?], untracked: [$($untracked:ident),* $(,)?] $(,)?) => {
+        impl Container {
+            /// The container's tables, besides its registrations and the order its instances were constructed in.
+            fn tables(&self) -> impl Iterator<Item = &dyn Table> {
+                let Self { $($table,)* $($untracked: _,)* } = self;
+
+                [$($table as &dyn Table),*].into_iter()
+            }
+
+            /// The container's tables, mutably.
+            fn tables_mut(&mut self) -> impl Iterator<Item = &mut dyn Table> {
+                let Self { $($table,)* $($untracked: _,)* } = self;
+
+                [$($table as &mut dyn Table),*].into_iter()
+            }
+        }
+    };
+}
+
+tables! {
+    tables: [
+        named_scopes,
+        lazy_instances,
+        swappable,
+        copiers,
+        teardowns,
+        async_shutdown_hooks,
+        arcs,
+        emplacers,
+        providers,
+        eager,
+        boot_callbacks,
+        async_boot_hooks,
+        scheduled,
+        pools,
+        thread_scoped,
+        connection_bindings,
+        sealed,
+        multi_bindings,
+        named_bindings,
+        parameterized,
+        per_key,
+        extenders,
+        rebinding_callbacks,
+        tags,
+        metadata,
+    ],
+    untracked: [
+        bindings,
+        pending_teardowns,
+        constructed,
+        booted,
+        refused,
+        frozen,
+        scheduler_halt,
+        resolving_hooks,
+        after_resolving_hooks,
+        missing_handler,
+        parent,
+        inherited,
+        version,
+        subscribers,
+    ],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_registrations_and_trims_tables() {
+        let mut container = Container::new();
+        container.reserve(64);
+        let reserved = container.stats().heap_bytes;
+
+        container.bind(|_| 42_u32);
+        container.singleton(&|_| 7_u64);
+        container.scoped(&|_| 1_u8);
+
+        let stats = container.stats();
+        assert_eq!((stats.bindings, stats.instances, stats.scoped), (1, 2, 1));
+
+        container.forget::<u64>();
+        container.shrink_to_fit();
+
+        let trimmed = container.stats();
+        assert_eq!((trimmed.bindings, trimmed.instances), (1, 1));
+        assert!(trimmed.heap_bytes < reserved);
+    }
+}
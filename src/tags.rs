@@ -0,0 +1,130 @@
+use std::{
+    any::{Any, TypeId},
+    sync::Arc,
+};
+
+use crate::{multi::insert_by_priority, Container, Error};
+
+impl Container {
+    /// Add the given type to a tag, so it's included when the tag is resolved with [`Container::resolve_tagged`].
+    ///
+    /// The type is looked up when the tag is resolved, so it can be registered (or re-bound) after being tagged.
+    pub fn tag<T: 'static>(&mut self, tag: &'static str) {
+        self.tag_with_priority::<T>(tag, 0);
+    }
+
+    /// Add the given type to a tag, ordered by priority among the tag's other types.
+    ///
+    /// [`Container::resolve_tagged`] returns higher-priority types first. Types tagged with [`Container::tag`] or [`Container::tag_trait`] have a priority of `0`.
+    pub fn tag_with_priority<T: 'static>(&mut self, tag: &'static str, priority: i32) {
+        if self.refuse_frozen() {
+            return;
+        }
+
+        insert_by_priority(
+            self.tags.entry(tag).or_default(),
+            priority,
+            Arc::new(|container: &Self| container.resolve_any(TypeId::of::<T>())),
+        );
+    }
+
+    /// Add the implementation `C` to a tag as the trait object `I`, so resolving the tag as `Arc<I>` includes it.
+    ///
+    /// This lets a tag collect different implementations of the same trait. As with [`Container::bind_trait`], the conversion is always the identity closure: `container.tag_trait::<dyn Renderer, PdfRenderer>("renderers", |renderer| renderer)`.
+    pub fn tag_trait<I: ?Sized + 'static, C: 'static>(
+        &mut self,
+        tag: &'static str,
+        upcast: fn(Arc<C>) -> Arc<I>,
+    ) {
+        if self.refuse_frozen() {
+            return;
+        }
+
+        insert_by_priority(
+            self.tags.entry(tag).or_default(),
+            0,
+            Arc::new(move |container: &Self| {
+                let implementation = container.resolve::<C>()?;
+
+                Ok(Box::new(upcast(Arc::new(implementation))) as Box<dyn Any>)
+            }),
+        );
+    }
+
+    /// Resolve every type under the given tag, from highest to lowest priority, and in the order they were tagged within a priority.
+    ///
+    /// Resolving a tag that has no types returns an empty list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the tagged types cannot be resolved, or cannot be cast to the requested type.
+    pub fn resolve_tagged<T: 'static>(&self, tag: &str) -> Result<Vec<T>, Error> {
+        self.tags
+            .get(tag)
+            .into_iter()
+            .flatten()
+            .map(|(_, binding)| {
+                binding(self)?
+                    .downcast::<T>()
+                    .map(|value| *value)
+                    .map_err(|_| Error::CastFailed)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    trait Renderer: Send + Sync {
+        fn format(&self) -> &'static str;
+    }
+
+    struct PdfRenderer;
+    impl Renderer for PdfRenderer {
+        fn format(&self) -> &'static str {
+            "pdf"
+        }
+    }
+
+    struct CsvRenderer;
+    impl Renderer for CsvRenderer {
+        fn format(&self) -> &'static str {
+            "csv"
+        }
+    }
+
+    #[test]
+    fn resolves_every_type_under_a_tag() {
+        let mut container = Container::new();
+        container.tag_trait::<dyn Renderer, PdfRenderer>("renderers", |renderer| renderer);
+        container.tag_trait::<dyn Renderer, CsvRenderer>("renderers", |renderer| renderer);
+        container.bind(|_| PdfRenderer);
+        container.bind(|_| CsvRenderer);
+
+        let formats = container
+            .resolve_tagged::<Arc<dyn Renderer>>("renderers")
+            .unwrap()
+            .iter()
+            .map(|renderer| renderer.format())
+            .collect::<Vec<_>>();
+
+        assert_eq!(formats, vec!["pdf", "csv"]);
+        assert!(container.resolve_tagged::<u32>("empty").unwrap().is_empty());
+    }
+
+    #[test]
+    fn tagged_types_must_match_the_requested_type() {
+        let mut container = Container::new();
+        container.bind(|_| 42_u32);
+        container.tag::<u32>("numbers");
+
+        assert_eq!(container.resolve_tagged::<u32>("numbers"), Ok(vec![42]));
+
+        assert_eq!(
+            container.resolve_tagged::<u64>("numbers"),
+            Err(Error::CastFailed)
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use crate::{scope::ScopedInstances, Container, Error};
+
+thread_local! {
+    /// The scope of the task currently being polled on this thread, if any.
+    static CURRENT: RefCell<Option<Arc<ScopedInstances>>> = const { RefCell::new(None) };
+}
+
+/// A future running inside its own task scope, created with [`scope`].
+///
+/// The scope is made active every time the future is polled, so it follows the task across `.await` points (and across threads, on work-stealing runtimes like Tokio).
+pub struct TaskScoped<F> {
+    /// The wrapped future.
+    future: Pin<Box<F>>,
+    /// The values cached for the task.
+    instances: Arc<ScopedInstances>,
+}
+
+impl<F: Future> Future for TaskScoped<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let _active = Activate::new(Arc::clone(&self.instances));
+
+        self.future.as_mut().poll(cx)
+    }
+}
+
+/// Makes a task's scope active on this thread, restoring the previously active one when dropped.
+struct Activate(Option<Arc<ScopedInstances>>);
+
+impl Activate {
+    fn new(instances: Arc<ScopedInstances>) -> Self {
+        Self(CURRENT.with_borrow_mut(|current| current.replace(instances)))
+    }
+}
+
+impl Drop for Activate {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        CURRENT.with_borrow_mut(|current| *current = previous);
+    }
+}
+
+/// Run the given future in a new task scope, for the lifetime of a single request or job in an async server.
+///
+/// While the future runs, [`facade::Container::resolve`](crate::facade::Container::resolve) consults the task's scope first: values stored with [`instance`] are returned, and types registered with [`Container::connection_scoped`] are constructed once per task. Everything is dropped when the future completes (or is dropped).
+///
+/// This works with any executor, including Tokio:
+///
+/// ```rust
+/// use silhouette::{facade::Container, task};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct RequestId(u64);
+///
+/// async fn handle() -> Result<RequestId, silhouette::facade::Error> {
+///     Container::resolve::<RequestId>()
+/// }
+///
+/// let request = task::scope(async {
+///     task::instance(RequestId(42));
+///
+///     handle().await
+/// });
+///
+/// assert_eq!(futures::executor::block_on(request), Ok(RequestId(42)));
+/// ```
+pub fn scope<F: Future>(future: F) -> TaskScoped<F> {
+    scope_in(Arc::default(), future)
+}
+
+/// Run the given future with the given scope active whenever it's polled.
+pub(crate) fn scope_in<F: Future>(instances: Arc<ScopedInstances>, future: F) -> TaskScoped<F> {
+    TaskScoped {
+        future: Box::pin(future),
+        instances,
+    }
+}
+
+/// Run the given closure with the given scope active on this thread.
+pub(crate) fn run_in<R>(instances: Arc<ScopedInstances>, f: impl FnOnce() -> R) -> R {
+    let _active = Activate::new(instances);
+
+    f()
+}
+
+/// Store a value in the current task's scope, so resolving its type through the facade returns it for the rest of the task.
+///
+/// Returns `false` (dropping the value) if no task scope is active.
+pub fn instance<T: 'static + Clone + Send + Sync>(value: T) -> bool {
+    CURRENT.with_borrow(|current| {
+        current
+            .as_ref()
+            .map(|instances| instances.insert(value))
+            .is_some()
+    })
+}
+
+/// Whether a task scope is active on this thread.
+#[must_use]
+pub fn in_scope() -> bool {
+    CURRENT.with_borrow(Option::is_some)
+}
+
+/// Resolve the given type from the current task's scope, or return `None` if there's no active scope or it doesn't handle the type.
+pub(crate) fn resolve_any(
+    container: &Container,
+    type_id: TypeId,
+) -> Option<Result<Box<dyn Any>, Error>> {
+    let instances = CURRENT.with_borrow(Clone::clone)?;
+
+    instances.resolve_any(type_id, container)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn task_scopes_are_only_active_while_their_future_runs() {
+        assert!(!in_scope());
+        assert!(!instance(1_u32));
+
+        let container = Container::new();
+        let result = futures::executor::block_on(scope(async {
+            assert!(instance(7_u32));
+            futures::future::ready(()).await;
+
+            resolve_any(&container, TypeId::of::<u32>())
+                .and_then(|value| value.ok()?.downcast::<u32>().ok())
+                .map(|value| *value)
+        }));
+
+        assert_eq!(result, Some(7));
+        assert!(!in_scope());
+    }
+}
@@ -0,0 +1,275 @@
+use std::{
+    any::TypeId,
+    future::Future,
+    sync::{Arc, PoisonError},
+};
+
+use crate::{
+    boot::async_hook, call::AsyncCallable, events::ContainerEvent, shared::Shared, Container, Error,
+};
+
+/// A cleanup callback for a shared instance, run with its current value when the instance is removed.
+pub type Teardown = Arc<dyn Fn() + Sync + Send>;
+
+impl Container {
+    /// Register a shared binding, along with a cleanup callback that runs when the instance is removed.
+    ///
+    /// The callback receives the instance's current value when it's removed by [`Container::flush`], [`Container::forget`], [`Container::forget_scoped_instances`] or [`Container::forget_scope`], or replaced by another registration. This gives resources like sockets or buffered writers a chance to close cleanly: `container.singleton_with_drop(&|_| Client::connect(), |client| client.shutdown())`.
+    pub fn singleton_with_drop<T: 'static + Clone + Send + Sync>(
+        &mut self,
+        factory: &(impl Fn(&Self) -> T + 'static),
+        teardown: impl Fn(&T) + 'static + Sync + Send,
+    ) {
+        let type_id = TypeId::of::<T>();
+        if self.refuse_sealed(type_id) {
+            return;
+        }
+
+        self.singleton(factory);
+
+        let Some(cell) = self
+            .swappable
+            .get(&type_id)
+            .and_then(|cell| Arc::clone(cell).downcast::<Shared<T>>().ok())
+        else {
+            return;
+        };
+
+        self.teardowns
+            .insert(type_id, Arc::new(move || teardown(&cell.get())));
+    }
+
+    /// Shut the container down, tearing its shared instances down in the reverse of the order they were constructed.
+    ///
+    /// Schedulers started from the container are stopped first. Each instance's cleanup callback runs (and its value is dropped) before those of the instances constructed earlier, so a database outlives the services that were built on top of it. The container is [flushed](Container::flush) afterwards.
+    pub fn shutdown(&mut self) {
+        self.emit(ContainerEvent::Shutdown);
+        self.halt_schedulers();
+
+        for type_id in self.constructed_order().into_iter().rev() {
+            self.tear_down(type_id);
+            self.bindings.remove_instance(&type_id);
+            self.swappable.remove(&type_id);
+            self.arcs.retain(|_, (owner, _)| *owner != type_id);
+            if let Some((_, reset)) = self.lazy_instances.remove(&type_id) {
+                reset();
+            }
+        }
+
+        self.flush();
+    }
+
+    /// Register an async function that runs when the container is shut down with [`Container::shutdown_async`], such as closing connections gracefully.
+    ///
+    /// The function's arguments are resolved from the container before it's shut down, and hooks are awaited one at a time, in the reverse of the order they were registered.
+    pub fn terminating_async<Args, F>(&mut self, hook: F)
+    where
+        F: AsyncCallable<Args> + Clone + 'static + Sync + Send,
+        F::Future: Future<Output = ()> + Send + 'static,
+    {
+        if self.refuse_frozen() {
+            return;
+        }
+
+        self.async_shutdown_hooks.push(async_hook(hook));
+    }
+
+    /// Await the hooks registered with [`Container::terminating_async`], then shut the container down like [`Container::shutdown`].
+    ///
+    /// This doesn't depend on any particular async runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error raised while resolving a hook's arguments. The remaining hooks still run, and the container is shut down regardless.
+    pub async fn shutdown_async(&mut self) -> Result<(), Error> {
+        let mut result = Ok(());
+
+        for hook in std::mem::take(&mut self.async_shutdown_hooks)
+            .into_iter()
+            .rev()
+        {
+            match hook(self) {
+                Ok(future) => future.await,
+                Err(error) => result = result.and(Err(error)),
+            }
+        }
+
+        self.shutdown();
+
+        result
+    }
+
+    /// Record that the value of the given shared instance was just constructed.
+    pub(crate) fn constructed(&self, type_id: TypeId) {
+        let mut constructed = self
+            .constructed
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        constructed.retain(|constructed| *constructed != type_id);
+        constructed.push(type_id);
+    }
+
+    /// The shared instances, in the order their values were constructed.
+    pub(crate) fn constructed_order(&self) -> Vec<TypeId> {
+        self.constructed
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Run the cleanup callback registered for the given type, if any, and discard it.
+    ///
+    /// Inside a [batch](Container::register_batch), the callback is held back until the batch succeeds.
+    pub(crate) fn tear_down(&mut self, type_id: TypeId) {
+        let Some(teardown) = self.teardowns.remove(&type_id) else {
+            return;
+        };
+
+        match &mut self.pending_teardowns {
+            Some(pending) => pending.push(teardown),
+            None => teardown(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bindings::Registration;
+    use std::sync::Mutex;
+
+    #[derive(Clone)]
+    struct Connection(&'static str);
+
+    fn recorder() -> (
+        Arc<Mutex<Vec<&'static str>>>,
+        impl Fn(&Connection) + Sync + Send,
+    ) {
+        let closed = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&closed);
+
+        (closed, move |connection: &Connection| {
+            log.lock().unwrap().push(connection.0);
+        })
+    }
+
+    #[test]
+    fn runs_teardown_when_the_instance_is_removed() {
+        let (closed, teardown) = recorder();
+        let mut container = Container::new();
+
+        container.singleton_with_drop(&|_| Connection("db"), teardown);
+        assert!(closed.lock().unwrap().is_empty());
+
+        container.swap(Connection("replica"));
+        container.forget::<Connection>();
+        assert_eq!(*closed.lock().unwrap(), vec!["replica"]);
+
+        container.forget::<Connection>();
+        container.flush();
+        assert_eq!(closed.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn runs_teardown_when_the_scope_ends_or_the_container_is_flushed() {
+        let (closed, teardown) = recorder();
+        let mut container = Container::new();
+
+        container.singleton_with_drop(&|_| Connection("request"), teardown);
+        let instance = container.bindings.instance(&TypeId::of::<Connection>());
+        container.bindings.insert(
+            TypeId::of::<Connection>(),
+            Registration::Scoped(instance.unwrap()),
+        );
+        container.forget_scoped_instances();
+        assert_eq!(*closed.lock().unwrap(), vec!["request"]);
+
+        let (closed, teardown) = recorder();
+        container.singleton_with_drop(&|_| Connection("first"), teardown);
+        container.singleton(&|_| Connection("second"));
+        container.flush();
+        assert_eq!(*closed.lock().unwrap(), vec!["first"]);
+    }
+
+    #[test]
+    fn defers_teardown_until_a_batch_succeeds() {
+        let (closed, teardown) = recorder();
+        let mut container = Container::new();
+
+        container.singleton_with_drop(&|_| Connection("db"), teardown);
+        let result = container.register_batch(|tx| {
+            tx.singleton(&|_| Connection("replica"));
+            assert!(closed.lock().unwrap().is_empty());
+
+            Err(Error::NotFound)
+        });
+
+        assert_eq!(result, Err(Error::NotFound));
+        assert!(closed.lock().unwrap().is_empty());
+        assert_eq!(container.resolve::<Connection>().map(|c| c.0), Ok("db"));
+
+        container
+            .register_batch(|tx| {
+                tx.singleton(&|_| Connection("replica"));
+
+                Ok::<_, Error>(())
+            })
+            .unwrap();
+        assert_eq!(*closed.lock().unwrap(), vec!["db"]);
+    }
+
+    #[test]
+    fn shuts_down_in_reverse_construction_order() {
+        #[derive(Clone)]
+        struct Service;
+
+        struct Repository(Arc<Mutex<Vec<&'static str>>>);
+        impl Drop for Repository {
+            fn drop(&mut self) {
+                self.0.lock().unwrap().push("repository");
+            }
+        }
+
+        let (closed, teardown) = recorder();
+        let mut container = Container::new();
+
+        let log = Arc::clone(&closed);
+        container.singleton_lazy(move |_| Arc::new(Repository(Arc::clone(&log))));
+        container.singleton_with_drop(&|_| Connection("database"), teardown);
+        drop(container.resolve::<Arc<Repository>>().unwrap());
+
+        let log = Arc::clone(&closed);
+        container.singleton_with_drop(&|_| Service, move |_| {
+            log.lock().unwrap().push("service");
+        });
+        container.shutdown();
+
+        assert_eq!(
+            *closed.lock().unwrap(),
+            vec!["service", "repository", "database"]
+        );
+        assert!(container.resolve::<Connection>().is_err());
+    }
+
+    #[test]
+    fn awaits_async_shutdown_hooks_before_shutting_down() {
+        let (closed, teardown) = recorder();
+        let mut container = Container::new();
+        container.singleton_with_drop(&|_| Connection("database"), teardown);
+
+        let log = Arc::clone(&closed);
+        container.terminating_async(move |connection: Connection| {
+            let log = Arc::clone(&log);
+
+            async move { log.lock().unwrap().push(connection.0) }
+        });
+        container.terminating_async(|_: u32| async {});
+
+        assert_eq!(
+            futures::executor::block_on(container.shutdown_async()),
+            Err(Error::NotFound)
+        );
+        assert_eq!(*closed.lock().unwrap(), vec!["database", "database"]);
+    }
+}
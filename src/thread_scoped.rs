@@ -0,0 +1,97 @@
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::HashMap,
+    sync::Arc,
+};
+
+use crate::{cache, Container};
+
+thread_local! {
+    /// The values of thread-scoped bindings constructed on this thread, keyed by their registration.
+    static THREAD_SCOPED: RefCell<HashMap<u64, Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+impl Container {
+    /// Register a binding that is constructed once per thread, until the thread's scope ends.
+    ///
+    /// This is a thread-local alternative to [`Container::scoped`]: each thread resolving the type gets its own value, so threads handling different requests through the [facade](crate::facade::Container) don't see each other's scoped services. Calling [`Container::forget_scoped_instances`] ends the scope of the calling thread only, and a thread's values are also dropped when it exits.
+    pub fn thread_scoped<T: 'static + Clone>(
+        &mut self,
+        factory: impl Fn(&Self) -> T + 'static + Sync + Send,
+    ) {
+        let type_id = TypeId::of::<T>();
+        if self.refuse_sealed(type_id) {
+            return;
+        }
+
+        let registration = cache::next_version();
+
+        self.insert_binding(
+            type_id,
+            Arc::new(move |container: &Self| {
+                let cached = THREAD_SCOPED.with_borrow(|values| {
+                    values
+                        .get(&registration)
+                        .and_then(|value| value.downcast_ref::<T>())
+                        .cloned()
+                });
+
+                // The factory runs without borrowing the thread's values, so it can resolve other thread-scoped types.
+                let value = cached.unwrap_or_else(|| {
+                    let value = factory(container);
+                    THREAD_SCOPED.with_borrow_mut(|values| {
+                        values.insert(registration, Box::new(value.clone()));
+                    });
+
+                    value
+                });
+
+                Ok(Box::new(value) as Box<dyn Any>)
+            }),
+        );
+
+        self.thread_scoped.insert(type_id, registration);
+    }
+
+    /// Drop the values of this container's thread-scoped bindings constructed on the calling thread.
+    pub(crate) fn forget_thread_scoped_instances(&self) {
+        THREAD_SCOPED.with_borrow_mut(|values| {
+            for registration in self.thread_scoped.values() {
+                values.remove(registration);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn thread_scoped_values_are_kept_per_thread() {
+        let built = Arc::new(AtomicUsize::new(0));
+
+        let mut container = Container::new();
+        let counter = Arc::clone(&built);
+        container.thread_scoped(move |_| counter.fetch_add(1, Ordering::SeqCst));
+
+        let first = container.resolve::<usize>().unwrap();
+        assert_eq!(container.resolve::<usize>(), Ok(first));
+        assert!(container.is_scoped::<usize>());
+
+        let other = std::thread::scope(|scope| {
+            scope
+                .spawn(|| container.resolve::<usize>().unwrap())
+                .join()
+                .unwrap()
+        });
+        assert_ne!(other, first);
+
+        container.forget_scoped_instances();
+        assert_eq!(built.load(Ordering::SeqCst), 2);
+        assert_ne!(container.resolve::<usize>(), Ok(first));
+        assert_eq!(built.load(Ordering::SeqCst), 3);
+    }
+}
@@ -0,0 +1,88 @@
+use std::{any::TypeId, sync::Arc};
+
+use crate::{events::ContainerEvent, resolver::Source, Container, Error};
+
+/// A factory producing values of `T` directly, without boxing them.
+type Producer<T> = Arc<dyn Fn(&Container) -> Result<T, Error> + Sync + Send>;
+
+impl Container {
+    /// Remember a factory that produces the values of the given registration without boxing them, unless the registration was refused.
+    pub(crate) fn insert_typed<T: 'static>(
+        &mut self,
+        source: &Source,
+        producer: impl Fn(&Self) -> Result<T, Error> + 'static + Sync + Send,
+    ) {
+        let producer: Producer<T> = Arc::new(producer);
+
+        self.bindings
+            .set_typed(&TypeId::of::<T>(), source, Arc::new(producer));
+    }
+
+    /// Resolve the given type through its typed factory, without allocating.
+    ///
+    /// Returns `None` if the type's current registration has no typed factory, or if its values go through extensions or resolution hooks, which only work on boxed values.
+    pub(crate) fn resolve_typed<T: 'static>(&self) -> Option<Result<T, Error>> {
+        let type_id = TypeId::of::<T>();
+        if self.extenders.contains_key(&type_id)
+            || self.resolving_hooks.applies_to(type_id)
+            || self.after_resolving_hooks.applies_to(type_id)
+        {
+            return None;
+        }
+
+        let result = self.bindings.with_typed(&type_id, |producer| {
+            producer
+                .downcast_ref::<Producer<T>>()
+                .map(|produce| produce(self))
+        })??;
+        self.emit(ContainerEvent::Resolved {
+            type_id,
+            success: result.is_ok(),
+        });
+
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn typed_factories_follow_the_current_registration() {
+        let mut container = Container::new();
+        container.bind(|_| 1_u32);
+        assert_eq!(container.resolve_typed::<u32>(), Some(Ok(1)));
+
+        container.singleton(&|_| 2_u32);
+        assert_eq!(container.resolve_typed::<u32>(), Some(Ok(2)));
+
+        container.bind_once(|_| 3_u32);
+        assert_eq!(container.resolve_typed::<u32>(), None);
+        assert_eq!(container.resolve::<u32>(), Ok(3));
+
+        container.singleton(&|_| 4_u32);
+        container.forget::<u32>();
+        assert_eq!(container.resolve_typed::<u32>(), None);
+    }
+
+    #[test]
+    fn falls_back_to_the_erased_path_for_extended_types() {
+        let resolved = Arc::new(AtomicUsize::new(0));
+        let mut container = Container::new();
+        container.bind(|_| 1_u32);
+        container.extend(|value: u32, _| value + 1);
+
+        let counter = Arc::clone(&resolved);
+        container.resolving(move |_: &mut u64, _| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        container.singleton(&|_| 10_u64);
+
+        assert_eq!(container.resolve_typed::<u32>(), None);
+        assert_eq!(container.resolve::<u32>(), Ok(2));
+        assert_eq!(container.resolve::<u64>(), Ok(10));
+        assert_eq!(resolved.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,10 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static CONSTRUCTED: AtomicU64 = AtomicU64::new(0);
+
+fn register(host: &mut silhouette::plugins::Host) {
+    host.bind(|_| "Hello from a plugin!".to_string());
+    host.singleton(|_| CONSTRUCTED.fetch_add(1, Ordering::SeqCst) + 1);
+}
+
+silhouette::export_plugin!(register);